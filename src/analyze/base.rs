@@ -2,19 +2,19 @@
 //!
 //! Performs ffts, frequency space smoothing, peak detection, harmonic collapsing, and note detection.
 
-use std::{collections::HashMap, ops::Deref};
+use std::collections::HashMap;
 
 use rustfft::{
     num_complex::{Complex, ComplexFloat},
     FftPlanner,
 };
 
-use crate::core::note::{HasPrimaryHarmonicSeries, ALL_PITCH_NOTES_WITH_FREQUENCY};
+use crate::core::note::{HasPrimaryHarmonicSeries, ALL_PITCH_NOTES};
 
-use crate::core::{base::Res, note::Note, pitch::HasFrequency};
+use crate::core::{base::Res, note::Note, pitch::HasFrequency, tuning::Tuning};
 
-/// Gets notes from audio data.
-pub fn get_notes_from_audio_data(data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
+/// Gets notes from audio data, matching the frequencies against the given [`Tuning`].
+pub fn get_notes_from_audio_data(data: &[f32], length_in_seconds: u8, tuning: &Tuning) -> Res<Vec<Note>> {
     if length_in_seconds < 1 {
         return Err(anyhow::Error::msg("Listening length in seconds must be greater than 1."));
     }
@@ -31,11 +31,11 @@ pub fn get_notes_from_audio_data(data: &[f32], length_in_seconds: u8) -> Res<Vec
     let smoothed_frequency_space = get_smoothed_frequency_space(&frequency_space, length_in_seconds);
     //plot_frequency_space(&smoothed_frequency_space, "frequency_space", 100f32, 1000f32);
 
-    Ok(get_notes_from_smoothed_frequency_space(&smoothed_frequency_space))
+    Ok(get_notes_from_smoothed_frequency_space(&smoothed_frequency_space, tuning))
 }
 
 /// Gets notes from pre-smoothed frequency data (helps with model training deterministic features).
-pub fn get_notes_from_smoothed_frequency_space(smoothed_frequency_space: &[(f32, f32)]) -> Vec<Note> {
+pub fn get_notes_from_smoothed_frequency_space(smoothed_frequency_space: &[(f32, f32)], tuning: &Tuning) -> Vec<Note> {
     // Translate the frequency space into a "peak space" (dampen values that are not the "peak" of a specified window).
 
     let peak_space = translate_frequency_space_to_peak_space(smoothed_frequency_space);
@@ -43,7 +43,7 @@ pub fn get_notes_from_smoothed_frequency_space(smoothed_frequency_space: &[(f32,
 
     // Bucket top N bins into their proper notes, and keep "magnitude".
 
-    let peak_best_notes = get_likely_notes_from_peak_space(&peak_space, 0.1);
+    let peak_best_notes = get_likely_notes_from_peak_space(&peak_space, 0.1, tuning);
     //.into_iter().map(|(n, _)| n).collect::<Vec<_>>();
     let best_notes = peak_best_notes;
     //let binned_best_notes = get_likely_notes_using_bins(smoothed_frequency_space, 0.5, 0.1);
@@ -218,7 +218,7 @@ pub fn translate_frequency_space_to_peak_space(frequency_space: &[(f32, f32)]) -
 }
 
 /// Get likely notes from the peak space.
-fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32) -> Vec<(Note, f32)> {
+fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32, tuning: &Tuning) -> Vec<(Note, f32)> {
     let mut peak_space = peak_space.iter().filter(|(_, m)| *m > 0.1).copied().collect::<Vec<_>>();
     peak_space.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
 
@@ -229,8 +229,10 @@ fn get_likely_notes_from_peak_space(peak_space: &[(f32, f32)], cutoff: f32) -> V
 
     let mut candidates = HashMap::new();
 
+    let notes_with_frequency = ALL_PITCH_NOTES.iter().map(|n| (*n, n.frequency_in(tuning))).collect::<Vec<_>>();
+
     for (frequency, magnitude) in peak_space.iter() {
-        if let Some(pair) = binary_search_closest(ALL_PITCH_NOTES_WITH_FREQUENCY.deref(), *frequency, |t| t.1) {
+        if let Some(pair) = binary_search_closest(&notes_with_frequency, *frequency, |t| t.1) {
             let note = pair.0;
             let entry = candidates.entry(note).or_insert(*magnitude);
             *entry += magnitude;
@@ -379,13 +381,13 @@ pub(crate) mod tests {
     #[test]
     #[should_panic]
     fn test_get_notes_from_audio_data_length() {
-        get_notes_from_audio_data(&[0.0, 0.0, 0.0], 0).unwrap();
+        get_notes_from_audio_data(&[0.0, 0.0, 0.0], 0, &Tuning::default()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn test_get_notes_from_audio_data_nan() {
-        get_notes_from_audio_data(&[0.0, 0.0, f32::NAN], 10).unwrap();
+        get_notes_from_audio_data(&[0.0, 0.0, f32::NAN], 10, &Tuning::default()).unwrap();
     }
 
     #[test]
@@ -10,15 +10,15 @@ use std::{
 
 use rodio::{buffer::SamplesBuffer, Decoder, OutputStream, Source};
 
-use crate::core::{base::Res, note::Note};
+use crate::core::{base::Res, note::Note, tuning::Tuning};
 
 use super::base::get_notes_from_audio_data;
 
-/// Retrieve a list of notes which are guessed from the given audio clip.
-pub fn get_notes_from_audio_file(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>) -> Res<Vec<Note>> {
+/// Retrieve a list of notes which are guessed from the given audio clip, in the given tuning.
+pub fn get_notes_from_audio_file(file: impl AsRef<Path>, start: Option<Duration>, end: Option<Duration>, tuning: &Tuning) -> Res<Vec<Note>> {
     let (data, length_in_seconds) = get_audio_data_from_file(file, start, end)?;
 
-    get_notes_from_audio_data(&data, length_in_seconds)
+    get_notes_from_audio_data(&data, length_in_seconds, tuning)
 }
 
 /// Gets the audio data from a file.
@@ -93,7 +93,7 @@ mod tests {
     #[cfg(feature = "analyze_file")]
     #[test]
     fn test_get_notes_from_audio_file() {
-        let notes = get_notes_from_audio_file("tests/C7b9.wav", None, None).unwrap();
+        let notes = get_notes_from_audio_file("tests/C7b9.wav", None, None, &Tuning::default()).unwrap();
 
         assert_eq!(Chord::parse("C7b9").unwrap(), Chord::try_from_notes(&notes).unwrap()[0]);
     }
@@ -102,7 +102,7 @@ mod tests {
     #[cfg(feature = "analyze_file_mp3")]
     #[test]
     fn test_get_notes_from_mp3_file() {
-        let notes = get_notes_from_audio_file("tests/C7b9.mp3", None, None).unwrap();
+        let notes = get_notes_from_audio_file("tests/C7b9.mp3", None, None, &Tuning::default()).unwrap();
 
         assert_eq!(Chord::parse("C7b9").unwrap(), Chord::from_notes(&notes).unwrap()[0]);
     }
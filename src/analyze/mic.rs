@@ -11,20 +11,20 @@ use cpal::{
     InputCallbackInfo,
 };
 
-use crate::core::{base::Res, note::Note};
+use crate::core::{base::Res, note::Note, tuning::Tuning};
 
 use super::base::get_notes_from_audio_data;
 
-/// Gets notes from the microphone input over the specified period of time.
+/// Gets notes from the microphone input over the specified period of time, matching the frequencies against the given [`Tuning`].
 #[no_coverage]
-pub async fn get_notes_from_microphone(length_in_seconds: u8) -> Res<Vec<Note>> {
+pub async fn get_notes_from_microphone(length_in_seconds: u8, tuning: &Tuning) -> Res<Vec<Note>> {
     // Get data.
 
     let data_from_microphone = get_audio_data_from_microphone(length_in_seconds).await?;
 
     // Get notes.
 
-    let result = get_notes_from_audio_data(&data_from_microphone, length_in_seconds)?;
+    let result = get_notes_from_audio_data(&data_from_microphone, length_in_seconds, tuning)?;
 
     Ok(result)
 }
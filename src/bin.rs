@@ -2,10 +2,25 @@ use std::path::PathBuf;
 
 use clap::{ArgAction, Parser, Subcommand};
 use klib::core::{
-    base::{Parsable, Res, Void},
-    chord::{Chord, Chordable},
-    note::Note,
+    base::{HasDescription, HasName, HasStaticName, Parsable, Res, Void},
+    chord::{Chord, Chordable, HasRoot},
+    chord_scale::{HasDegreeLabel, HasDegrees, HasScaleTones},
+    fretboard::{DiagramStyle, Fingering, Fretboard, StringTuning},
+    interval::{DirectedInterval, Interval},
+    key::Key,
+    name_style::{NameStyle, NamingOptions},
+    negative::Negatable,
+    neo_riemannian::{Transform, Transformable},
+    note::{Note, Respellable, Spelling},
     octave::Octave,
+    pitch::HasFrequency,
+    pitch_class_set::PitchClassSet,
+    progression::Progression,
+    reharm::{Reharmonizer, Technique, ALL_TECHNIQUES},
+    tone_row::{RowLabel, ToneRow},
+    tuning::Tuning,
+    voice_leading::VoiceLeader,
+    voicing::{HasVoicings, Register, Voicing, VoicingKind},
 };
 
 #[derive(Parser, Debug)]
@@ -21,7 +36,7 @@ enum Command {
     ///
     /// The `symbol` has some special syntax.  These are the parts:
     ///
-    /// * The root note (e.g., `C`, `D#`, `Eb`, `F##`, `Gbb`, `A♯`, `B♭`, etc.).
+    /// * The root note (e.g., `C`, `D#`, `Eb`, `F##`, `Gbb`, `A♯`, `B♭`, etc.), with an optional quarter-tone inflection (e.g., `Eqf` or `E𝄳` for E half flat, and `Cqs` or `C𝄲` for C half sharp).
     ///
     /// * Any modifiers (e.g., `7`, `9`, `m7b5`, `sus4`, `dim`, `+`, `maj7`, `-maj7`, `m7b5#9`, etc.).
     ///
@@ -41,6 +56,25 @@ enum Command {
         /// Sets the octave of the primary note.
         #[arg(short, long, default_value_t = 4i8)]
         octave: i8,
+
+        /// Voices the chord (e.g., `drop2`, `drop3`, `drop2+4`, `rootless-a`, `rootless-b`, `shell`, `spread`, `quartal`, or `upper-structure`).
+        #[arg(short, long)]
+        voicing: Option<String>,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+
+        /// Shows fingerings for a fretted instrument (e.g., `guitar`, `drop-d`, `open-g`, `bass`, `ukulele`, `mandolin`, or the strings, such as `DADGAD`).
+        #[arg(short, long)]
+        instrument: Option<String>,
+
+        /// Sets the fret at which the capo is placed (for `instrument`).
+        #[arg(short, long, default_value_t = 0u8)]
+        capo: u8,
+
+        /// Draws the chord diagram with ASCII characters, instead of Unicode box-drawing characters (for `instrument`).
+        #[arg(long)]
+        ascii: bool,
     },
 
     /// Describes and plays a chord.
@@ -61,24 +95,198 @@ enum Command {
         /// Fade in duration (in seconds).
         #[arg(short, long, default_value_t = 0.1f32)]
         fade_in: f32,
+
+        /// Voices the chord (e.g., `drop2`, `drop3`, `drop2+4`, `rootless-a`, `rootless-b`, `shell`, `spread`, `quartal`, or `upper-structure`).
+        #[arg(short, long)]
+        voicing: Option<String>,
+
+        #[command(flatten)]
+        tuning: TuningArgs,
+
+        #[command(flatten)]
+        naming: NamingArgs,
     },
 
-    /// Loops on a set of chord changes, while simultaneously outputting the descriptions.
+    /// Loops on a chord progression, while simultaneously outputting the descriptions.
+    ///
+    /// The `progression` has some special syntax.  These are the parts:
+    ///
+    /// * An optional time signature (default is `4/4`) and tempo (default is `120bpm`).
+    ///
+    /// * Chord symbols (see `describe`), or `N.C.` for a rest, separated into bars with `|`.
+    ///
+    /// * Optional durations in beats (e.g., `Cm7:2`) or bars (e.g., `Cm7:2bars`); chords without a duration share the rest of their bar.
+    ///
+    /// * Repeats (e.g., `|: C | G :|`, or `|: C | G :|x3`), and `%` to repeat the previous bar.
+    ///
+    /// If there are no bar lines, each chord is its own bar (e.g., `Cm7 F7 Bbmaj7`).
     Loop {
-        /// Chord symbol to parse, followed by length in 32nd notes (e.g., "Cm7|32 Dm7|32 Em7|32").
-        ///
-        /// If no length is given, the default is 32.
-        chords: Vec<String>,
-
-        /// Sets the beats per minute of the playback loop.
-        #[arg(short, long, default_value_t = 60f32)]
-        bpm: f32,
+        /// The chord progression to loop (e.g., "3/4 90bpm | Dm7 | G7 | Cmaj7 | % |").
+        progression: Vec<String>,
+
+        /// Sets the beats per minute of the playback loop (overrides the tempo of the progression).
+        #[arg(short, long)]
+        bpm: Option<f32>,
+
+        #[command(flatten)]
+        tuning: TuningArgs,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+    },
+
+    /// Transposes a chord progression by an interval, and outputs the transposed progression and chord descriptions.
+    ///
+    /// Please see `loop` for more information on the progression syntax.
+    Transpose {
+        /// The interval by which to transpose (e.g., `M2`, `P5`, `A4`, `b9`, `MajorSecond`, `PerfectFifth`, etc.).
+        interval: String,
+
+        /// The chord progression to transpose (e.g., "| Bb7#9/D | Ebmaj7 |").
+        progression: Vec<String>,
+
+        /// Transposes down, rather than up.
+        #[arg(short, long)]
+        down: bool,
+
+        /// Respells the transposed chords with the simplest enharmonic roots (e.g., `Fb` becomes `E`).
+        #[arg(short, long)]
+        simplify: bool,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+    },
+
+    /// Mirrors a chord progression across the tonic/dominant axis of a key (i.e., negative harmony), and outputs the
+    /// resulting progression and chord descriptions.
+    ///
+    /// Please see `loop` for more information on the progression syntax.
+    Negative {
+        /// The chord progression to mirror (e.g., "Fm7 Bb7 Cmaj7").
+        progression: Vec<String>,
+
+        /// Sets the key whose axis to mirror across (e.g., `C`, `Am`, or `Eb major`).
+        #[arg(short, long)]
+        key: String,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+    },
+
+    /// Voice leads through a chord progression, choosing a voicing for each chord that minimizes the motion of the voices.
+    ///
+    /// Please see `loop` for more information on the progression syntax.
+    Lead {
+        /// The chord progression to voice lead through (e.g., "Dm7 G7 Cmaj7").
+        progression: Vec<String>,
+
+        /// Sets the number of voices.
+        #[arg(short, long, default_value_t = 4usize)]
+        voices: usize,
+
+        /// Allows parallel fifths and octaves.
+        #[arg(long)]
+        allow_parallels: bool,
+
+        /// Ignores common tones (rather than preferring to hold them).
+        #[arg(long)]
+        ignore_common_tones: bool,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+    },
+
+    /// Suggests reharmonizations of a chord progression (tritone substitutions, secondary dominants, etc.).
+    ///
+    /// Please see `loop` for more information on the progression syntax.
+    Reharm {
+        /// The chord progression to reharmonize (e.g., "Dm7 G7 Cmaj7").
+        progression: Vec<String>,
+
+        /// Restricts the suggestions to a technique (`tritone`, `secondary`, `backdoor`, `ii-v`, `diminished`, or `modal`); may be repeated.
+        #[arg(short, long)]
+        technique: Vec<String>,
+
+        /// Sets the key used for modal interchange (e.g., `C`, `Am`, or `Eb major`); by default, it is the key that the chords fit best.
+        #[arg(short, long)]
+        key: Option<String>,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+    },
+
+    /// Applies Neo-Riemannian transformations to a major or minor triad, or finds the shortest path between two triads on the Tonnetz.
+    Tonnetz {
+        /// The triad to start from (e.g., `C` or `Abm`).
+        chord: String,
+
+        /// The transformations to apply, in order (e.g., `PLR`, or `N S H`).
+        transforms: Vec<String>,
+
+        /// Finds the shortest path (of `P`, `L`, and `R` transformations) to this triad, instead.
+        #[arg(short, long, conflicts_with = "transforms")]
+        to: Option<String>,
+
+        #[command(flatten)]
+        naming: NamingArgs,
+    },
+
+    /// Analyzes a pitch-class set (normal form, prime form, Forte number, interval-class vector, and Z-relation).
+    Set {
+        /// The pitch classes (e.g., `0 1 4 6`, `{0, 4, 7, 10}`, or `014T`), or the notes (e.g., `C E G Bb`).
+        pitch_classes: Vec<String>,
+    },
+
+    /// Outputs the twelve-tone matrix of a tone row (or the given row forms).
+    ///
+    /// The row forms are labeled relative to the row (i.e., `P0` is the row as given).
+    Row {
+        /// The tone row, as twelve distinct notes (e.g., "G Bb D F# A C E G# B C# Eb F").
+        row: Vec<String>,
+
+        /// Outputs the given row forms (e.g., `P5`, `I0`, `R3`, or `RI11`), instead of the matrix; may be repeated.
+        #[arg(short, long)]
+        form: Vec<String>,
+
+        /// Outputs the matrix as CSV.
+        #[arg(long, conflicts_with = "form")]
+        csv: bool,
+    },
+
+    /// Outputs the interval between two notes, or the note an interval away from a note.
+    Interval {
+        /// The first note (e.g., `C4`).
+        from: String,
+
+        /// The second note (e.g., `A5`), or an interval, which may be compound or descending (e.g., `M3`, `b9`, `M17`, or `-P5`).
+        #[arg(allow_hyphen_values = true)]
+        to: String,
+    },
+
+    /// Outputs the nearest note to a frequency, and how far the frequency is from it (in cents).
+    Tune {
+        /// The frequency (in Hz).
+        frequency: f32,
+
+        #[command(flatten)]
+        tuning: TuningArgs,
     },
 
     /// Attempt to guess the chord from a set of notes (ordered by simplicity).
     Guess {
         /// A set of notes from which the guesser will attempt to build a chord.
         notes: Vec<String>,
+
+        /// Guesses the chord from a tab, instead of notes (e.g., `x32010`, `3x543x`, or `x-10-12-12-11-10`).
+        #[arg(short, long, conflicts_with = "notes")]
+        tab: Option<String>,
+
+        /// Sets the tuning of the strings in the tab (e.g., `EADGBE`, `DADGAD`, `drop-d`, `bass`, `ukulele`, or `mandolin`).
+        #[arg(long, default_value = "guitar")]
+        tuning: String,
+
+        #[command(flatten)]
+        naming: NamingArgs,
     },
 
     /// Set of commands to analyze audio data.
@@ -104,6 +312,12 @@ enum AnalyzeCommand {
         /// Sets the duration of listening time (in seconds).
         #[arg(short, long, default_value_t = 10)]
         length: u8,
+
+        #[command(flatten)]
+        tuning: TuningArgs,
+
+        #[command(flatten)]
+        naming: NamingArgs,
     },
 
     /// Guess pitches and chords from the specified section of an audio file.
@@ -124,6 +338,12 @@ enum AnalyzeCommand {
 
         /// The source file to listen to/analyze.
         source: PathBuf,
+
+        #[command(flatten)]
+        tuning: TuningArgs,
+
+        #[command(flatten)]
+        naming: NamingArgs,
     },
 }
 
@@ -304,9 +524,56 @@ enum InferCommand {
 
         /// The source file to listen to/analyze.
         source: PathBuf,
+
+        #[command(flatten)]
+        tuning: TuningArgs,
     },
 }
 
+/// The options for how notes and chords are written.
+#[derive(clap::Args, Debug)]
+struct NamingArgs {
+    /// Sets the enharmonic spelling policy (`theoretical`, `no-doubles`, `simplest`, `sharps`, `flats`, or a key, such as `Eb` or `F#m`).
+    #[arg(long, default_value = "theoretical")]
+    spelling: String,
+
+    /// Sets the chord name style (`standard`, `jazz`, `berklee`, `ascii`, `ireal`, or `latex`).
+    #[arg(long, default_value = "standard")]
+    style: String,
+}
+
+impl NamingArgs {
+    /// Returns the parsed naming options.
+    fn options(&self) -> Res<NamingOptions> {
+        Ok(NamingOptions::default().with_spelling(Spelling::parse(&self.spelling)?).with_style(NameStyle::parse(&self.style)?))
+    }
+}
+
+/// The options for how notes are tuned.
+#[derive(clap::Args, Debug)]
+struct TuningArgs {
+    /// Sets the tuning (`equal`, `just`, `pythagorean`, `meantone`, or `werckmeister`, with an optional tonic, such as "just D", an EDO, such as `19edo` or `24edo`, or twelve cents values from C to B).
+    #[arg(long, default_value = "equal")]
+    tuning: String,
+
+    /// Sets the reference pitch (the frequency of A4, e.g., `442` or `415`).
+    #[arg(long)]
+    reference: Option<f32>,
+}
+
+impl TuningArgs {
+    /// Returns the parsed tuning, at the given reference pitch (if any).
+    fn tuning(&self) -> Res<Tuning> {
+        let tuning = Tuning::parse(&self.tuning)?;
+
+        match self.reference {
+            Some(reference) if !reference.is_finite() || reference <= 0.0 => Err(anyhow::Error::msg(format!("Invalid reference pitch `{}`.", reference))),
+            Some(reference) => Ok(tuning.with_reference(reference)),
+            None => Ok(tuning),
+        }
+    }
+}
+
 fn main() -> Void {
     let args = Args::parse();
 
@@ -317,67 +584,302 @@ fn main() -> Void {
 
 fn start(args: Args) -> Void {
     match args.command {
-        Some(Command::Describe { symbol, octave }) => {
+        Some(Command::Describe {
+            symbol,
+            octave,
+            voicing,
+            naming,
+            instrument,
+            capo,
+            ascii,
+        }) => {
+            let options = naming.options()?;
             let chord = Chord::parse(&symbol)?.with_octave(Octave::Zero + octave);
 
-            describe(&chord);
+            describe(&chord, &options);
+            describe_degrees(&chord, &options);
+
+            if let Some(voicing) = voicing {
+                println!("   {}", chord.voicing(VoicingKind::parse(&voicing)?, Register::default())?);
+            }
+
+            if let Some(instrument) = instrument {
+                let fretboard = Fretboard::new(StringTuning::parse(&instrument)?).with_capo(capo);
+                let fingerings = fretboard.fingerings(&chord).into_iter().take(5).collect::<Vec<_>>();
+
+                let Some(best) = fingerings.first() else {
+                    return Err(anyhow::Error::msg(format!("No playable fingerings for `{}` in {}.", chord.name_with(&options), fretboard.tuning())));
+                };
+
+                println!("   {}", fingerings.iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", "));
+
+                let style = if ascii { DiagramStyle::Ascii } else { DiagramStyle::Unicode };
+
+                for line in best.diagram(style).lines() {
+                    println!("{}", format!("   {}", line).trim_end());
+                }
+            }
         }
-        Some(Command::Play { symbol, delay, length, fade_in }) => {
+        Some(Command::Play {
+            symbol,
+            delay,
+            length,
+            fade_in,
+            voicing,
+            tuning,
+            naming,
+        }) => {
+            let tuning = tuning.tuning()?;
+            let options = naming.options()?;
             let chord = Chord::parse(&symbol)?;
 
-            play(&chord, delay, length, fade_in)?;
+            match voicing {
+                Some(voicing) => play_voicing(&chord.voicing(VoicingKind::parse(&voicing)?, Register::default())?, &tuning, &options, delay, length, fade_in)?,
+                None => play(&chord, &tuning, &options, delay, length, fade_in)?,
+            }
+        }
+        Some(Command::Set { pitch_classes }) => {
+            let set = match PitchClassSet::parse(&pitch_classes.join(" ")) {
+                Ok(set) => set,
+                Err(_) => PitchClassSet::from_notes(&pitch_classes.iter().map(|n| Note::parse(n)).collect::<Res<Vec<_>>>()?),
+            };
+
+            let join = |pitch_classes: Vec<u8>| pitch_classes.iter().map(|k| k.to_string()).collect::<Vec<_>>().join(", ");
+            let prime = set.prime();
+
+            println!("{}", set);
+            println!("   normal form: [{}]", join(set.normal_form()));
+            println!("   prime form: ({})", join(set.prime_form()));
+            println!("   Forte number: {}", set.forte_number());
+            println!("   interval vector: <{}>", set.interval_vector().iter().map(|k| k.to_string()).collect::<Vec<_>>().join(","));
+
+            if let Some(z) = set.z_correspondent() {
+                println!("   Z-related: {} ({})", z.forte_number(), join(z.pitch_classes()));
+            }
+
+            println!("   complement: {} ({})", prime.complement().forte_number(), join(prime.complement().prime_form()));
         }
-        Some(Command::Guess { notes }) => {
-            // Parse the notes.
-            let notes = notes.into_iter().map(|n| Note::parse(&n)).collect::<Result<Vec<_>, _>>()?;
+        Some(Command::Interval { from, to }) => {
+            let from = Note::parse(&from)?;
 
-            // Get the chord from the notes.
-            let candidates = Chord::try_from_notes(&notes)?;
+            match Note::parse(&to) {
+                Ok(to) => {
+                    let interval = DirectedInterval::between(from, to)?;
+                    let direction = if interval.is_descending() { "down" } else { "up" };
 
-            for candidate in candidates {
-                describe(&candidate);
+                    println!("{} ({})", interval.name(), interval);
+                    println!("   {} → {}: {} semitones {}", from, to, interval.semitones().abs(), direction);
+
+                    if interval.is_compound() {
+                        println!("   compound: {} octave(s) and a {}", interval.octaves(), interval.simple().name().trim_start_matches("descending "));
+                    }
+
+                    println!(
+                        "   inversion: {} ({})",
+                        interval.inverted().name().trim_start_matches("descending "),
+                        interval.inverted().to_string().trim_start_matches('-')
+                    );
+                }
+                Err(_) => {
+                    let interval = DirectedInterval::parse(&to)?;
+
+                    let to = from.checked_add(interval).ok_or_else(|| anyhow::Error::msg(format!("{} + {} is out of range.", from, interval)))?;
+
+                    println!("{} + {} = {}", from, interval, to);
+                }
             }
         }
-        Some(Command::Loop { chords, bpm }) => {
-            let chord_pairs = chords
-                .into_iter()
-                .map(|c| {
-                    let mut parts = c.split('|');
+        Some(Command::Tune { frequency, tuning }) => {
+            let tuning = tuning.tuning()?;
+            let (note, cents) = tuning.nearest_note(frequency)?;
+
+            println!("{} {:+.1} cents", note, cents);
+            println!(
+                "   {:.2} Hz ({} is {:.2} Hz in {}, at A4 = {} Hz)",
+                frequency,
+                note,
+                note.frequency_in(&tuning),
+                tuning.temperament().name(),
+                tuning.reference()
+            );
+        }
+        Some(Command::Row { row, form, csv }) => {
+            let row = ToneRow::parse(&row.join(" "))?;
 
-                    let chord = Chord::parse(parts.next().unwrap()).unwrap();
+            if !form.is_empty() {
+                for label in form {
+                    let label = RowLabel::parse(&label)?;
+                    let pitches = ToneRow::new(&row.form(label))?;
 
-                    let length = parts.next().map(|l| l.parse::<u16>().unwrap()).unwrap_or(32);
+                    println!("{}: {}", label, pitches);
+                }
+            } else if csv {
+                println!("{}", row.csv());
+            } else {
+                println!("{}", row.table());
+            }
+        }
+        Some(Command::Guess { notes, tab, tuning, naming }) => {
+            let options = naming.options()?;
+
+            // Get the chord from the tab, or the notes.
+            let candidates = match tab {
+                Some(tab) => Fretboard::new(StringTuning::parse(&tuning)?).chords(&Fingering::parse(&tab)?)?,
+                None => Chord::try_from_notes(&notes.into_iter().map(|n| Note::parse(&n)).collect::<Result<Vec<_>, _>>()?)?,
+            };
+
+            for candidate in candidates {
+                describe(&candidate, &options);
+            }
+        }
+        Some(Command::Loop { progression, bpm, tuning, naming }) => {
+            let tuning = tuning.tuning()?;
+            let options = naming.options()?;
+            let mut progression = Progression::parse(&progression.join(" "))?;
 
-                    (chord, length)
-                })
-                .collect::<Vec<_>>();
+            if let Some(bpm) = bpm {
+                progression = progression.with_tempo(bpm)?;
+            }
+
+            if progression.entries().is_empty() {
+                return Err(anyhow::Error::msg("The progression is empty."));
+            }
 
             loop {
-                for (chord, length) in chord_pairs.iter() {
-                    let length = (*length as f32) * 60f32 / bpm / 8f32;
-                    play(chord, 0.0, length, 0.1)?;
+                for entry in progression.entries() {
+                    let length = entry.beats * progression.seconds_per_beat();
+
+                    match &entry.chord {
+                        Some(chord) => play(chord, &tuning, &options, 0.0, length, 0.1)?,
+                        None => std::thread::sleep(std::time::Duration::from_secs_f32(length)),
+                    }
                 }
             }
         }
+        Some(Command::Transpose {
+            interval,
+            progression,
+            down,
+            simplify,
+            naming,
+        }) => {
+            let interval = Interval::parse(&interval)?;
+            let progression = Progression::parse(&progression.join(" "))?;
+
+            let mut progression = progression.checked_transpose(interval, down)?;
+
+            if simplify {
+                progression = progression.simplified();
+            }
+
+            let options = naming.options()?;
+
+            println!("{}", progression.to_string_with(&options));
+
+            for chord in progression.chords() {
+                describe(&chord, &options);
+            }
+        }
+        Some(Command::Negative { progression, key, naming }) => {
+            let key = Key::parse(&key)?;
+            let options = naming.options()?;
+            let progression = Progression::parse(&progression.join(" "))?.negative(&key)?;
+
+            println!("{}", progression.to_string_with(&options));
+
+            for chord in progression.chords() {
+                describe(&chord, &options);
+            }
+        }
+        Some(Command::Lead {
+            progression,
+            voices,
+            allow_parallels,
+            ignore_common_tones,
+            naming,
+        }) => {
+            let options = naming.options()?;
+            let progression = Progression::parse(&progression.join(" "))?;
+
+            let leading = VoiceLeader::new()
+                .with_voices(voices)
+                .with_avoid_parallels(!allow_parallels)
+                .with_keep_common_tones(!ignore_common_tones)
+                .lead(&progression.chords())?;
+
+            println!("{}", leading.to_string_with(&options));
+            println!("Total motion: {} semitones", leading.total_motion());
+        }
+        Some(Command::Reharm { progression, technique, key, naming }) => {
+            let options = naming.options()?;
+            let progression = Progression::parse(&progression.join(" "))?;
+
+            let techniques = if technique.is_empty() {
+                ALL_TECHNIQUES.to_vec()
+            } else {
+                technique.iter().map(|t| Technique::parse(t)).collect::<Res<Vec<_>>>()?
+            };
+
+            let mut reharmonizer = Reharmonizer::new().with_techniques(&techniques);
+
+            if let Some(key) = key {
+                reharmonizer = reharmonizer.with_key(Key::parse(&key)?);
+            }
+
+            let reharmonizations = reharmonizer.reharmonize(&progression.chords());
+
+            if reharmonizations.is_empty() {
+                println!("No reharmonizations found.");
+            }
+
+            for reharmonization in reharmonizations {
+                println!("{}", reharmonization.to_string_with(&options));
+            }
+        }
+        Some(Command::Tonnetz { chord, transforms, to, naming }) => {
+            let options = naming.options()?;
+            let mut chord = Chord::parse(&chord)?;
+
+            let transforms = match to {
+                Some(to) => chord.tonnetz_path(&Chord::parse(&to)?)?,
+                None => Transform::parse_sequence(&transforms.join(" "))?,
+            };
+
+            println!("{}", chord.name_with(&options));
+
+            for transform in transforms {
+                chord = chord.transformed(transform)?;
+
+                println!("{} ({}): {}", transform, transform.description(), chord.name_with(&options));
+            }
+        }
         #[cfg(feature = "analyze_base")]
         Some(Command::Analyze { analyze_command }) => match analyze_command {
             #[cfg(feature = "analyze_mic")]
-            Some(AnalyzeCommand::Mic { length }) => {
-                let notes = futures::executor::block_on(Note::try_from_mic(length))?;
+            Some(AnalyzeCommand::Mic { length, tuning, naming }) => {
+                let notes = futures::executor::block_on(Note::try_from_mic_in(length, &tuning.tuning()?))?;
 
-                show_notes_and_chords(&notes)?;
+                show_notes_and_chords(&notes, &naming.options()?)?;
             }
             #[cfg(feature = "analyze_file")]
-            Some(AnalyzeCommand::File { preview, start_time, end_time, source }) => {
+            Some(AnalyzeCommand::File {
+                preview,
+                start_time,
+                end_time,
+                source,
+                tuning,
+                naming,
+            }) => {
                 use klib::analyze::file::{get_notes_from_audio_file, preview_audio_file_clip};
 
+                let tuning = tuning.tuning()?;
                 let start_time = if let Some(t) = start_time { Some(parse_duration0::parse(&t)?) } else { None };
                 let end_time = if let Some(t) = end_time { Some(parse_duration0::parse(&t)?) } else { None };
                 if preview {
                     preview_audio_file_clip(&source, start_time, end_time)?;
                 }
-                let notes = get_notes_from_audio_file(&source, start_time, end_time)?;
-                show_notes_and_chords(&notes)?;
+                let notes = get_notes_from_audio_file(&source, start_time, end_time, &tuning)?;
+                show_notes_and_chords(&notes, &naming.options()?)?;
             }
             None => {
                 return Err(anyhow::Error::msg("No subcommand given for `analyze`."));
@@ -478,7 +980,7 @@ fn start(args: Args) -> Void {
                     let notes = infer(&audio_data, length)?;
 
                     // Show the results.
-                    show_notes_and_chords(&notes)?;
+                    show_notes_and_chords(&notes, &NamingOptions::default())?;
                 }
                 #[cfg(feature = "analyze_file")]
                 Some(InferCommand::File { preview, start_time, end_time, source }) => {
@@ -501,7 +1003,7 @@ fn start(args: Args) -> Void {
                     let notes = infer(&audio_data, length)?;
 
                     // Show the results.
-                    show_notes_and_chords(&notes)?;
+                    show_notes_and_chords(&notes, &NamingOptions::default())?;
                 }
                 None => {
                     return Err(anyhow::Error::msg("Invalid inference command."));
@@ -595,26 +1097,59 @@ fn start(args: Args) -> Void {
     Ok(())
 }
 
-fn describe(chord: &Chord) {
-    println!("{}", chord);
+fn describe(chord: &Chord, options: &NamingOptions) {
+    println!("{}", chord.to_string_with(options));
+}
+
+fn describe_degrees(chord: &Chord, options: &NamingOptions) {
+    let label = |note: Note, interval: Interval| format!("{} ({})", note.respelled(options.spelling()).static_name(), interval.degree_label());
+    let tones = |intervals: Vec<Interval>| intervals.into_iter().map(|i| label(chord.root() + i, i)).collect::<Vec<_>>().join(", ");
+
+    println!("   chord tones: {}", chord.degrees().into_iter().map(|(n, i)| label(n, i)).collect::<Vec<_>>().join(", "));
+
+    let tensions = chord.tensions();
+    if !tensions.is_empty() {
+        println!("   tensions: {}", tones(tensions));
+    }
+
+    let avoid_notes = chord.avoid_notes();
+    if !avoid_notes.is_empty() {
+        println!("   avoid notes: {}", tones(avoid_notes));
+    }
 }
 
-fn play(chord: &Chord, delay: f32, length: f32, fade_in: f32) -> Void {
-    describe(chord);
+fn play(chord: &Chord, tuning: &Tuning, options: &NamingOptions, delay: f32, length: f32, fade_in: f32) -> Void {
+    describe(chord, options);
 
     #[cfg(feature = "audio")]
     {
         use klib::core::base::Playable;
         use std::time::Duration;
 
-        let _playable = chord.play(delay, length, fade_in)?;
+        let _playable = chord.play_in(tuning, delay, length, fade_in)?;
         std::thread::sleep(Duration::from_secs_f32(length));
     }
 
     Ok(())
 }
 
-fn show_notes_and_chords(notes: &[Note]) -> Res<()> {
+fn play_voicing(voicing: &Voicing, tuning: &Tuning, options: &NamingOptions, delay: f32, length: f32, fade_in: f32) -> Void {
+    describe(voicing.chord(), options);
+    println!("   {}", voicing);
+
+    #[cfg(feature = "audio")]
+    {
+        use klib::core::base::Playable;
+        use std::time::Duration;
+
+        let _playable = voicing.play_in(tuning, delay, length, fade_in)?;
+        std::thread::sleep(Duration::from_secs_f32(length));
+    }
+
+    Ok(())
+}
+
+fn show_notes_and_chords(notes: &[Note], options: &NamingOptions) -> Res<()> {
     println!("Notes: {}", notes.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(" "));
 
     let candidates = Chord::try_from_notes(notes)?;
@@ -623,7 +1158,7 @@ fn show_notes_and_chords(notes: &[Note]) -> Res<()> {
         println!("No chord candidates found");
     } else {
         for candidate in candidates {
-            describe(&candidate);
+            describe(&candidate, options);
         }
     }
     Ok(())
@@ -634,6 +1169,7 @@ fn show_notes_and_chords(notes: &[Note]) -> Res<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use klib::core::pitch::Pitch;
 
     #[test]
     fn test_describe() {
@@ -641,6 +1177,14 @@ mod tests {
             command: Some(Command::Describe {
                 symbol: "Cmaj7b9@3^2!".to_string(),
                 octave: 4,
+                voicing: Some("drop2".to_owned()),
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "jazz".to_owned(),
+                },
+                instrument: Some("guitar".to_owned()),
+                capo: 0,
+                ascii: false,
             }),
         })
         .unwrap();
@@ -651,8 +1195,347 @@ mod tests {
         start(Args {
             command: Some(Command::Guess {
                 notes: vec!["C".to_owned(), "E".to_owned(), "G".to_owned()],
+                tab: None,
+                tuning: "guitar".to_owned(),
+                naming: NamingArgs {
+                    spelling: "sharps".to_owned(),
+                    style: "standard".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+
+        start(Args {
+            command: Some(Command::Guess {
+                notes: vec![],
+                tab: Some("x32010".to_owned()),
+                tuning: "EADGBE".to_owned(),
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "ascii".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_tuning() {
+        let tuning = |tuning: &str, reference: Option<f32>| TuningArgs { tuning: tuning.to_owned(), reference }.tuning();
+
+        assert_eq!(tuning("just D", Some(442.0)).unwrap(), Tuning::just(Pitch::D).with_reference(442.0));
+        assert_eq!(tuning("31edo", None).unwrap(), Tuning::edo(31).unwrap());
+        assert_eq!(tuning("equal", None).unwrap(), Tuning::default());
+
+        assert!(tuning("equal", Some(0.0)).is_err());
+        assert!(tuning("kirnberger", None).is_err());
+    }
+
+    #[test]
+    fn test_loop() {
+        assert!(start(Args {
+            command: Some(Command::Loop {
+                progression: vec!["| C | G |".to_owned()],
+                bpm: Some(0.0),
+                tuning: TuningArgs {
+                    tuning: "equal".to_owned(),
+                    reference: None,
+                },
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "standard".to_owned(),
+                },
+            }),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_transpose() {
+        start(Args {
+            command: Some(Command::Transpose {
+                interval: "majorsecond".to_owned(),
+                progression: vec!["| Bb7#9/D | Ebmaj7 |".to_owned()],
+                down: false,
+                simplify: true,
+                naming: NamingArgs {
+                    spelling: "Eb".to_owned(),
+                    style: "ireal".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+
+        assert!(start(Args {
+            command: Some(Command::Transpose {
+                interval: "P8".to_owned(),
+                progression: vec!["C@0".to_owned()],
+                down: true,
+                simplify: false,
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "standard".to_owned(),
+                },
+            }),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_negative() {
+        start(Args {
+            command: Some(Command::Negative {
+                progression: vec!["Fm7".to_owned(), "Bb7".to_owned(), "Cmaj7".to_owned()],
+                key: "C".to_owned(),
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "berklee".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_set() {
+        start(Args {
+            command: Some(Command::Set {
+                pitch_classes: vec!["0 1 4 6".to_owned()],
+            }),
+        })
+        .unwrap();
+
+        start(Args {
+            command: Some(Command::Set {
+                pitch_classes: vec!["C".to_owned(), "E".to_owned(), "G".to_owned(), "Bb".to_owned()],
+            }),
+        })
+        .unwrap();
+
+        // The aggregate has two-digit entries in its interval vector (i.e., `<12,12,12,12,12,6>`).
+        start(Args {
+            command: Some(Command::Set {
+                pitch_classes: vec!["0 1 2 3 4 5 6 7 8 9 10 11".to_owned()],
+            }),
+        })
+        .unwrap();
+
+        assert!(start(Args {
+            command: Some(Command::Set { pitch_classes: vec!["X".to_owned()] }),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_interval() {
+        start(Args {
+            command: Some(Command::Interval {
+                from: "C4".to_owned(),
+                to: "A5".to_owned(),
+            }),
+        })
+        .unwrap();
+
+        start(Args {
+            command: Some(Command::Interval {
+                from: "C4".to_owned(),
+                to: "-M17".to_owned(),
             }),
         })
         .unwrap();
+
+        assert!(start(Args {
+            command: Some(Command::Interval {
+                from: "C4".to_owned(),
+                to: "P3".to_owned(),
+            }),
+        })
+        .is_err());
+
+        assert!(start(Args {
+            command: Some(Command::Interval {
+                from: "C0".to_owned(),
+                to: "-P8".to_owned(),
+            }),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_tune() {
+        start(Args {
+            command: Some(Command::Tune {
+                frequency: 442.5,
+                tuning: TuningArgs {
+                    tuning: "equal".to_owned(),
+                    reference: None,
+                },
+            }),
+        })
+        .unwrap();
+
+        assert!(start(Args {
+            command: Some(Command::Tune {
+                frequency: -1.0,
+                tuning: TuningArgs {
+                    tuning: "equal".to_owned(),
+                    reference: None,
+                },
+            }),
+        })
+        .is_err());
+
+        assert!(start(Args {
+            command: Some(Command::Tune {
+                frequency: 1.0,
+                tuning: TuningArgs {
+                    tuning: "equal".to_owned(),
+                    reference: None,
+                },
+            }),
+        })
+        .is_err());
+
+        assert!(start(Args {
+            command: Some(Command::Tune {
+                frequency: 440.0,
+                tuning: TuningArgs {
+                    tuning: "1edo".to_owned(),
+                    reference: None,
+                },
+            }),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_row() {
+        start(Args {
+            command: Some(Command::Row {
+                row: vec!["G Bb D F# A C E G# B C# Eb F".to_owned()],
+                form: vec![],
+                csv: false,
+            }),
+        })
+        .unwrap();
+
+        start(Args {
+            command: Some(Command::Row {
+                row: vec!["G Bb D F# A C E G# B C# Eb F".to_owned()],
+                form: vec!["P5".to_owned(), "RI0".to_owned()],
+                csv: false,
+            }),
+        })
+        .unwrap();
+
+        assert!(start(Args {
+            command: Some(Command::Row {
+                row: vec!["G Bb D F# A C E G# B C# Eb G".to_owned()],
+                form: vec![],
+                csv: true,
+            }),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_lead() {
+        start(Args {
+            command: Some(Command::Lead {
+                progression: vec!["Dm7 G7 Cmaj7".to_owned()],
+                voices: 4,
+                allow_parallels: false,
+                ignore_common_tones: false,
+                naming: NamingArgs {
+                    spelling: "flats".to_owned(),
+                    style: "jazz".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_reharm() {
+        start(Args {
+            command: Some(Command::Reharm {
+                progression: vec!["Dm7 G7 Cmaj7".to_owned()],
+                technique: vec!["tritone".to_owned(), "modal".to_owned()],
+                key: Some("C".to_owned()),
+                naming: NamingArgs {
+                    spelling: "sharps".to_owned(),
+                    style: "ascii".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+
+        assert!(start(Args {
+            command: Some(Command::Reharm {
+                progression: vec!["Dm7 G7 Cmaj7".to_owned()],
+                technique: vec!["foo".to_owned()],
+                key: None,
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "standard".to_owned(),
+                },
+            }),
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_tonnetz() {
+        start(Args {
+            command: Some(Command::Tonnetz {
+                chord: "C".to_owned(),
+                transforms: vec!["PLR".to_owned(), "H".to_owned()],
+                to: None,
+                naming: NamingArgs {
+                    spelling: "flats".to_owned(),
+                    style: "berklee".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+
+        start(Args {
+            command: Some(Command::Tonnetz {
+                chord: "C".to_owned(),
+                transforms: vec![],
+                to: Some("F#".to_owned()),
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "standard".to_owned(),
+                },
+            }),
+        })
+        .unwrap();
+
+        assert!(start(Args {
+            command: Some(Command::Tonnetz {
+                chord: "C7".to_owned(),
+                transforms: vec!["P".to_owned()],
+                to: None,
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "standard".to_owned(),
+                },
+            }),
+        })
+        .is_err());
+
+        assert!(start(Args {
+            command: Some(Command::Tonnetz {
+                chord: "C".to_owned(),
+                transforms: vec!["P".to_owned()],
+                to: None,
+                naming: NamingArgs {
+                    spelling: "theoretical".to_owned(),
+                    style: "foo".to_owned(),
+                },
+            }),
+        })
+        .is_err());
     }
 }
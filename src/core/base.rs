@@ -4,6 +4,8 @@
 
 use rodio::{OutputStream, OutputStreamHandle, Sink};
 
+use super::tuning::Tuning;
+
 /// Global result type.
 pub type Res<T> = anyhow::Result<T>;
 
@@ -70,5 +72,12 @@ pub trait Playable {
     /// Plays the [`Playable`].
     ///
     /// Dropping the returned [`PlayableResult`] will stop the playback.
-    fn play(&self, delay: f32, length: f32, fade_in: f32) -> Res<PlaybackHandle>;
+    fn play(&self, delay: f32, length: f32, fade_in: f32) -> Res<PlaybackHandle> {
+        self.play_in(&Tuning::default(), delay, length, fade_in)
+    }
+
+    /// Plays the [`Playable`] in the given [`Tuning`].
+    ///
+    /// Dropping the returned [`PlayableResult`] will stop the playback.
+    fn play_in(&self, tuning: &Tuning, delay: f32, length: f32, fade_in: f32) -> Res<PlaybackHandle>;
 }
@@ -1,6 +1,11 @@
 //! A module that contains the [`Chord`] struct and related traits.
 
-use std::{cmp::Ordering, collections::HashSet, fmt::Display};
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    fmt::{self, Display, Write},
+    ops::{Add, Sub},
+};
 
 use pest::Parser;
 
@@ -9,11 +14,13 @@ use crate::core::{
     interval::Interval,
     known_chord::{HasRelativeChord, HasRelativeScale, KnownChord},
     modifier::{known_modifier_sets, likely_extension_sets, one_off_modifier_sets, Degree, Extension, HasIsDominant, Modifier},
-    named_pitch::HasNamedPitch,
-    note::{CZero, Note, NoteRecreator},
+    name_style::{NameStyle, NamingOptions},
+    named_pitch::{HasNamedPitch, ALL_PITCHES as ALL_NAMED_PITCHES},
+    note::{CZero, HasQuarterTone, Note, NoteRecreator, Respellable, Spelling},
     octave::{HasOctave, Octave},
     parser::{note_str_to_note, octave_str_to_octave, ChordParser, Rule},
-    pitch::HasFrequency,
+    pitch::HasPitch,
+    scale::{HasKnownScale, Scale},
 };
 
 // Traits.
@@ -30,6 +37,12 @@ pub trait HasSlash {
     fn slash(&self) -> Note;
 }
 
+/// A trait that represents a type that has an upper structure (i.e., is a polychord).
+pub trait HasUpper {
+    /// Returns the upper chord of the implementor (most likely a [`Chord`]), if it is a polychord (e.g., the `D` in `D/C7`).
+    fn upper(&self) -> Option<&Chord>;
+}
+
 /// A trait that represents a type that has modifiers.
 pub trait HasModifiers {
     /// Returns the modifiers of the implementor (most likely a [`Chord`]).
@@ -90,6 +103,8 @@ pub trait Chordable {
     fn with_inversion(self, inversion: u8) -> Chord;
     /// Sets the slash note of the implementor (most likely a [`Chord`]), and returns a new chord.
     fn with_slash(self, slash: Note) -> Chord;
+    /// Stacks the given chord above the implementor (most likely a [`Chord`]), and returns a new polychord.
+    fn with_upper(self, upper: Chord) -> Chord;
     /// Sets the octave of the implementor (most likely the root note of a chord), and returns a new chord.
     fn with_octave(self, octave: Octave) -> Chord;
     /// Sets whether or not the implementor (most likely a [`Chord`]) is crunchy.
@@ -223,6 +238,18 @@ pub trait Chordable {
     fn add13(self) -> Chord;
     /// Returns a new chord with an add13 extension on the implementor (most likely a [`Chord`]).
     fn add_thirteen(self) -> Chord;
+
+    /// Returns a new chord with the third omitted from the implementor (most likely a [`Chord`]).
+    fn no3(self) -> Chord;
+    /// Returns a new chord with the third omitted from the implementor (most likely a [`Chord`]).
+    fn no_three(self) -> Chord;
+    /// Returns a new chord with the third omitted from the implementor (most likely a [`Chord`]); i.e., a power chord.
+    fn power(self) -> Chord;
+
+    /// Returns a new chord with the fifth omitted from the implementor (most likely a [`Chord`]).
+    fn no5(self) -> Chord;
+    /// Returns a new chord with the fifth omitted from the implementor (most likely a [`Chord`]).
+    fn no_five(self) -> Chord;
 }
 
 /// A trait for types that have a dominant degree; i.e., 7, 9, 11, 13.
@@ -240,6 +267,8 @@ pub struct Chord {
     root: Note,
     /// The slash note of the chord.
     slash: Option<Note>,
+    /// The upper structure of the chord, when it is a polychord (e.g., the `D` in `D/C7`).
+    upper: Option<Box<Chord>>,
     /// The modifiers of the chord.
     modifiers: HashSet<Modifier>,
     /// The extensions of the chord.
@@ -302,8 +331,12 @@ impl Ord for Chord {
         let a_inversion_exists = u8::from(a_inversion != 0);
         let b_inversion_exists = u8::from(b_inversion != 0);
 
-        let a_all_changes_len = a_extensions_len + a_modifiers_len + 2 * a_slashes + 2 * a_inversion_exists;
-        let b_all_changes_len = b_extensions_len + b_modifiers_len + 2 * b_slashes + 2 * b_inversion_exists;
+        // Also, prefer complete chords to ones with omitted tones (which count twice).
+        let a_omissions = self.extensions.iter().filter(|e| matches!(e, Extension::No3 | Extension::No5)).count() as u8;
+        let b_omissions = other.extensions.iter().filter(|e| matches!(e, Extension::No3 | Extension::No5)).count() as u8;
+
+        let a_all_changes_len = a_extensions_len + a_modifiers_len + 2 * a_slashes + 2 * a_inversion_exists + a_omissions;
+        let b_all_changes_len = b_extensions_len + b_modifiers_len + 2 * b_slashes + 2 * b_inversion_exists + b_omissions;
 
         let cmp_all_changes = a_all_changes_len.cmp(&b_all_changes_len);
 
@@ -333,6 +366,7 @@ impl Chord {
         Self {
             root,
             slash: None,
+            upper: None,
             modifiers: HashSet::new(),
             extensions: HashSet::new(),
             inversion: 0,
@@ -344,36 +378,57 @@ impl Chord {
 impl Chord {
     /// Attempts to guess the chord from the notes.
     pub fn try_from_notes(notes: &[Note]) -> Res<Vec<Self>> {
+        let mut notes = notes.to_vec();
+        notes.sort();
+
+        // Power chords are the only two-note chords (either a fifth, or a fourth with the fifth in the bass).
+        if let [low, high] = notes[..] {
+            match high.semitones() - low.semitones() {
+                7 => return Ok(vec![Chord::new(low).power()]),
+                5 => {
+                    let octave = (high.octave() as u8)
+                        .checked_sub(1)
+                        .and_then(|o| Octave::try_from(o).ok())
+                        .ok_or_else(|| anyhow::Error::msg("The root of the power chord would be below octave 0."))?;
+                    let root = high.with_octave(octave);
+
+                    return Ok(vec![Chord::new(root).power().with_inversion(1)]);
+                }
+                _ => {}
+            }
+        }
+
         if notes.len() < 3 {
             return Err(anyhow::Error::msg("Must have at least three notes to guess a chord."));
         }
 
-        let mut notes = notes.to_vec();
-        notes.sort();
-
         let mut result = Vec::new();
 
         // Iterate through all known chords (and some likely extensions) and find the longest match.
         for inversion in 0..3 {
-            let proper_root = if inversion == 0 {
-                notes[0]
+            let (proper_root, proper_root_slash) = if inversion == 0 {
+                (notes[0], notes[1])
             } else {
                 let note = notes[notes.len() - inversion];
 
-                note.with_octave(note.octave() - 1)
-            };
-
-            let proper_root_slash = if inversion == 0 {
-                notes[1]
-            } else {
-                let note = notes[notes.len() - inversion];
+                // The root of an inversion is an octave below, which may not exist.
+                let Some(octave) = (note.octave() as u8).checked_sub(1).and_then(|o| Octave::try_from(o).ok()) else {
+                    continue;
+                };
 
-                note.with_octave(note.octave() - 1)
+                (note.with_octave(octave), note.with_octave(octave))
             };
 
             for mod_set in known_modifier_sets() {
+                // Omitted tones are only likely in shell voicings (i.e., chords with a seventh).
+                let is_shell = mod_set.iter().any(|m| m.is_dominant() || *m == Modifier::Major7);
+
                 for mod_set2 in one_off_modifier_sets() {
                     for ext_set in likely_extension_sets() {
+                        if !is_shell && ext_set.iter().any(|e| matches!(e, Extension::No3 | Extension::No5)) {
+                            continue;
+                        }
+
                         for is_crunchy in [false, true] {
                             // Check using the first note as the root.
                             let candidate_chord_root = Chord::new(proper_root)
@@ -384,7 +439,12 @@ impl Chord {
                                 .with_crunchy(is_crunchy);
                             let candidate_chord_root_notes = candidate_chord_root.chord();
 
-                            if notes.len() == candidate_chord_root_notes.len() && notes.iter().zip(&candidate_chord_root.chord()).all(|(a, b)| a.frequency() == b.frequency()) {
+                            if notes.len() == candidate_chord_root_notes.len()
+                                && notes
+                                    .iter()
+                                    .zip(&candidate_chord_root.chord())
+                                    .all(|(a, b)| a.semitones() == b.semitones() && a.quarter_tone() == b.quarter_tone())
+                            {
                                 result.push(candidate_chord_root);
                             }
 
@@ -398,7 +458,12 @@ impl Chord {
                                 .with_crunchy(is_crunchy);
                             let candidate_chord_slash_notes = candidate_chord_slash.chord();
 
-                            if notes.len() == candidate_chord_slash_notes.len() && notes.iter().zip(&candidate_chord_slash.chord()).all(|(a, b)| a.frequency() == b.frequency()) {
+                            if notes.len() == candidate_chord_slash_notes.len()
+                                && notes
+                                    .iter()
+                                    .zip(&candidate_chord_slash.chord())
+                                    .all(|(a, b)| a.semitones() == b.semitones() && a.quarter_tone() == b.quarter_tone())
+                            {
                                 result.push(candidate_chord_slash);
                             }
                         }
@@ -434,6 +499,16 @@ impl Chord {
                 c.modifiers.remove(&Modifier::Flat5);
                 c.modifiers.remove(&Modifier::Augmented5);
             }
+
+            // Omitted tones cannot be altered.
+            if c.extensions.contains(&Extension::No3) {
+                c.modifiers.remove(&Modifier::Minor);
+            }
+
+            if c.extensions.contains(&Extension::No5) {
+                c.modifiers.remove(&Modifier::Flat5);
+                c.modifiers.remove(&Modifier::Augmented5);
+            }
         });
 
         // Order the candidates by "simplicity" (i.e., least slashes, least extensions, least modifiers, and least inversion).
@@ -446,51 +521,190 @@ impl Chord {
     }
 }
 
+impl Chord {
+    /// Returns the extended chord that has the same tones as this polychord (e.g., `C13(♯11)` for `D/C7`), if there is one.
+    pub fn equivalent(&self) -> Option<Chord> {
+        self.upper.as_ref()?;
+
+        // Drop doubled tones, and move the rest into the octave above the bass, so that the chord can be identified.
+        let notes = self.chord();
+        let bass = *notes.first()?;
+        let mut close = Vec::<Note>::new();
+
+        for note in notes {
+            let moved = Note::from_semitones(bass.semitones() + (note.semitones() - bass.semitones()).rem_euclid(12))
+                .and_then(|n| n.enharmonic(note.named_pitch()))
+                .unwrap_or(note);
+
+            if !close.iter().any(|n| n.semitones() == moved.semitones()) {
+                close.push(moved);
+            }
+        }
+
+        Chord::try_from_notes(&close).ok()?.into_iter().next()
+    }
+
+    /// Transposes the chord by the interval (down, if `descending`), returning an error if the chord would leave the range of octaves.
+    pub fn checked_transpose(mut self, interval: Interval, descending: bool) -> Res<Self> {
+        self.root = transpose_note(self.root, interval, descending)?;
+        self.slash = self.slash.map(|s| transpose_note(s, interval, descending)).transpose()?;
+        self.upper = self.upper.map(|u| u.checked_transpose(interval, descending).map(Box::new)).transpose()?;
+
+        Ok(self)
+    }
+
+    /// Returns the name of the extended chord that has the same tones as this polychord (e.g., `C13(♯11)` for `D/C7`), if there is one.
+    pub fn equivalent_name(&self) -> Option<String> {
+        self.equivalent_name_with(&NamingOptions::default())
+    }
+
+    /// Returns the name of the chord, written with the given [`NamingOptions`] (e.g., `Cø7` in the jazz style).
+    pub fn name_with(&self, options: &NamingOptions) -> String {
+        self.clone().respelled(options.spelling()).write_name(options.style())
+    }
+
+    /// Returns the precise name of the chord (see [`HasPreciseName`]), written with the given [`NamingOptions`].
+    pub fn precise_name_with(&self, options: &NamingOptions) -> String {
+        let mut name = self.name_with(options);
+
+        // Add octave modifier.
+        if self.root.octave() != Octave::Four {
+            name.push_str(&format!("@{}", self.root.octave().static_name()));
+        }
+
+        // Add inversion modifier.
+        if self.inversion != 0 {
+            name.push_str(&format!("^{}", self.inversion));
+        }
+
+        // Add crunchy modifier.
+        if self.is_crunchy {
+            name.push('!');
+        }
+
+        name
+    }
+
+    /// Returns the name of the equivalent extended chord (see [`Chord::equivalent_name`]), written with the given [`NamingOptions`].
+    pub fn equivalent_name_with(&self, options: &NamingOptions) -> Option<String> {
+        self.equivalent().map(|e| e.name_with(options))
+    }
+
+    /// Returns the notes of the chord's scale, respelled with the given [`NamingOptions`].
+    pub fn scale_with(&self, options: &NamingOptions) -> Vec<Note> {
+        self.scale().into_iter().map(|n| n.respelled(options.spelling())).collect()
+    }
+
+    /// Returns the notes of the chord, respelled with the given [`NamingOptions`].
+    pub fn chord_with(&self, options: &NamingOptions) -> Vec<Note> {
+        self.chord().into_iter().map(|n| n.respelled(options.spelling())).collect()
+    }
+
+    /// Returns the description of the chord (i.e., its name, description, scale, and notes), written with the given [`NamingOptions`].
+    pub fn to_string_with(&self, options: &NamingOptions) -> String {
+        let mut result = String::new();
+
+        self.write_with(&mut result, options).expect("Writing to a string cannot fail.");
+
+        result
+    }
+
+    /// Writes the description of the chord (see [`Chord::to_string_with`]).
+    fn write_with(&self, f: &mut impl Write, options: &NamingOptions) -> fmt::Result {
+        let scale = self.scale_with(options).iter().map(|n| n.static_name()).collect::<Vec<_>>().join(", ");
+        let chord = self.chord_with(options).iter().map(|n| n.static_name()).collect::<Vec<_>>().join(", ");
+
+        let name = match self.equivalent_name_with(options) {
+            Some(equivalent) => format!("{} ({})", self.precise_name_with(options), equivalent),
+            None => self.precise_name_with(options),
+        };
+
+        write!(f, "{}\n   {}\n   {}\n   {}", name, self.description(), scale, chord)
+    }
+
+    /// Returns the equivalent of this polychord (see [`Chord::equivalent`]), if it is built on the same root.
+    pub(crate) fn combined(&self) -> Option<Chord> {
+        self.equivalent().filter(|e| e.root.pitch() == self.root.pitch())
+    }
+
+    /// Returns the known chord that determines the scale, which, for a polychord, comes from all of its tones (e.g., `D/C7` is a `C13(♯11)`).
+    fn combined_known_chord(&self) -> KnownChord {
+        match self.combined() {
+            Some(combined) => combined.known_chord(),
+            None => self.known_chord(),
+        }
+    }
+}
+
 impl HasName for Chord {
     fn name(&self) -> String {
-        let known_name = self.known_chord().name();
-        let known_name = known_name.as_str();
-        let mut name = String::new();
+        self.write_name(NameStyle::default())
+    }
+}
+
+impl Chord {
+    /// Writes the name of the chord in the given [`NameStyle`].
+    fn write_name(&self, style: NameStyle) -> String {
+        let mut known_name = self.known_chord().name();
+
+        // Major 7 chords carry their dominant degree on the `maj` (e.g., `maj9`).
+        if let Some(degree) = self.dominant_degree().filter(|d| *d != Degree::Seven && known_name.contains("maj7")) {
+            known_name = known_name.replace("maj7", &format!("maj{}", degree.static_name()));
+        }
 
-        name.push_str(self.root.static_name());
+        // Power chords are major chords without a third, which are written as `5`.
+        let is_power = known_name.is_empty() && self.modifiers.is_empty() && self.extensions.contains(&Extension::No3);
 
-        name.push_str(known_name);
+        if is_power {
+            known_name.push('5');
+        }
+
+        // Split the known name into its quality and its alterations (e.g., `m7(♭5)` is `m7` and `♭5`).
+        let known_name = known_name.as_str();
+        let (quality, mut alterations) = match known_name.split_once('(') {
+            Some((quality, alterations)) => (quality, alterations.trim_end_matches(')').split(")(").collect::<Vec<_>>()),
+            None => (known_name, vec![]),
+        };
 
         // Add special modifiers that are true modifiers when not part of their "special case".
 
         if self.modifiers.contains(&Modifier::Flat5) && !known_name.contains("(♭5)") {
-            name.push_str("(♭5)");
+            alterations.push("♭5");
         }
 
         if self.modifiers.contains(&Modifier::Augmented5) && !known_name.contains('+') {
-            name.push_str("(♯5)");
+            alterations.push("♯5");
         }
 
         if self.modifiers.contains(&Modifier::Flat9) && !known_name.contains("(♭9)") {
-            name.push_str("(♭9)");
+            // Keep the ninths in order, even when the sharp 9 is part of the known name (e.g., `7(♭9)(♯9)`).
+            let position = alterations.iter().position(|a| *a == "♯9").unwrap_or(alterations.len());
+
+            alterations.insert(position, "♭9");
         }
 
         if self.modifiers.contains(&Modifier::Sharp9) && !known_name.contains("(♯9)") {
-            name.push_str("(♯9)");
+            alterations.push("♯9");
         }
 
         if self.modifiers.contains(&Modifier::Sharp11) && !known_name.contains("(♯11)") {
-            name.push_str("(♯11)");
+            alterations.push("♯11");
         }
 
         // Add extensions.
-        if !self.extensions.is_empty() {
-            for e in self.extensions.iter() {
-                name.push_str(&format!("({})", e.static_name()));
-            }
+        for e in self.extensions.iter().filter(|e| !is_power || **e != Extension::No3) {
+            alterations.push(e.static_name());
         }
 
-        // Add slash note.
-        if let Some(slash) = self.slash {
-            name.push_str(&format!("/{}", slash.static_name()));
-        }
+        let slash = self.slash.map(|s| s.static_name());
+        let mut name = style.write(self.root.static_name(), quality, &alterations, slash);
 
-        // Add special information about the chord.
+        // Add the upper structure of a polychord (over a bare triad, `|` is used, since `/` would make it a slash chord).
+        if let Some(upper) = &self.upper {
+            let separator = if quality.is_empty() && alterations.is_empty() && slash.is_none() { '|' } else { '/' };
+
+            name = format!("{}{}{}", upper.write_name(style), separator, name);
+        }
 
         name
     }
@@ -498,26 +712,7 @@ impl HasName for Chord {
 
 impl HasPreciseName for Chord {
     fn precise_name(&self) -> String {
-        let mut name = String::new();
-
-        name.push_str(&self.name());
-
-        // Add octave modifier.
-        if self.root.octave() != Octave::Four {
-            name.push_str(&format!("@{}", self.root.octave().static_name()));
-        }
-
-        // Add inversion modifier.
-        if self.inversion != 0 {
-            name.push_str(&format!("^{}", self.inversion));
-        }
-
-        // Add crunchy modifier.
-        if self.is_crunchy {
-            name.push('!');
-        }
-
-        name
+        self.precise_name_with(&NamingOptions::default())
     }
 }
 
@@ -533,6 +728,12 @@ impl HasSlash for Chord {
     }
 }
 
+impl HasUpper for Chord {
+    fn upper(&self) -> Option<&Chord> {
+        self.upper.as_deref()
+    }
+}
+
 impl HasModifiers for Chord {
     fn modifiers(&self) -> &HashSet<Modifier> {
         &self.modifiers
@@ -559,10 +760,7 @@ impl HasIsCrunchy for Chord {
 
 impl Display for Chord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let scale = self.scale().iter().map(|n| n.static_name()).collect::<Vec<_>>().join(", ");
-        let chord = self.chord().iter().map(|n| n.static_name()).collect::<Vec<_>>().join(", ");
-
-        write!(f, "{}\n   {}\n   {}\n   {}", self.precise_name(), self.description(), scale, chord)
+        self.write_with(f, &NamingOptions::default())
     }
 }
 
@@ -621,8 +819,14 @@ impl Chordable for Chord {
         self
     }
 
+    fn with_upper(mut self, upper: Chord) -> Chord {
+        self.upper = Some(Box::new(upper));
+
+        self
+    }
+
     fn with_octave(self, octave: Octave) -> Self {
-        let root = Note::new(self.root.named_pitch(), octave);
+        let root = self.root.with_octave(octave);
 
         Chord { root, ..self }
     }
@@ -840,6 +1044,26 @@ impl Chordable for Chord {
     fn add_thirteen(self) -> Chord {
         self.add13()
     }
+
+    fn no3(self) -> Chord {
+        self.with_extension(Extension::No3)
+    }
+
+    fn no_three(self) -> Chord {
+        self.no3()
+    }
+
+    fn power(self) -> Chord {
+        self.no3()
+    }
+
+    fn no5(self) -> Chord {
+        self.with_extension(Extension::No5)
+    }
+
+    fn no_five(self) -> Chord {
+        self.no5()
+    }
 }
 
 impl HasKnownChord for Chord {
@@ -884,7 +1108,10 @@ impl HasKnownChord for Chord {
             }
 
             if contains_dominant {
-                if modifiers.contains(&Modifier::Flat9) {
+                // With both ninths and a flat 13 (i.e., `alt`), only the altered scale fits (the diminished scale has a natural 13).
+                let is_altered = modifiers.contains(&Modifier::Sharp9) && self.extensions.contains(&Extension::Flat13);
+
+                if modifiers.contains(&Modifier::Flat9) && !is_altered {
                     return KnownChord::DominantFlat9(degree);
                 }
 
@@ -906,13 +1133,19 @@ impl HasKnownChord for Chord {
 
 impl HasDescription for Chord {
     fn description(&self) -> &'static str {
-        self.known_chord().description()
+        self.combined_known_chord().description()
+    }
+}
+
+impl HasKnownScale for Chord {
+    fn known_scale(&self) -> Scale {
+        self.combined_known_chord().known_scale()
     }
 }
 
 impl HasRelativeScale for Chord {
     fn relative_scale(&self) -> Vec<Interval> {
-        self.known_chord().relative_scale()
+        self.combined_known_chord().relative_scale()
     }
 }
 
@@ -956,6 +1189,8 @@ impl HasRelativeChord for Chord {
         }
 
         if modifiers.contains(&Modifier::Sharp11) {
+            // The sharp 11 replaces the natural 11 of dominant 11 and 13 chords.
+            result.retain(|i| *i != Interval::PerfectEleventh);
             result.push(Interval::AugmentedEleventh);
         }
 
@@ -1007,6 +1242,16 @@ impl HasRelativeChord for Chord {
             result.push(Interval::MajorThirteenth);
         }
 
+        // Omissions.
+
+        if extensions.contains(&Extension::No3) {
+            result.retain(|i| !matches!(i, Interval::MinorThird | Interval::MajorThird));
+        }
+
+        if extensions.contains(&Extension::No5) {
+            result.retain(|i| !matches!(i, Interval::DiminishedFifth | Interval::PerfectFifth | Interval::AugmentedFifth));
+        }
+
         // Keep everything in order.
         result.sort();
         result.dedup();
@@ -1052,7 +1297,11 @@ impl HasChord for Chord {
         if let Some(mut slash) = self.slash {
             // Fix slash note (it should be less than, or equal to, one octave away from the bottom tone).
             let bottom = *result.first().unwrap_or(&CZero);
-            let floor = Note::new(bottom.named_pitch(), bottom.octave() - 1);
+            let floor = if bottom.octave() == Octave::Zero {
+                CZero
+            } else {
+                Note::new(bottom.named_pitch(), bottom.octave() - 1)
+            };
 
             slash = slash.with_octave(Octave::Zero);
             while slash < floor {
@@ -1062,6 +1311,18 @@ impl HasChord for Chord {
             result.insert(0, slash);
         }
 
+        // Stack the upper structure (of a polychord) above the rest of the chord.
+        if let Some(upper) = &self.upper {
+            let top = result.iter().max().copied().unwrap_or(CZero);
+            let mut notes = upper.chord();
+
+            while notes.first().is_some_and(|n| *n <= top) {
+                notes.iter_mut().for_each(|n| *n += Interval::PerfectOctave);
+            }
+
+            result.extend(notes);
+        }
+
         // Crunchiness, etc. can introduce changes, so resort, and dedup.
         result.sort();
         result.dedup();
@@ -1099,7 +1360,17 @@ impl Parsable for Chord {
 
         let mut components = root.into_inner();
 
-        let note = components.next().unwrap();
+        let mut note = components.next().unwrap();
+        let mut upper = None;
+
+        // Polychords start with the upper chord, and a separator.
+        if note.as_rule() == Rule::upper {
+            upper = Some(Chord::parse(note.as_str())?);
+
+            assert_eq!(Rule::polychord, components.next().unwrap().as_rule());
+
+            note = components.next().unwrap();
+        }
 
         assert_eq!(Rule::note, note.as_rule());
 
@@ -1108,8 +1379,15 @@ impl Parsable for Chord {
         while let Some(component) = components.next() {
             match component.as_rule() {
                 Rule::maj7_modifier => {
-                    result = result.major7();
+                    result = match component.as_str().trim_start_matches(|c: char| !c.is_ascii_digit()) {
+                        "" | "7" => result.major7(),
+                        "9" => result.major7().nine(),
+                        "11" => result.major7().eleven(),
+                        "13" => result.major7().thirteen(),
+                        _ => unreachable!(),
+                    };
                 }
+                Rule::major => {}
                 Rule::minor => {
                     result = result.minor();
                 }
@@ -1122,6 +1400,9 @@ impl Parsable for Chord {
                 Rule::half_diminished => {
                     result = result.half_diminished();
                 }
+                Rule::power_chord => {
+                    result = result.power();
+                }
                 Rule::dominant_modifier => match component.as_str() {
                     "7" => {
                         result = result.seven();
@@ -1143,7 +1424,7 @@ impl Parsable for Chord {
                     "sus2" => {
                         result = result.sus2();
                     }
-                    "sus4" => {
+                    "sus4" | "sus" => {
                         result = result.sus4();
                     }
                     "add2" => {
@@ -1155,6 +1436,9 @@ impl Parsable for Chord {
                     "add6" | "6" => {
                         result = result.add6();
                     }
+                    "6/9" | "69" => {
+                        result = result.add6().add9();
+                    }
                     "b5" | "♭5" => {
                         result = result.flat5();
                     }
@@ -1164,30 +1448,44 @@ impl Parsable for Chord {
                     "add9" => {
                         result = result.add9();
                     }
-                    "b9" | "♭9" => {
+                    "b9" | "♭9" | "addb9" | "add♭9" => {
                         result = result.flat9();
                     }
-                    "#9" | "♯9" => {
+                    "#9" | "♯9" | "add#9" | "add♯9" => {
                         result = result.sharp9();
                     }
                     "add11" => {
                         result = result.add11();
                     }
-                    "b11" | "♭11" => {
+                    "b11" | "♭11" | "addb11" | "add♭11" => {
                         result = result.flat11();
                     }
-                    "#11" | "♯11" => {
+                    "#11" | "♯11" | "add#11" | "add♯11" => {
                         result = result.sharp11();
                     }
                     "add13" => {
                         result = result.add13();
                     }
-                    "b13" | "♭13" => {
+                    "b13" | "♭13" | "addb13" | "add♭13" => {
                         result = result.flat13();
                     }
-                    "#13" | "♯13" => {
+                    "#13" | "♯13" | "add#13" | "add♯13" => {
                         result = result.sharp13();
                     }
+                    "alt" => {
+                        // Altered chords carry all of the altered tensions (i.e., the altered scale), and imply the seventh.
+                        if result.dominant_degree().is_none() {
+                            result = result.seven();
+                        }
+
+                        result = result.flat9().sharp9().sharp11().flat13();
+                    }
+                    "omit3" | "no3" => {
+                        result = result.no3();
+                    }
+                    "omit5" | "no5" => {
+                        result = result.no5();
+                    }
                     _ => {
                         unreachable!();
                     }
@@ -1217,51 +1515,64 @@ impl Parsable for Chord {
             }
         }
 
+        if let Some(upper) = upper {
+            result = result.with_upper(upper);
+        }
+
         Ok(result)
     }
 }
 
 #[cfg(feature = "audio")]
-use super::base::{Playable, PlaybackHandle};
+use super::{
+    base::{Playable, PlaybackHandle},
+    tuning::Tuning,
+};
 
 #[cfg(feature = "audio")]
 impl Playable for Chord {
     #[no_coverage]
-    fn play(&self, delay: f32, length: f32, fade_in: f32) -> Res<PlaybackHandle> {
-        use rodio::{source::SineWave, OutputStream, Sink, Source};
-        use std::time::Duration;
-
-        let chord_tones = self.chord();
+    fn play_in(&self, tuning: &Tuning, delay: f32, length: f32, fade_in: f32) -> Res<PlaybackHandle> {
+        play_notes(&self.chord(), tuning, delay, length, fade_in)
+    }
+}
 
-        if length <= chord_tones.len() as f32 * delay {
-            return Err(anyhow::Error::msg(
-                "The delay is too long for the length of play (i.e., the number of chord tones times the delay is longer than the length).",
-            ));
-        }
+/// Plays the given notes (e.g., the tones of a [`Chord`]) in the given [`Tuning`], with each successive note delayed by `delay`.
+#[cfg(feature = "audio")]
+#[no_coverage]
+pub(crate) fn play_notes(notes: &[Note], tuning: &Tuning, delay: f32, length: f32, fade_in: f32) -> Res<PlaybackHandle> {
+    use crate::core::pitch::HasFrequency;
+    use rodio::{source::SineWave, OutputStream, Sink, Source};
+    use std::time::Duration;
 
-        let (stream, stream_handle) = OutputStream::try_default()?;
+    if length <= notes.len() as f32 * delay {
+        return Err(anyhow::Error::msg(
+            "The delay is too long for the length of play (i.e., the number of chord tones times the delay is longer than the length).",
+        ));
+    }
 
-        let mut sinks = vec![];
+    let (stream, stream_handle) = OutputStream::try_default()?;
 
-        for (k, n) in chord_tones.into_iter().enumerate() {
-            let sink = Sink::try_new(&stream_handle)?;
+    let mut sinks = vec![];
 
-            let d = k as f32 * delay;
+    for (k, n) in notes.iter().enumerate() {
+        let sink = Sink::try_new(&stream_handle)?;
 
-            let source = SineWave::new(n.frequency())
-                .take_duration(Duration::from_secs_f32(length - d))
-                .buffered()
-                .delay(Duration::from_secs_f32(d))
-                .fade_in(Duration::from_secs_f32(fade_in))
-                .amplify(0.20);
+        let d = k as f32 * delay;
 
-            sink.append(source);
+        let source = SineWave::new(n.frequency_in(tuning))
+            .take_duration(Duration::from_secs_f32(length - d))
+            .buffered()
+            .delay(Duration::from_secs_f32(d))
+            .fade_in(Duration::from_secs_f32(fade_in))
+            .amplify(0.20);
 
-            sinks.push(sink);
-        }
+        sink.append(source);
 
-        Ok(PlaybackHandle::new(stream, stream_handle, sinks))
+        sinks.push(sink);
     }
+
+    Ok(PlaybackHandle::new(stream, stream_handle, sinks))
 }
 
 impl Default for Chord {
@@ -1270,12 +1581,72 @@ impl Default for Chord {
     }
 }
 
+impl Add<Interval> for Chord {
+    type Output = Self;
+
+    fn add(self, rhs: Interval) -> Self::Output {
+        self.checked_transpose(rhs, false).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl Sub<Interval> for Chord {
+    type Output = Self;
+
+    fn sub(self, rhs: Interval) -> Self::Output {
+        self.checked_transpose(rhs, true).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl Respellable for Chord {
+    fn respelled(mut self, spelling: Spelling) -> Self {
+        let root = self.root.respelled(spelling);
+
+        self.slash = match spelling {
+            // Respell the slash note by the same amount as the root, so that it stays "in key" with the root.
+            Spelling::Simplest => {
+                let shift = root.named_pitch() as i8 - self.root.named_pitch() as i8;
+
+                self.slash.map(|s| {
+                    let index = usize::try_from(s.named_pitch() as i8 + shift).ok();
+
+                    index.and_then(|k| ALL_NAMED_PITCHES.get(k)).and_then(|p| s.enharmonic(*p)).unwrap_or(s)
+                })
+            }
+            _ => self.slash.map(|s| s.respelled(spelling)),
+        };
+
+        self.upper = self.upper.map(|u| Box::new(u.respelled(spelling)));
+        self.root = root;
+
+        self
+    }
+}
+
+// Helpers.
+
+/// Transposes a note of a chord by the interval.  Chords stay in their octave when transposed (e.g., `B♭` up a major
+/// second is `C`, rather than `C@5`), apart from the whole octaves of compound intervals.
+fn transpose_note(note: Note, interval: Interval, descending: bool) -> Res<Note> {
+    let octaves = (interval.number() as i8 - 1) / 7;
+    let octave = if descending { note.octave() as i8 - octaves } else { note.octave() as i8 + octaves };
+    let octave = u8::try_from(octave)
+        .ok()
+        .and_then(|o| Octave::try_from(o).ok())
+        .ok_or_else(|| anyhow::Error::msg(format!("Transposing {} by a {} leaves the range of octaves.", note, interval.name())))?;
+
+    // The interval is applied in a middle octave, so that only the pitch is taken from it.
+    let middle = note.with_octave(Octave::Four);
+    let transposed = if descending { middle - interval } else { middle + interval };
+
+    Ok(transposed.with_octave(octave))
+}
+
 // Tests.
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{note::*, octave::HasOctave};
+    use crate::core::{key::Key, note::*, octave::HasOctave};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -1329,6 +1700,9 @@ mod tests {
         assert_eq!(Chord::new(C).sus4().known_chord(), KnownChord::Major);
         assert_eq!(Chord::new(C).sustain().known_chord(), KnownChord::Major);
         assert_eq!(Chord::new(C).seven().sus().known_chord(), KnownChord::Dominant(Degree::Seven));
+
+        assert_eq!(Chord::new(C).minor().seven().known_scale(), Scale::Dorian);
+        assert_eq!(Chord::new(C).seven().sharp9().known_scale(), Scale::Altered);
     }
 
     #[test]
@@ -1459,6 +1833,70 @@ mod tests {
         assert_eq!(Chord::parse("Dmb11").unwrap().chord(), vec![D, F, A, GFlatFive]);
         assert_eq!(Chord::parse("D(b13)").unwrap().chord(), vec![D, FSharp, A, BFlatFive]);
         assert_eq!(Chord::parse("D(#13)").unwrap().chord(), vec![D, FSharp, A, BSharpFive]);
+
+        // Quarter-tone roots inflect every chord tone.
+        let half_flat = |n: Note| n.with_quarter_tone(QuarterTone::HalfFlat);
+
+        assert_eq!(Chord::parse("Eqf7").unwrap().chord(), vec![half_flat(E), half_flat(GSharp), half_flat(B), half_flat(DFive)]);
+        assert_eq!(Chord::parse("E𝄳7/G#qf").unwrap().name(), "E𝄳7/G♯𝄳");
+        assert_eq!(Chord::parse("Eqf7").unwrap().with_octave(Octave::Three).root(), half_flat(EThree));
+    }
+
+    #[test]
+    fn test_parse_dialects() {
+        let same = |a: &str, b: &str| assert_eq!(Chord::parse(a).unwrap(), Chord::parse(b).unwrap(), "`{}` should parse as `{}`", a, b);
+
+        for symbol in ["CΔ", "CΔ7", "CM7", "CMaj7"] {
+            same(symbol, "Cmaj7");
+        }
+
+        same("CΔ9", "Cmaj9");
+        same("Cmi7", "Cm7");
+        same("Cmin7", "C-7");
+        same("CmMaj7", "Cmmaj7");
+        same("C-Δ7", "Cmmaj7");
+        same("C°7", "Cdim");
+        same("Co7", "Cdim7");
+        same("Csus", "Csus4");
+        same("Caug", "C+");
+        same("C6/9", "C69");
+        same("Cadd#11", "C(#11)");
+        same("Cadd♭9", "C(b9)");
+        same("C7alt", "C7b9#9#11b13");
+        same("Calt", "C7alt");
+        same("CM", "C");
+        same("Cmaj", "C");
+        same("CM6", "C6");
+        same("C(omit3)", "C5");
+        same("Comit3", "C5");
+        same("Cno5", "C(omit5)");
+
+        assert_eq!(Chord::parse("Cmaj9").unwrap().chord(), vec![C, E, G, B, DFive]);
+        assert_eq!(Chord::parse("Cmaj13").unwrap().chord(), vec![C, E, G, B, DFive, FFive, AFive]);
+        assert_eq!(Chord::parse("Cm6").unwrap().chord(), vec![C, EFlat, G, A]);
+        assert_eq!(Chord::parse("C6/9").unwrap().chord(), vec![C, E, G, A, DFive]);
+        assert_eq!(Chord::parse("C6/G").unwrap().chord(), vec![GThree, C, E, G, A]);
+        assert_eq!(Chord::parse("C13sus4").unwrap().chord(), vec![C, F, G, BFlat, DFive, FFive, AFive]);
+        assert_eq!(Chord::parse("C7sus4b9").unwrap().chord(), vec![C, F, G, BFlat, DFlatFive]);
+        assert_eq!(Chord::parse("C5").unwrap().chord(), vec![C, G]);
+        assert_eq!(Chord::parse("Bb5").unwrap().chord(), vec![BFlat, FFive]);
+        assert_eq!(Chord::parse("C7(no5)").unwrap().chord(), vec![C, E, BFlat]);
+        assert_eq!(Chord::parse("Cm7omit3").unwrap().chord(), vec![C, G, BFlat]);
+
+        // Names round-trip through the parser.
+        for symbol in ["CΔ9", "Cm(maj11)", "C+(maj13)", "C7alt", "C5", "C5(add9)", "Cm7(no5)", "C7(omit3)", "C6/9", "Cadd#11", "C°7", "Cmi7b5"] {
+            let chord = Chord::parse(symbol).unwrap();
+
+            assert_eq!(Chord::parse(&chord.name()).unwrap(), chord, "`{}` does not round-trip through `{}`", symbol, chord.name());
+        }
+
+        assert_eq!(Chord::parse("CΔ9").unwrap().name(), "Cmaj9");
+        assert_eq!(Chord::parse("CmMaj9").unwrap().name(), "Cm(maj9)");
+        assert_eq!(Chord::parse("C5").unwrap().name(), "C5");
+        assert_eq!(Chord::parse("C(no3)").unwrap().name(), "C5");
+        assert_eq!(Chord::parse("C7(no3)").unwrap().name(), "C7(no3)");
+        assert_eq!(Chord::parse("C7alt").unwrap().name(), "C7(♭9)(♯9)(♯11)(♭13)");
+        assert_eq!(Chord::parse("C7alt").unwrap().scale(), Chord::parse("C7#9").unwrap().scale());
     }
 
     #[test]
@@ -1477,6 +1915,134 @@ mod tests {
             Chord::parse("C13").unwrap().chord()
         );
         assert_eq!(Chord::try_from_notes(&[C, EFlat, GFlat, A]).unwrap().first().unwrap().chord(), Chord::parse("Cdim").unwrap().chord());
+        assert_eq!(Chord::try_from_notes(&[C, E, G, B, DFive]).unwrap().first().unwrap().name(), "Cmaj9");
+
+        // Shell voicings leave out the third or fifth.
+        assert_eq!(Chord::try_from_notes(&[C, E, BFlat]).unwrap().first().unwrap().name(), "C7(no5)");
+        assert_eq!(Chord::try_from_notes(&[C, G, BFlat]).unwrap().first().unwrap().name(), "C7(no3)");
+        assert_eq!(Chord::try_from_notes(&[C, EFlat, BFlat]).unwrap().first().unwrap().name(), "Cm7(no5)");
+        assert_eq!(
+            Chord::try_from_notes(&[C, E, B, DFive]).unwrap().first().unwrap().chord(),
+            Chord::parse("Cmaj9(omit5)").unwrap().chord()
+        );
+        assert_eq!(
+            Chord::try_from_notes(&[C, E, A, BFlat]).unwrap().first().unwrap().chord(),
+            Chord::parse("C7(add13)(no5)!").unwrap().chord()
+        );
+        assert!(Chord::try_from_notes(&[C, E, BFlat]).unwrap().iter().filter(|c| c.root() == C).all(|c| c.name() == "C7(no5)"));
+
+        // Only seventh chords leave out tones, and complete triads are not read as shells.
+        let is_omitted = |c: &Chord| c.extensions.contains(&Extension::No3) || c.extensions.contains(&Extension::No5);
+
+        assert!(Chord::try_from_notes(&[C, E, G]).unwrap().iter().all(|c| !is_omitted(c)));
+        assert!(Chord::try_from_notes(&[C, E, BFlat]).unwrap().iter().filter(|c| is_omitted(c)).all(|c| c.dominant_degree().is_some()));
+        assert!(Chord::try_from_notes(&[C, E, BFlat]).unwrap().iter().all(|c| c.name() != "E(♭5)(no3)/C"));
+
+        // Power chords only need two notes.
+        assert_eq!(Chord::try_from_notes(&[C, G]).unwrap(), vec![Chord::new(C).power()]);
+        assert_eq!(Chord::try_from_notes(&[GThree, C]).unwrap()[0].chord(), vec![GThree, C]);
+        assert_eq!(Chord::try_from_notes(&[GThree, C]).unwrap()[0].precise_name(), "C5@3^1");
+        assert!(Chord::try_from_notes(&[CZero, CZero + Interval::PerfectFourth]).is_err());
+        assert_eq!(Chord::try_from_notes(&[CZero, CZero + Interval::MajorThird, CZero + Interval::PerfectFifth]).unwrap()[0].root(), CZero);
+    }
+
+    #[test]
+    fn test_polychords() {
+        let chord = Chord::parse("D/C7").unwrap();
+
+        assert_eq!(chord.upper(), Some(&Chord::new(D)));
+        assert_eq!(chord.root(), C);
+        assert_eq!(chord.slash(), C);
+        assert_eq!(chord.chord(), vec![C, E, G, BFlat, DFive, FSharpFive, AFive]);
+        assert_eq!(chord.name(), "D/C7");
+        assert_eq!(chord.equivalent_name().unwrap(), "C13(♯11)");
+        assert_eq!(Chord::parse(&chord.name()).unwrap(), chord);
+        assert_eq!(chord.precise_name(), "D/C7");
+        assert!(chord.to_string().starts_with("D/C7 (C13(♯11))\n"));
+        assert_eq!(chord.equivalent().unwrap(), Chord::parse("C13(#11)").unwrap().with_crunchy(true));
+        assert_eq!(Chord::parse("D|C7").unwrap(), chord);
+        assert_eq!(Chord::new(C).seven().with_upper(Chord::new(D)), chord);
+
+        // A triad over a bare note is still a slash chord, unless the `|` separator is used.
+        assert_eq!(Chord::parse("D/C").unwrap(), Chord::new(D).with_slash(C));
+        assert_eq!(Chord::parse("D|C").unwrap().precise_name(), "D|C");
+        assert_eq!(Chord::parse("D|C").unwrap().chord(), vec![C, E, G, DFive, FSharpFive, AFive]);
+        assert_eq!(Chord::parse("C6/9").unwrap().upper(), None);
+        assert_eq!(Chord::parse("Am7/G@3").unwrap().upper(), None);
+
+        // Doubled tones are ignored when naming the equivalent chord.
+        assert_eq!(Chord::parse("Eb/C7").unwrap().equivalent_name().unwrap(), "C7(♯9)");
+        assert_eq!(Chord::parse("Ab/C7").unwrap().equivalent().unwrap().chord(), Chord::parse("C7(#9)(b13)!").unwrap().chord());
+
+        // Slashes, octaves, inversions, and transpositions apply to the whole polychord.
+        let chord = Chord::parse("Dm/G7/B@3").unwrap();
+
+        assert_eq!(chord.precise_name(), "Dm/G7/B@3");
+        assert_eq!(chord.chord(), vec![BTwo, GThree, BThree, D, F, DFive, FFive, AFive]);
+        assert_eq!((chord + Interval::PerfectFourth).precise_name(), "Gm/C7/E@3");
+        assert_eq!(Chord::parse(&Chord::parse("F#/C7^1!").unwrap().precise_name()).unwrap(), Chord::parse("F#/C7^1!").unwrap());
+    }
+
+    #[test]
+    fn test_name_styles() {
+        let options = |style: NameStyle| NamingOptions::default().with_style(style);
+        let name = |symbol: &str, style: NameStyle| Chord::parse(symbol).unwrap().name_with(&options(style));
+        let names = |symbol: &str| [NameStyle::Standard, NameStyle::Jazz, NameStyle::Berklee, NameStyle::Ascii, NameStyle::IReal].map(|style| name(symbol, style));
+
+        assert_eq!(names("Cm7b5"), ["Cm7(♭5)", "Cø7", "C-7(b5)", "Cm7b5", "Ch7"]);
+        assert_eq!(names("Cm9b5add2/E"), ["Cm9(♭5)(add2)/E", "Cø9(add2)/E", "C-9(b5,add2)/E", "Cm9b5add2/E", "Ch9add2/E"]);
+        assert_eq!(names("CΔ9"), ["Cmaj9", "CΔ9", "Cmaj9", "Cmaj9", "C^9"]);
+        assert_eq!(names("CmMaj7"), ["Cm(maj7)", "CmΔ7", "C-(maj7)", "Cmmaj7", "C-^7"]);
+        assert_eq!(names("Cdim7"), ["Cdim", "C°7", "Co7", "Cdim", "Co7"]);
+        assert_eq!(names("Bb7#9#11"), ["B♭7(♯9)(♯11)", "B♭7(♯9)(♯11)", "Bb7(#9,#11)", "Bb7#9#11", "Bb7#9#11"]);
+        assert_eq!(names("F#m7"), ["F♯m7", "F♯m7", "F#-7", "F#m7", "F#-7"]);
+        assert_eq!(name("C7sus4", NameStyle::IReal), "C7sus");
+        assert_eq!(name("C6/9", NameStyle::IReal), "C69");
+        assert_eq!(name("Cm6", NameStyle::IReal), "C-6");
+        assert_eq!(name("C+maj7", NameStyle::IReal), "C^7#5");
+
+        assert_eq!(name("Cm7b5", NameStyle::Latex), "$\\mathrm{C}\\mathrm{m}^{7(\\flat 5)}$");
+        assert_eq!(name("Bbmaj9/D", NameStyle::Latex), "$\\mathrm{B}\\flat^{\\mathrm{maj}9}/\\mathrm{D}$");
+        assert_eq!(name("Cdim", NameStyle::Latex), "$\\mathrm{C}^{\\circ 7}$");
+        assert_eq!(name("C", NameStyle::Latex), "$\\mathrm{C}$");
+
+        // Styles carry through polychords, and precise names.
+        assert_eq!(name("D/C7", NameStyle::Ascii), "D/C7");
+        assert_eq!(Chord::parse("D/C7").unwrap().equivalent_name_with(&options(NameStyle::Ascii)).unwrap(), "C13#11");
+        assert_eq!(Chord::parse("Ebm7b5@3^1!").unwrap().precise_name_with(&options(NameStyle::Jazz)), "E♭ø7@3^1!");
+
+        // ASCII names can be parsed again.
+        for symbol in ["Cm9b5add2/E", "C+maj7", "CmMaj9", "F#7#9b13", "Bbqs7", "C5add9", "C7no5", "Cdim", "Ebb13sus4"] {
+            let chord = Chord::parse(symbol).unwrap();
+
+            assert_eq!(Chord::parse(&chord.name_with(&options(NameStyle::Ascii))).unwrap(), chord);
+        }
+    }
+
+    #[test]
+    fn test_transpose() {
+        let chord = Chord::parse("Bb7#9/D^1!").unwrap();
+
+        assert_eq!((chord.clone() + Interval::MajorSecond).precise_name(), "C7(♯9)/E^1!");
+        assert_eq!((chord.clone() - Interval::MinorThird).precise_name(), "G7(♯9)/B^1!");
+        assert_eq!((chord.clone() + Interval::AugmentedFourth).precise_name(), "E7(♯9)/G♯^1!");
+        assert_eq!((chord.clone() + Interval::DiminishedFifth).precise_name(), "F♭7(♯9)/A♭^1!");
+        assert_eq!((chord.clone() + Interval::DiminishedFifth).simplified().precise_name(), "E7(♯9)/G♯^1!");
+
+        // Transposed chords stay in their octave (unless the interval spans an octave).
+        assert_eq!(chord.clone() + Interval::MajorSecond, Chord::parse("C7#9/E^1!").unwrap());
+        assert_eq!((chord.clone() + Interval::MajorSecond).chord(), Chord::parse("C7#9/E^1!").unwrap().chord());
+        assert_eq!(Chord::parse("Bb7#9/D").unwrap() + Interval::MajorSecond, Chord::parse("C7#9/E").unwrap());
+        assert_eq!(Chord::parse("C/E").unwrap() - Interval::MajorSecond, Chord::parse("Bb/D").unwrap());
+        assert_eq!((chord.clone() + Interval::PerfectOctave).precise_name(), "B♭7(♯9)/D@5^1!");
+        assert_eq!((chord - Interval::MajorNinth).precise_name(), "A♭7(♯9)/C@3^1!");
+
+        assert_eq!((Chord::new(C).minor() + Interval::PerfectFifth).chord(), vec![G, BFlat, DFive]);
+
+        // Transposing out of the range of octaves is an error.
+        assert_eq!(Chord::parse("C@0").unwrap().checked_transpose(Interval::MinorSecond, true).unwrap().precise_name(), "B@0");
+        assert!(Chord::parse("C@0").unwrap().checked_transpose(Interval::PerfectOctave, true).is_err());
+        assert!(Chord::new(C).with_octave(Octave::Fifteen).checked_transpose(Interval::MajorNinth, false).is_err());
     }
 
     #[test]
@@ -1484,4 +2050,28 @@ mod tests {
     fn test_chord_from_notes_failure() {
         Chord::try_from_notes(&[C, E]).unwrap();
     }
+
+    #[test]
+    fn test_spelling() {
+        let chord = Chord::parse("Bb7#9#11").unwrap();
+        let names = |notes: Vec<Note>| notes.iter().map(|n| n.static_name()).collect::<Vec<_>>().join(" ");
+
+        let options = |spelling: Spelling| NamingOptions::default().with_spelling(spelling);
+
+        assert_eq!(names(chord.scale()), "B♭ C♭ D♭ E𝄫 F♭ G♭ A♭");
+        assert_eq!(names(chord.scale_with(&options(Spelling::NoDoubleAccidentals))), "B♭ C♭ D♭ D F♭ G♭ A♭");
+        assert_eq!(names(chord.scale_with(&options(Spelling::Flats))), "B♭ B D♭ D E G♭ A♭");
+        assert_eq!(names(chord.chord_with(&options(Spelling::Sharps))), "A♯ D F G♯ C♯ E");
+        assert_eq!(names(chord.chord_with(&options(Spelling::Key(Key::major(EFlat))))), "B♭ D F A♭ D♭ E");
+        assert_eq!(chord.name_with(&options(Spelling::Sharps)), "A♯7(♯9)(♯11)");
+
+        let chord = Chord::parse("A#/E#").unwrap();
+
+        assert_eq!(chord.name_with(&options(Spelling::Flats)), "B♭/F");
+        assert_eq!(names(chord.chord_with(&options(Spelling::Flats))), "F B♭ D F");
+
+        // Respelling a chord changes its root (and slash note), so it is a different chord.
+        assert_eq!(Chord::parse("C7").unwrap().respelled(Spelling::Flats), Chord::parse("C7").unwrap());
+        assert_ne!(Chord::parse("A#7").unwrap().respelled(Spelling::Flats), Chord::parse("A#7").unwrap());
+    }
 }
@@ -0,0 +1,382 @@
+//! A module for chord-scale theory (i.e., classifying the tones of a chord's scale as chord tones, available tensions, or avoid notes).
+
+use crate::core::{
+    base::HasStaticName,
+    chord::{Chord, HasChord, HasRoot, HasUpper},
+    interval::{DirectedInterval, Interval, ALL_INTERVALS},
+    known_chord::{HasRelativeChord, HasRelativeScale, KnownChord},
+    named_pitch::HasNamedPitch,
+    note::{CFour, Note},
+};
+
+// Traits.
+
+/// A trait for types that have a degree label (e.g., `R`, `3`, `♭7`, or `♯9`).
+pub trait HasDegreeLabel {
+    /// Returns the degree label of the type (usually an [`Interval`]).
+    fn degree_label(&self) -> &'static str;
+}
+
+/// A trait for types that can classify the tones of their scale (usually a [`Chord`] or [`KnownChord`]).
+pub trait HasScaleTones {
+    /// Returns every tone of the scale, classified as a chord tone, an available tension, or an avoid note.
+    fn scale_tones(&self) -> Vec<ScaleTone>;
+
+    /// Returns the available tensions (i.e., the scale tones that are not chord tones, and that can be added to the chord).
+    fn tensions(&self) -> Vec<Interval> {
+        self.scale_tones().into_iter().filter(|t| t.role == ToneRole::Tension).map(|t| t.interval).collect()
+    }
+
+    /// Returns the avoid notes (i.e., the scale tones that clash with the chord, usually a half step above a chord tone).
+    fn avoid_notes(&self) -> Vec<Interval> {
+        self.scale_tones().into_iter().filter(|t| t.role == ToneRole::AvoidNote).map(|t| t.interval).collect()
+    }
+}
+
+/// A trait for types that have degrees (usually a [`Chord`]).
+pub trait HasDegrees {
+    /// Returns the notes of the chord, along with their intervals above the root.
+    fn degrees(&self) -> Vec<(Note, Interval)>;
+}
+
+// Enum.
+
+/// The role of a scale tone in a chord.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+pub enum ToneRole {
+    /// A tone of the chord.
+    ChordTone,
+    /// An available tension.
+    Tension,
+    /// An avoid note.
+    AvoidNote,
+}
+
+// Structs.
+
+/// A tone of a chord's scale, classified against the chord.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+pub struct ScaleTone {
+    /// The interval above the root (tensions and avoid notes are given as ninths, elevenths, and thirteenths).
+    interval: Interval,
+    /// The role of the tone in the chord.
+    role: ToneRole,
+}
+
+// Impls.
+
+impl ScaleTone {
+    /// Returns the interval above the root.
+    pub fn interval(&self) -> Interval {
+        self.interval
+    }
+
+    /// Returns the role of the tone in the chord.
+    pub fn role(&self) -> ToneRole {
+        self.role
+    }
+}
+
+impl HasDegreeLabel for ScaleTone {
+    fn degree_label(&self) -> &'static str {
+        self.interval.degree_label()
+    }
+}
+
+impl HasStaticName for ToneRole {
+    fn static_name(&self) -> &'static str {
+        match self {
+            ToneRole::ChordTone => "chord tone",
+            ToneRole::Tension => "tension",
+            ToneRole::AvoidNote => "avoid note",
+        }
+    }
+}
+
+impl HasDegreeLabel for Interval {
+    fn degree_label(&self) -> &'static str {
+        match self {
+            Interval::PerfectUnison => "R",
+            Interval::DiminishedSecond => "𝄫2",
+            Interval::AugmentedUnison => "♯1",
+            Interval::MinorSecond => "♭2",
+            Interval::MajorSecond => "2",
+            Interval::DiminishedThird => "𝄫3",
+            Interval::AugmentedSecond => "♯2",
+            Interval::MinorThird => "♭3",
+            Interval::MajorThird => "3",
+            Interval::DiminishedFourth => "♭4",
+            Interval::AugmentedThird => "♯3",
+            Interval::PerfectFourth => "4",
+            Interval::AugmentedFourth => "♯4",
+            Interval::DiminishedFifth => "♭5",
+            Interval::PerfectFifth => "5",
+            Interval::DiminishedSixth => "𝄫6",
+            Interval::AugmentedFifth => "♯5",
+            Interval::MinorSixth => "♭6",
+            Interval::MajorSixth => "6",
+            Interval::DiminishedSeventh => "𝄫7",
+            Interval::AugmentedSixth => "♯6",
+            Interval::MinorSeventh => "♭7",
+            Interval::MajorSeventh => "7",
+            Interval::DiminishedOctave => "♭8",
+            Interval::AugmentedSeventh => "♯7",
+            Interval::PerfectOctave => "8",
+            Interval::MinorNinth => "♭9",
+            Interval::MajorNinth => "9",
+            Interval::AugmentedNinth => "♯9",
+            Interval::DiminishedEleventh => "♭11",
+            Interval::PerfectEleventh => "11",
+            Interval::AugmentedEleventh => "♯11",
+            Interval::MinorThirteenth => "♭13",
+            Interval::MajorThirteenth => "13",
+            Interval::AugmentedThirteenth => "♯13",
+            Interval::PerfectOctaveAndPerfectFifth => "5",
+            Interval::TwoPerfectOctaves => "R",
+            Interval::TwoPerfectOctavesAndMajorThird => "3",
+            Interval::TwoPerfectOctavesAndPerfectFifth => "5",
+            Interval::TwoPerfectOctavesAndMinorSeventh => "♭7",
+            Interval::ThreePerfectOctaves => "R",
+            Interval::ThreePerfectOctavesAndMajorSecond => "9",
+            Interval::ThreePerfectOctavesAndMajorThird => "3",
+            Interval::ThreePerfectOctavesAndAugmentedFourth => "♯11",
+            Interval::ThreePerfectOctavesAndPerfectFifth => "5",
+            Interval::ThreePerfectOctavesAndMinorSixth => "♭13",
+            Interval::ThreePerfectOctavesAndMinorSeventh => "♭7",
+            Interval::ThreePerfectOctavesAndMajorSeventh => "7",
+        }
+    }
+}
+
+impl HasScaleTones for KnownChord {
+    fn scale_tones(&self) -> Vec<ScaleTone> {
+        classify(&self.relative_scale(), &self.relative_chord())
+    }
+}
+
+impl HasScaleTones for Chord {
+    fn scale_tones(&self) -> Vec<ScaleTone> {
+        // A polychord is classified against all of its tones (e.g., `D/C7` as `C13(♯11)`).
+        match self.combined() {
+            Some(combined) => combined.scale_tones(),
+            None => classify(&self.relative_scale(), &self.relative_chord()),
+        }
+    }
+}
+
+impl HasDegrees for Chord {
+    fn degrees(&self) -> Vec<(Note, Interval)> {
+        let root = self.root();
+        let notes = self.chord();
+        let mut relative_chord = self.relative_chord();
+
+        // The upper structure of a polychord is labeled by its intervals above the root (e.g., `D/C7` has a ninth, rather than a second).
+        if self.upper().is_some() {
+            relative_chord.extend(notes.iter().filter(|n| **n > root).filter_map(|n| DirectedInterval::between(root, *n).ok()?.interval()));
+        }
+
+        notes
+            .into_iter()
+            .map(|note| {
+                let class = (note.semitones() - root.semitones()).rem_euclid(12);
+
+                // Prefer the chord's own intervals (e.g., a ninth, rather than a second, even if respelled), and otherwise the simplest interval (e.g., for a slash note).
+                let interval = relative_chord
+                    .iter()
+                    .find(|i| pitch_class(**i) == class)
+                    .or_else(|| ALL_INTERVALS.iter().find(|i| (root + **i).named_pitch() == note.named_pitch()))
+                    .or_else(|| ALL_INTERVALS.iter().find(|i| pitch_class(**i) == class))
+                    .copied()
+                    .unwrap_or(Interval::PerfectUnison);
+
+                (note, interval)
+            })
+            .collect()
+    }
+}
+
+// Helpers.
+
+/// Returns the pitch class of the interval (i.e., the number of semitones, within an octave).
+fn pitch_class(interval: Interval) -> i32 {
+    ((CFour + interval).semitones() - CFour.semitones()).rem_euclid(12)
+}
+
+/// Returns the name of a tension (i.e., seconds, fourths, and sixths as ninths, elevenths, and thirteenths, and thirds and
+/// fifths that clash with the chord's third and fifth as ♯9, ♯11, and ♭13).
+fn tension(interval: Interval, chord: &[Interval]) -> Interval {
+    let has_major_third = chord.contains(&Interval::MajorThird);
+    let has_fifth = chord.iter().any(|i| matches!(i, Interval::PerfectFifth | Interval::AugmentedFifth | Interval::DiminishedFifth));
+
+    match interval {
+        Interval::MinorThird if has_major_third => Interval::AugmentedNinth,
+        Interval::DiminishedFifth if has_fifth => Interval::AugmentedEleventh,
+        Interval::AugmentedFifth if has_fifth => Interval::MinorThirteenth,
+        Interval::MinorSecond => Interval::MinorNinth,
+        Interval::MajorSecond => Interval::MajorNinth,
+        Interval::AugmentedSecond => Interval::AugmentedNinth,
+        Interval::DiminishedFourth => Interval::DiminishedEleventh,
+        Interval::PerfectFourth => Interval::PerfectEleventh,
+        Interval::AugmentedFourth => Interval::AugmentedEleventh,
+        Interval::MinorSixth => Interval::MinorThirteenth,
+        Interval::MajorSixth => Interval::MajorThirteenth,
+        Interval::AugmentedSixth => Interval::AugmentedThirteenth,
+        _ => interval,
+    }
+}
+
+/// Classifies the tones of the scale against the chord.
+///
+/// The third and seventh of the scale are stable, so, if the chord leaves them out (e.g., the E of `C5`, or the B of
+/// `C`), they count as chord tones, rather than tensions (apart from the third of a suspended chord, which is an avoid
+/// note).  The eleventh is an avoid note if there is a major third, and an available tension otherwise.  Any other
+/// scale tone is an avoid note if it is a half step above a chord tone (except for the altered tensions, ♭9 and ♭13, on
+/// dominant chords), and an available tension otherwise.
+fn classify(scale: &[Interval], chord: &[Interval]) -> Vec<ScaleTone> {
+    let chord_classes = chord.iter().map(|i| pitch_class(*i)).collect::<Vec<_>>();
+    let is_dominant = chord.contains(&Interval::MajorThird) && chord.contains(&Interval::MinorSeventh);
+
+    let has_third = chord.iter().any(|i| is_third(*i));
+    let has_seventh = chord.iter().any(|i| is_seventh(*i));
+    let is_suspended = !has_third && chord.iter().any(|i| matches!(i, Interval::MajorSecond | Interval::PerfectFourth));
+    let has_major_third = chord.contains(&Interval::MajorThird) || (!has_third && !is_suspended && scale.contains(&Interval::MajorThird));
+
+    scale
+        .iter()
+        .map(|s| {
+            let class = pitch_class(*s);
+
+            if let Some(c) = chord.iter().find(|c| pitch_class(**c) == class) {
+                return ScaleTone { interval: *c, role: ToneRole::ChordTone };
+            }
+
+            if is_third(*s) && !has_third {
+                let role = if is_suspended { ToneRole::AvoidNote } else { ToneRole::ChordTone };
+
+                return ScaleTone { interval: *s, role };
+            }
+
+            if is_seventh(*s) && !has_seventh {
+                return ScaleTone { interval: *s, role: ToneRole::ChordTone };
+            }
+
+            let interval = tension(*s, chord);
+            let is_altered = is_dominant && (class == 1 || class == 8);
+
+            let role = if interval == Interval::PerfectEleventh {
+                if has_major_third {
+                    ToneRole::AvoidNote
+                } else {
+                    ToneRole::Tension
+                }
+            } else if chord_classes.contains(&(class - 1).rem_euclid(12)) && !is_altered {
+                ToneRole::AvoidNote
+            } else {
+                ToneRole::Tension
+            };
+
+            ScaleTone { interval, role }
+        })
+        .collect()
+}
+
+/// Returns whether the interval is a third.
+fn is_third(interval: Interval) -> bool {
+    matches!(interval, Interval::DiminishedThird | Interval::MinorThird | Interval::MajorThird | Interval::AugmentedThird)
+}
+
+/// Returns whether the interval is a seventh.
+fn is_seventh(interval: Interval) -> bool {
+    matches!(interval, Interval::DiminishedSeventh | Interval::MinorSeventh | Interval::MajorSeventh | Interval::AugmentedSeventh)
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        base::Parsable,
+        note::{Respellable, Spelling},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn labels(intervals: Vec<Interval>) -> Vec<&'static str> {
+        intervals.iter().map(|i| i.degree_label()).collect()
+    }
+
+    fn degrees(symbol: &str) -> Vec<String> {
+        Chord::parse(symbol)
+            .unwrap()
+            .degrees()
+            .iter()
+            .map(|(n, i)| format!("{} ({})", n.static_name(), i.degree_label()))
+            .collect()
+    }
+
+    #[test]
+    fn test_scale_tones() {
+        let tones = KnownChord::Major7.scale_tones();
+
+        assert_eq!(tones.iter().map(|t| t.degree_label()).collect::<Vec<_>>(), vec!["R", "9", "3", "11", "5", "13", "7"]);
+        assert_eq!(
+            tones.iter().map(|t| t.role().static_name()).collect::<Vec<_>>(),
+            vec!["chord tone", "tension", "chord tone", "avoid note", "chord tone", "tension", "chord tone"]
+        );
+
+        assert_eq!(tones[1].interval(), Interval::MajorNinth);
+    }
+
+    #[test]
+    fn test_tensions() {
+        let tensions = |symbol: &str| labels(Chord::parse(symbol).unwrap().tensions());
+        let avoid_notes = |symbol: &str| labels(Chord::parse(symbol).unwrap().avoid_notes());
+
+        assert_eq!(tensions("Cmaj7"), vec!["9", "13"]);
+        assert_eq!(avoid_notes("Cmaj7"), vec!["11"]);
+
+        assert_eq!(tensions("C7"), vec!["9", "13"]);
+        assert_eq!(avoid_notes("C7"), vec!["11"]);
+
+        assert_eq!(tensions("Cm7"), vec!["9", "11", "13"]);
+        assert!(avoid_notes("Cm7").is_empty());
+
+        assert_eq!(tensions("Cm7b5"), vec!["9", "11", "♭13"]);
+        assert_eq!(tensions("C7#11"), vec!["9", "13"]);
+        assert_eq!(tensions("Cdim"), vec!["9", "11", "♭13", "7"]);
+        assert_eq!(avoid_notes("Cm"), vec!["♭13"]);
+
+        // Altered tensions are available on dominant chords.
+        assert_eq!(tensions("C7b9"), vec!["♯9", "♯11", "13"]);
+        assert_eq!(tensions("C7#9"), vec!["♭9", "♯11", "♭13"]);
+
+        assert!(Chord::parse("C13").unwrap().scale_tones().iter().all(|t| t.role() == ToneRole::ChordTone));
+
+        // The stable third and seventh are never tensions, even if the chord leaves them out.
+        assert_eq!(tensions("C"), vec!["9", "13"]);
+        assert_eq!(avoid_notes("C"), vec!["11"]);
+        assert_eq!(tensions("C5"), tensions("C"));
+        assert_eq!(avoid_notes("C5"), avoid_notes("C"));
+        assert_eq!(tensions("C7sus4"), vec!["9", "13"]);
+        assert_eq!(avoid_notes("C7sus4"), vec!["3"]);
+    }
+
+    #[test]
+    fn test_degrees() {
+        assert_eq!(degrees("C7#9"), vec!["C (R)", "E (3)", "G (5)", "B♭ (♭7)", "D♯ (♯9)"]);
+        assert_eq!(degrees("Dm9"), vec!["D (R)", "F (♭3)", "A (5)", "C (♭7)", "E (9)"]);
+        assert_eq!(degrees("C/E"), vec!["E (3)", "C (R)", "E (3)", "G (5)"]);
+        assert_eq!(degrees("C7/Bb"), vec!["B♭ (♭7)", "C (R)", "E (3)", "G (5)", "B♭ (♭7)"]);
+        assert_eq!(degrees("C/Db"), vec!["D♭ (♭2)", "C (R)", "E (3)", "G (5)"]);
+        assert_eq!(degrees("D/C7"), vec!["C (R)", "E (3)", "G (5)", "B♭ (♭7)", "D (9)", "F♯ (♯11)", "A (13)"]);
+
+        let flats = Chord::parse("C7#9").unwrap().degrees();
+        assert_eq!(
+            flats
+                .iter()
+                .map(|(n, i)| format!("{} ({})", n.respelled(Spelling::Flats).static_name(), i.degree_label()))
+                .collect::<Vec<_>>(),
+            vec!["C (R)", "E (3)", "G (5)", "B♭ (♭7)", "E♭ (♯9)"]
+        );
+    }
+}
@@ -0,0 +1,843 @@
+//! A module for working with fretted string instruments (e.g., guitar, bass, ukulele, and mandolin).
+//!
+//! A [`Fretboard`] maps a [`Chord`] onto the strings of an instrument as playable [`Fingering`]s.
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::core::{
+    base::{HasName, HasStaticName, Parsable, Res},
+    chord::{Chord, HasChord, HasRoot, HasSlash},
+    named_pitch::HasNamedPitch,
+    note::{Note, NoteRecreator},
+    octave::Octave,
+    voicing::Tones,
+};
+
+// Statics.
+
+/// The default number of frets on a [`Fretboard`].
+pub const DEFAULT_FRETS: u8 = 15;
+
+/// The default maximum stretch of a [`Fingering`] (i.e., the number of frets that the fretting hand can cover).
+pub const DEFAULT_MAX_STRETCH: u8 = 4;
+
+/// The number of fingers available to fret notes.
+const FINGERS: usize = 4;
+
+// Enum.
+
+/// The style of a chord box diagram.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum DiagramStyle {
+    /// Plain ASCII characters (e.g., `|`, `-`, and `+`).
+    Ascii,
+    /// Unicode box-drawing characters (e.g., `│`, `─`, and `┼`).
+    #[default]
+    Unicode,
+}
+
+// Structs.
+
+/// The tuning of a fretted string instrument (i.e., the open strings, from the lowest string to the highest string).
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct StringTuning {
+    /// The open strings (from the lowest string to the highest string).
+    strings: Vec<Note>,
+}
+
+/// A fingering of a chord on a fretted string instrument.
+///
+/// Each string is either muted (`None`), or played at a fret (where `0` is the open string, or the capo).
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct Fingering {
+    /// The fret of each string (from the lowest string to the highest string).
+    frets: Vec<Option<u8>>,
+}
+
+/// The fretboard of a fretted string instrument.
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct Fretboard {
+    /// The tuning of the instrument.
+    tuning: StringTuning,
+    /// The number of frets.
+    frets: u8,
+    /// The fret at which the capo is placed (`0` for no capo).
+    capo: u8,
+    /// The maximum number of frets that a fingering may span.
+    max_stretch: u8,
+}
+
+// Impls.
+
+impl StringTuning {
+    /// Creates a new [`StringTuning`] from the open strings (from the lowest string to the highest string).
+    pub fn new(strings: Vec<Note>) -> Self {
+        Self { strings }
+    }
+
+    /// Returns the open strings (from the lowest string to the highest string).
+    pub fn strings(&self) -> &[Note] {
+        &self.strings
+    }
+
+    /// Standard guitar tuning (E2 A2 D3 G3 B3 E4).
+    pub fn guitar() -> Self {
+        tuning("E2 A2 D3 G3 B3 E4")
+    }
+
+    /// Drop D guitar tuning (D2 A2 D3 G3 B3 E4).
+    pub fn drop_d() -> Self {
+        tuning("D2 A2 D3 G3 B3 E4")
+    }
+
+    /// Open G guitar tuning (D2 G2 D3 G3 B3 D4).
+    pub fn open_g() -> Self {
+        tuning("D2 G2 D3 G3 B3 D4")
+    }
+
+    /// Open D guitar tuning (D2 A2 D3 F♯3 A3 D4).
+    pub fn open_d() -> Self {
+        tuning("D2 A2 D3 F#3 A3 D4")
+    }
+
+    /// DADGAD guitar tuning (D2 A2 D3 G3 A3 D4).
+    pub fn dadgad() -> Self {
+        tuning("D2 A2 D3 G3 A3 D4")
+    }
+
+    /// Standard four-string bass tuning (E1 A1 D2 G2).
+    pub fn bass() -> Self {
+        tuning("E1 A1 D2 G2")
+    }
+
+    /// Standard (re-entrant) ukulele tuning (G4 C4 E4 A4).
+    pub fn ukulele() -> Self {
+        tuning("G4 C4 E4 A4")
+    }
+
+    /// Standard mandolin tuning (G3 D4 A4 E5).
+    pub fn mandolin() -> Self {
+        tuning("G3 D4 A4 E5")
+    }
+}
+
+impl Default for StringTuning {
+    fn default() -> Self {
+        StringTuning::guitar()
+    }
+}
+
+impl Display for StringTuning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.strings.iter().map(|s| s.name()).collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl Parsable for StringTuning {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        // Allow the known tunings by name.
+        let known = match symbol.trim().to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "guitar" | "standard" => Some(StringTuning::guitar()),
+            "dropd" => Some(StringTuning::drop_d()),
+            "openg" => Some(StringTuning::open_g()),
+            "opend" => Some(StringTuning::open_d()),
+            "dadgad" => Some(StringTuning::dadgad()),
+            "bass" => Some(StringTuning::bass()),
+            "ukulele" | "uke" => Some(StringTuning::ukulele()),
+            "mandolin" => Some(StringTuning::mandolin()),
+            _ => None,
+        };
+
+        if let Some(tuning) = known {
+            return Ok(tuning);
+        }
+
+        // Otherwise, parse the strings (e.g., `EADGBE`, `DADGAD`, or `E2 A2 D3 G3 B3 E4`).
+        let mut tokens = vec![];
+
+        for c in symbol.chars().filter(|c| !c.is_whitespace()) {
+            if c.is_ascii_uppercase() || tokens.is_empty() {
+                tokens.push(String::new());
+            }
+
+            if let Some(token) = tokens.last_mut() {
+                token.push(c);
+            }
+        }
+
+        if tokens.is_empty() {
+            return Err(anyhow::Error::msg("A tuning needs at least one string."));
+        }
+
+        let mut strings: Vec<Note> = vec![];
+
+        for token in tokens {
+            let note = Note::parse(&token).map_err(|_| anyhow::Error::msg(format!("Invalid string `{}` in the tuning `{}`.", token, symbol)))?;
+
+            // Strings without an octave ascend from the second octave (like a guitar).
+            let note = if token.chars().any(|c| c.is_ascii_digit()) {
+                note
+            } else {
+                match strings.last() {
+                    Some(last) => above(*last, note),
+                    None => note.with_octave(Octave::Two),
+                }
+            };
+
+            strings.push(note);
+        }
+
+        Ok(StringTuning::new(strings))
+    }
+}
+
+impl Fingering {
+    /// Creates a new [`Fingering`] from the fret of each string (from the lowest string to the highest string).
+    pub fn new(frets: Vec<Option<u8>>) -> Self {
+        Self { frets }
+    }
+
+    /// Returns the fret of each string (from the lowest string to the highest string), or `None` for muted strings.
+    pub fn frets(&self) -> &[Option<u8>] {
+        &self.frets
+    }
+
+    /// Returns the lowest fretted (i.e., not open) fret, if any.
+    pub fn lowest_fret(&self) -> Option<u8> {
+        self.frets.iter().flatten().filter(|f| **f > 0).min().copied()
+    }
+
+    /// Returns the highest fretted (i.e., not open) fret, if any.
+    pub fn highest_fret(&self) -> Option<u8> {
+        self.frets.iter().flatten().filter(|f| **f > 0).max().copied()
+    }
+
+    /// Returns the number of fingers needed to fret the fingering (barring the lowest fret with one finger, if there are more fretted strings than fingers).
+    pub fn fingers(&self) -> usize {
+        let fretted = self.frets.iter().flatten().filter(|f| **f > 0).count();
+
+        match self.barre() {
+            Some(barre) if fretted > FINGERS => self.frets.iter().flatten().filter(|f| **f > barre).count() + 1,
+            _ => fretted,
+        }
+    }
+
+    /// Returns whether the fingering can be played with four fingers (i.e., any barre does not cross an open string).
+    pub fn is_playable(&self) -> bool {
+        let fretted = self.frets.iter().flatten().filter(|f| **f > 0).count();
+
+        if fretted <= FINGERS {
+            return true;
+        }
+
+        let Some(barre) = self.barre() else {
+            return false;
+        };
+
+        // The barre covers every string from the lowest barred string up.
+        let first = self.frets.iter().position(|f| *f == Some(barre)).unwrap_or(0);
+
+        self.fingers() <= FINGERS && self.frets[first..].iter().flatten().all(|f| *f >= barre)
+    }
+
+    /// Returns the finger (`1` for the index finger) that frets each string, or `None` for muted and open strings.
+    ///
+    /// Lower frets get lower fingers, and the index finger barres the lowest fret when there are more fretted strings than fingers.
+    pub fn finger_numbers(&self) -> Vec<Option<u8>> {
+        let fretted = self.frets.iter().flatten().filter(|f| **f > 0).count();
+        let barre = if fretted > FINGERS { self.barre() } else { None };
+
+        let mut result = self.frets.iter().map(|f| if barre.is_some() && *f == barre { Some(1) } else { None }).collect::<Vec<_>>();
+
+        let mut others = self
+            .frets
+            .iter()
+            .enumerate()
+            .filter_map(|(string, fret)| fret.filter(|f| *f > 0 && Some(*f) != barre).map(|f| (f, string)))
+            .collect::<Vec<_>>();
+        others.sort();
+
+        let first = if barre.is_some() { 2 } else { 1 };
+
+        for (k, (_, string)) in others.into_iter().enumerate() {
+            result[string] = Some(first + k as u8);
+        }
+
+        result
+    }
+
+    /// Renders the fingering as a chord box diagram (strings from the lowest to the highest, and frets from top to bottom).
+    ///
+    /// Muted strings are marked with `x`, open strings with `o`, and fretted strings with finger numbers.
+    pub fn diagram(&self, style: DiagramStyle) -> String {
+        let strings = self.frets.len();
+
+        // Show the first frets (with the nut) if the shape fits, and otherwise start at the lowest fretted fret.
+        let highest = self.highest_fret().unwrap_or(0);
+        let start = if highest <= 4 { 1 } else { self.lowest_fret().unwrap_or(1) };
+        let rows = (highest.saturating_sub(start) + 1).max(4);
+
+        let (string, top, middle, bottom) = match style {
+            DiagramStyle::Ascii => ('|', if start == 1 { "====" } else { "+-++" }, "+-++", "+-++"),
+            DiagramStyle::Unicode => ('│', if start == 1 { "╒═╤╕" } else { "┌─┬┐" }, "├─┼┤", "└─┴┘"),
+        };
+
+        let fingers = self.finger_numbers();
+        let mut lines = vec![];
+
+        lines.push(
+            self.frets
+                .iter()
+                .map(|f| match f {
+                    None => 'x',
+                    Some(0) => 'o',
+                    Some(_) => ' ',
+                })
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim_end()
+                .to_owned(),
+        );
+        lines.push(line(strings, top));
+
+        for row in 0..rows {
+            let fret = start + row;
+
+            let mut text = (0..strings)
+                .map(|k| match (self.frets[k], fingers[k]) {
+                    (Some(f), Some(finger)) if f == fret => char::from_digit(finger as u32, 10).unwrap_or(string),
+                    _ => string,
+                })
+                .map(String::from)
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            if row == 0 && start > 1 {
+                text.push_str(&format!(" {}fr", start));
+            }
+
+            lines.push(text);
+            lines.push(line(strings, if row + 1 == rows { bottom } else { middle }));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Returns the lowest fretted fret, if more than one string is fretted there (and so could be barred).
+    fn barre(&self) -> Option<u8> {
+        let lowest = self.lowest_fret()?;
+
+        if self.frets.iter().filter(|f| **f == Some(lowest)).count() > 1 {
+            Some(lowest)
+        } else {
+            None
+        }
+    }
+}
+
+impl Parsable for Fingering {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let symbol = symbol.trim();
+
+        // Frets above 9 need separators (e.g., `x-10-12-12-11-10`); otherwise, each character is a string (e.g., `x32010`).
+        let tokens = if symbol.contains(['-', ' ', ',']) {
+            symbol.split(['-', ' ', ',']).filter(|t| !t.is_empty()).collect::<Vec<_>>()
+        } else {
+            symbol.split("").filter(|t| !t.is_empty()).collect::<Vec<_>>()
+        };
+
+        if tokens.is_empty() {
+            return Err(anyhow::Error::msg("A tab needs at least one string."));
+        }
+
+        let frets = tokens
+            .into_iter()
+            .map(|t| match t {
+                "x" | "X" => Ok(None),
+                _ => t.parse::<u8>().map(Some).map_err(|_| anyhow::Error::msg(format!("Invalid fret `{}` in the tab `{}`.", t, symbol))),
+            })
+            .collect::<Res<Vec<_>>>()?;
+
+        Ok(Fingering::new(frets))
+    }
+}
+
+impl Display for Fingering {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let frets = self.frets.iter().map(|f| f.map(|f| f.to_string()).unwrap_or_else(|| "x".to_owned())).collect::<Vec<_>>();
+
+        // Frets above 9 need separators to be unambiguous.
+        let separator = if frets.iter().any(|f| f.len() > 1) { "-" } else { "" };
+
+        write!(f, "{}", frets.join(separator))
+    }
+}
+
+impl Fretboard {
+    /// Creates a new [`Fretboard`] with the given [`StringTuning`].
+    pub fn new(tuning: StringTuning) -> Self {
+        Self {
+            tuning,
+            frets: DEFAULT_FRETS,
+            capo: 0,
+            max_stretch: DEFAULT_MAX_STRETCH,
+        }
+    }
+
+    /// Sets the number of frets.
+    pub fn with_frets(mut self, frets: u8) -> Self {
+        self.frets = frets;
+
+        self
+    }
+
+    /// Sets the fret at which the capo is placed (`0` for no capo).
+    pub fn with_capo(mut self, capo: u8) -> Self {
+        self.capo = capo;
+
+        self
+    }
+
+    /// Sets the maximum number of frets that a fingering may span.
+    pub fn with_max_stretch(mut self, max_stretch: u8) -> Self {
+        self.max_stretch = max_stretch;
+
+        self
+    }
+
+    /// Returns the [`StringTuning`] of the fretboard.
+    pub fn tuning(&self) -> &StringTuning {
+        &self.tuning
+    }
+
+    /// Returns the fret at which the capo is placed (`0` for no capo).
+    pub fn capo(&self) -> u8 {
+        self.capo
+    }
+
+    /// Returns the (universally spelled) sounding [`Note`] of the given string (zero-based, from the lowest string) at the given fret (relative to the capo).
+    pub fn note(&self, string: usize, fret: u8) -> Option<Note> {
+        let open = self.tuning.strings.get(string)?;
+
+        Note::from_semitones(open.semitones() + self.capo as i32 + fret as i32)
+    }
+
+    /// Returns the (universally spelled) sounding [`Note`]s of the given [`Fingering`] (from the lowest string to the highest string).
+    pub fn notes(&self, fingering: &Fingering) -> Res<Vec<Note>> {
+        if fingering.frets.len() != self.tuning.strings.len() {
+            return Err(anyhow::Error::msg(format!(
+                "The fingering has {} strings, but the tuning has {}.",
+                fingering.frets.len(),
+                self.tuning.strings.len()
+            )));
+        }
+
+        fingering
+            .frets
+            .iter()
+            .enumerate()
+            .filter_map(|(string, fret)| fret.map(|f| (string, f)))
+            .map(|(string, fret)| self.note(string, fret).ok_or_else(|| anyhow::Error::msg("The fingering is out of range.")))
+            .collect()
+    }
+
+    /// Attempts to guess the [`Chord`]s played by the given [`Fingering`] (ordered by simplicity).
+    ///
+    /// Doubled notes are ignored, and the other notes are moved into the octave above the bass note.
+    pub fn chords(&self, fingering: &Fingering) -> Res<Vec<Chord>> {
+        let notes = self.notes(fingering)?;
+
+        let Some(bass) = notes.iter().min().copied() else {
+            return Err(anyhow::Error::msg("Must have at least three notes to guess a chord."));
+        };
+
+        let mut close = vec![bass];
+
+        for note in notes {
+            let note = Note::from_semitones(bass.semitones() + (note.semitones() - bass.semitones()).rem_euclid(12)).unwrap_or(note);
+
+            if !close.iter().any(|n| pitch_class(*n) == pitch_class(note)) {
+                close.push(note);
+            }
+        }
+
+        Chord::try_from_notes(&close)
+    }
+
+    /// Renders the given [`Chord`]s as tab, using the most playable fingering of each (with the highest string on top).
+    pub fn tab(&self, chords: &[Chord]) -> Res<String> {
+        let columns = chords
+            .iter()
+            .map(|c| {
+                let fingering = self
+                    .fingerings(c)
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| anyhow::Error::msg(format!("No playable fingerings for `{}` in {}.", c.name(), self.tuning)))?;
+
+                Ok((c.name(), fingering))
+            })
+            .collect::<Res<Vec<_>>>()?;
+
+        let labels = self.tuning.strings.iter().map(|s| s.named_pitch().static_name()).collect::<Vec<_>>();
+        let label_width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+        let widths = columns
+            .iter()
+            .map(|(name, fingering)| fingering.frets.iter().flatten().map(|f| f.to_string().len()).chain([name.chars().count()]).max().unwrap_or(1))
+            .collect::<Vec<_>>();
+
+        let mut lines = vec![];
+
+        let header = columns.iter().zip(&widths).map(|((name, _), width)| format!(" {:<width$} ", name, width = width)).collect::<String>();
+        lines.push(format!("{:<width$}{}", "", header, width = label_width + 1).trim_end().to_owned());
+
+        for (string, label) in labels.iter().enumerate().rev() {
+            let cells = columns
+                .iter()
+                .zip(&widths)
+                .map(|((_, fingering), width)| {
+                    let fret = fingering.frets.get(string).copied().flatten().map(|f| f.to_string()).unwrap_or_default();
+
+                    format!("-{:-<width$}-", fret, width = width)
+                })
+                .collect::<String>();
+
+            lines.push(format!("{:<width$}|{}|", label, cells, width = label_width));
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    /// Returns the playable [`Fingering`]s of the given [`Chord`], ranked by playability (easiest first).
+    ///
+    /// Every fingering contains the root (and slash note), third, seventh, and highest tension of the chord, and a slash
+    /// note is always in the bass (except on re-entrant tunings).  Other chord tones (e.g., the fifth) may be left out.
+    pub fn fingerings(&self, chord: &Chord) -> Vec<Fingering> {
+        let root = chord.root();
+        let tones = Tones::new(chord);
+
+        let slash = chord.slash();
+        let slash = if slash != root { Some(pitch_class(slash)) } else { None };
+
+        let pitch_classes = chord.chord().into_iter().map(pitch_class).collect::<HashSet<_>>();
+
+        let mut required = [tones.third, tones.seventh, tones.tensions.last().copied()]
+            .into_iter()
+            .flatten()
+            .map(|i| pitch_class(root + i))
+            .collect::<HashSet<_>>();
+        required.insert(pitch_class(root));
+        required.extend(slash);
+
+        let open = self.tuning.strings.iter().map(|s| s.semitones() + self.capo as i32).collect::<Vec<_>>();
+        let strings = open.len();
+
+        // Re-entrant tunings (e.g., ukulele) do not have a meaningful bass string.
+        let is_reentrant = open.windows(2).any(|w| w[1] < w[0]);
+
+        let mut seen = HashSet::new();
+        let mut ranked = vec![];
+
+        for start in 1..=self.frets.saturating_sub(self.capo) {
+            let end = (start + self.max_stretch.max(1) - 1).min(self.frets.saturating_sub(self.capo));
+
+            // The options for each string: muted, open, or any fret in the window that plays a chord tone.
+            let options = open
+                .iter()
+                .map(|o| {
+                    let mut options = vec![None];
+                    options.extend((0..1).chain(start..=end).filter(|f| pitch_classes.contains(&(o + *f as i32).rem_euclid(12))).map(Some));
+                    options
+                })
+                .collect::<Vec<_>>();
+
+            let mut frets = vec![];
+
+            search(&options, &mut frets, &mut |frets| {
+                let fingering = Fingering::new(frets.to_vec());
+
+                if seen.contains(&fingering) {
+                    return;
+                }
+
+                let sounding = frets.iter().enumerate().filter_map(|(k, f)| f.map(|f| open[k] + f as i32)).collect::<Vec<_>>();
+                let sounding_classes = sounding.iter().map(|s| s.rem_euclid(12)).collect::<HashSet<_>>();
+
+                let Some(bass) = sounding.iter().min().map(|b| b.rem_euclid(12)) else {
+                    return;
+                };
+
+                let is_playable = sounding.len() >= strings.min(3) && fingering.is_playable() && required.is_subset(&sounding_classes) && (is_reentrant || slash.map(|s| s == bass).unwrap_or(true));
+
+                if is_playable {
+                    let is_inverted = !is_reentrant && slash.is_none() && bass != pitch_class(root);
+                    let score = score(&fingering, pitch_classes.len() - sounding_classes.len(), is_inverted);
+
+                    seen.insert(fingering.clone());
+                    ranked.push((score, fingering));
+                }
+            });
+        }
+
+        ranked.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.frets.cmp(&b.1.frets)));
+
+        ranked.into_iter().map(|(_, f)| f).collect()
+    }
+}
+
+impl Default for Fretboard {
+    fn default() -> Self {
+        Fretboard::new(StringTuning::default())
+    }
+}
+
+// Helpers.
+
+/// Parses a tuning that is known to be valid.
+fn tuning(strings: &str) -> StringTuning {
+    StringTuning::new(strings.split_whitespace().map(|s| Note::parse(s).unwrap()).collect())
+}
+
+/// Returns the given note, moved by octaves so that it is the lowest such note above `floor`.
+fn above(floor: Note, note: Note) -> Note {
+    let semitones = floor.semitones() + (note.semitones() - floor.semitones()).rem_euclid(12);
+    let semitones = if semitones == floor.semitones() { semitones + 12 } else { semitones };
+
+    Note::from_semitones(semitones).and_then(|n| n.enharmonic(note.named_pitch())).unwrap_or(note)
+}
+
+/// Returns the pitch class of the note (i.e., the number of semitones above C).
+fn pitch_class(note: Note) -> i32 {
+    note.semitones().rem_euclid(12)
+}
+
+/// Returns a horizontal line of a chord box across the given number of strings, from the `[left, fill, middle, right]` characters.
+fn line(strings: usize, chars: &str) -> String {
+    let chars = chars.chars().collect::<Vec<_>>();
+
+    let mut result = String::from(chars[0]);
+
+    for k in 1..strings {
+        result.push(chars[1]);
+        result.push(if k + 1 == strings { chars[3] } else { chars[2] });
+    }
+
+    result
+}
+
+/// Calls `visit` with every combination of the options for each string.
+fn search(options: &[Vec<Option<u8>>], frets: &mut Vec<Option<u8>>, visit: &mut impl FnMut(&[Option<u8>])) {
+    let Some((first, rest)) = options.split_first() else {
+        visit(frets);
+        return;
+    };
+
+    for option in first {
+        frets.push(*option);
+        search(rest, frets, visit);
+        frets.pop();
+    }
+}
+
+/// Scores the playability of a fingering (lower is easier).
+fn score(fingering: &Fingering, missing_tones: usize, is_inverted: bool) -> f32 {
+    let frets = fingering.frets();
+
+    let span = fingering.highest_fret().unwrap_or(0) - fingering.lowest_fret().unwrap_or(0);
+    let position = fingering.lowest_fret().unwrap_or(0);
+
+    // Muted strings between sounding strings are harder to play than muted strings on the edges, and muting matters more
+    // on instruments with fewer strings.
+    let first = frets.iter().position(|f| f.is_some()).unwrap_or(0);
+    let last = frets.iter().rposition(|f| f.is_some()).unwrap_or(0);
+    let muted = frets.iter().filter(|f| f.is_none()).count();
+    let inner_muted = frets[first..=last].iter().filter(|f| f.is_none()).count();
+
+    span as f32
+        + position as f32 * 0.5
+        + fingering.fingers() as f32 * 0.5
+        + (muted - inner_muted) as f32 * 9.0 / frets.len() as f32
+        + inner_muted as f32 * 3.0
+        + missing_tones as f32 * 0.5
+        + if is_inverted { 4.0 } else { 0.0 }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn shapes(fretboard: &Fretboard, symbol: &str, count: usize) -> Vec<String> {
+        fretboard.fingerings(&Chord::parse(symbol).unwrap()).iter().take(count).map(|f| f.to_string()).collect()
+    }
+
+    #[test]
+    fn test_fingerings() {
+        let guitar = Fretboard::default();
+
+        assert_eq!(shapes(&guitar, "C", 1), vec!["x32010"]);
+        assert_eq!(shapes(&guitar, "G", 1), vec!["320003"]);
+        assert_eq!(shapes(&guitar, "D", 1), vec!["xx0232"]);
+        assert_eq!(shapes(&guitar, "E", 1), vec!["022100"]);
+        assert_eq!(shapes(&guitar, "Am", 1), vec!["x02210"]);
+        assert_eq!(shapes(&guitar, "F", 1), vec!["133211"]);
+        assert_eq!(shapes(&guitar, "Cmaj7", 1), vec!["x32000"]);
+        assert_eq!(shapes(&guitar, "Dm7", 1), vec!["xx0211"]);
+        assert_eq!(shapes(&guitar, "G7", 1), vec!["320001"]);
+        assert_eq!(shapes(&guitar, "C/E", 1), vec!["032010"]);
+        assert_eq!(shapes(&guitar, "C13", 1), vec!["x33355"]);
+
+        // Slash notes are always in the bass.
+        assert!(guitar
+            .fingerings(&Chord::parse("C/E").unwrap())
+            .iter()
+            .all(|f| guitar.notes(f).unwrap().into_iter().min().unwrap().name().starts_with('E')));
+    }
+
+    #[test]
+    fn test_instruments() {
+        assert_eq!(shapes(&Fretboard::new(StringTuning::drop_d()), "D", 1), vec!["000232"]);
+        assert_eq!(shapes(&Fretboard::new(StringTuning::ukulele()), "C", 1), vec!["0003"]);
+        assert_eq!(shapes(&Fretboard::new(StringTuning::ukulele()), "Am", 1), vec!["2000"]);
+        assert_eq!(shapes(&Fretboard::new(StringTuning::ukulele()), "F", 1), vec!["2010"]);
+
+        // With a capo on the second fret, a D is played with a C shape.
+        let capo = Fretboard::default().with_capo(2);
+
+        assert_eq!(shapes(&capo, "D", 1), vec!["x32010"]);
+        assert_eq!(
+            capo.notes(&capo.fingerings(&Chord::parse("D").unwrap())[0]).unwrap().iter().map(|n| n.name()).collect::<Vec<_>>(),
+            vec!["D3", "G♭3", "A3", "D4", "G♭4"]
+        );
+
+        // A shorter stretch rules out wide shapes.
+        let short = Fretboard::default().with_max_stretch(2);
+
+        assert!(short
+            .fingerings(&Chord::parse("F").unwrap())
+            .iter()
+            .all(|f| f.highest_fret().unwrap_or(0) - f.lowest_fret().unwrap_or(0) < 2));
+        assert!(Fretboard::default().with_frets(0).fingerings(&Chord::parse("F#").unwrap()).is_empty());
+    }
+
+    #[test]
+    fn test_fingering() {
+        let f = Fingering::new(vec![Some(1), Some(3), Some(3), Some(2), Some(1), Some(1)]);
+
+        assert_eq!(f.to_string(), "133211");
+        assert_eq!(f.fingers(), 4);
+        assert!(f.is_playable());
+
+        assert!(!Fingering::new(vec![Some(1), Some(0), Some(3), Some(2), Some(1), Some(1)]).is_playable());
+        assert_eq!(Fingering::new(vec![None, Some(10), Some(12), Some(12), Some(11), Some(10)]).to_string(), "x-10-12-12-11-10");
+        assert!(Fretboard::default().notes(&Fingering::new(vec![None, Some(3)])).is_err());
+    }
+
+    #[test]
+    fn test_finger_numbers() {
+        assert_eq!(Fingering::parse("x32010").unwrap().finger_numbers(), vec![None, Some(3), Some(2), None, Some(1), None]);
+        assert_eq!(Fingering::parse("320003").unwrap().finger_numbers(), vec![Some(2), Some(1), None, None, None, Some(3)]);
+        assert_eq!(Fingering::parse("133211").unwrap().finger_numbers(), vec![Some(1), Some(3), Some(4), Some(2), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn test_diagram() {
+        let c = Fingering::parse("x32010").unwrap();
+
+        assert_eq!(
+            c.diagram(DiagramStyle::Unicode),
+            "x     o   o\n╒═╤═╤═╤═╤═╕\n│ │ │ │ 1 │\n├─┼─┼─┼─┼─┤\n│ │ 2 │ │ │\n├─┼─┼─┼─┼─┤\n│ 3 │ │ │ │\n├─┼─┼─┼─┼─┤\n│ │ │ │ │ │\n└─┴─┴─┴─┴─┘"
+        );
+        assert_eq!(
+            c.diagram(DiagramStyle::Ascii),
+            "x     o   o\n===========\n| | | | 1 |\n+-+-+-+-+-+\n| | 2 | | |\n+-+-+-+-+-+\n| 3 | | | |\n+-+-+-+-+-+\n| | | | | |\n+-+-+-+-+-+"
+        );
+        assert_eq!(
+            Fingering::parse("x-10-12-12-11-10").unwrap().diagram(DiagramStyle::Ascii),
+            "x\n+-+-+-+-+-+\n| 1 | | | 1 10fr\n+-+-+-+-+-+\n| | | | 2 |\n+-+-+-+-+-+\n| | 3 4 | |\n+-+-+-+-+-+\n| | | | | |\n+-+-+-+-+-+"
+        );
+        assert_eq!(
+            Fingering::parse("2000").unwrap().diagram(DiagramStyle::Unicode).lines().take(3).collect::<Vec<_>>(),
+            vec!["  o o o", "╒═╤═╤═╕", "│ │ │ │"]
+        );
+    }
+
+    #[test]
+    fn test_tab() {
+        let chords = ["C", "Am", "F", "G7"].map(|c| Chord::parse(c).unwrap());
+
+        assert_eq!(
+            Fretboard::default().tab(&chords).unwrap(),
+            "   C  Am  F  G7\nE|-0--0---1--1--|\nB|-1--1---1--0--|\nG|-0--2---2--0--|\nD|-2--2---3--0--|\nA|-3--0---3--2--|\nE|--------1--3--|"
+        );
+        assert_eq!(
+            Fretboard::new(StringTuning::ukulele()).tab(&chords[..2]).unwrap(),
+            "   C  Am\nA|-3--0--|\nE|-0--0--|\nC|-0--0--|\nG|-0--2--|"
+        );
+        assert!(Fretboard::default().with_frets(0).tab(&chords).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Fingering::parse("x32010").unwrap(), Fingering::new(vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)]));
+        assert_eq!(
+            Fingering::parse("X-10-12-12-11-10").unwrap(),
+            Fingering::new(vec![None, Some(10), Some(12), Some(12), Some(11), Some(10)])
+        );
+        assert_eq!(Fingering::parse("3 x 5 4 3 x").unwrap().to_string(), "3x543x");
+
+        assert!(Fingering::parse("x3201o").is_err());
+        assert!(Fingering::parse("").is_err());
+    }
+
+    #[test]
+    fn test_chords() {
+        let guitar = Fretboard::default();
+        let name = |tab: &str| guitar.chords(&Fingering::parse(tab).unwrap()).unwrap()[0].name();
+
+        assert_eq!(name("x32010"), "C");
+        assert_eq!(name("3x543x"), "G");
+        assert_eq!(name("133211"), "F");
+        assert_eq!(name("x02210"), "Am");
+        assert_eq!(name("xx0211"), "Dm7");
+        assert_eq!(name("320001"), "G7");
+        assert_eq!(name("x355xx"), "C5");
+        assert_eq!(name("3x34xx"), "G7(no5)");
+        assert_eq!(
+            guitar.chords(&Fingering::parse("x3x355").unwrap()).unwrap()[0].chord(),
+            Chord::parse("C7(add13)(no5)@3!").unwrap().chord()
+        );
+        assert!(guitar.chords(&Fingering::parse("032010").unwrap()).unwrap().iter().any(|c| c.name() == "C"));
+        assert_eq!(Fretboard::new(StringTuning::ukulele()).chords(&Fingering::parse("0003").unwrap()).unwrap()[0].name(), "C");
+
+        assert!(guitar.chords(&Fingering::parse("xxxx10").unwrap()).is_err());
+        assert!(guitar.chords(&Fingering::parse("x3201").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_tuning() {
+        assert_eq!(StringTuning::parse("EADGBE").unwrap(), StringTuning::guitar());
+        assert_eq!(StringTuning::parse("drop-d").unwrap(), StringTuning::drop_d());
+        assert_eq!(StringTuning::parse("DADGAD").unwrap(), StringTuning::dadgad());
+        assert_eq!(StringTuning::parse("Ukulele").unwrap(), StringTuning::ukulele());
+        assert_eq!(StringTuning::parse("G3 D4 A4 E5").unwrap(), StringTuning::mandolin());
+        assert_eq!(StringTuning::parse("Eb Ab Db Gb Bb Eb").unwrap().to_string(), "E♭2 A♭2 D♭3 G♭3 B♭3 E♭4");
+        assert_eq!(StringTuning::bass().to_string(), "E1 A1 D2 G2");
+
+        assert!(StringTuning::parse("EADGBQ").is_err());
+        assert!(StringTuning::parse("").is_err());
+    }
+}
@@ -1,11 +1,20 @@
 //! A module for working with intervals.
 
-use std::fmt::{Display, Formatter, Error};
+use std::{
+    fmt::{Display, Error, Formatter},
+    ops::{Add, Sub},
+};
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
-use crate::core::octave::{HasOctave, Octave};
+use crate::core::{
+    base::{HasDescription, HasName, HasStaticName, Parsable, Res},
+    named_pitch::{HasLetter, HasNamedPitch, ALL_PITCHES},
+    note::{Note, NoteRecreator},
+    octave::{HasOctave, Octave},
+    pitch::HasPitch,
+};
 
 // Traits.
 
@@ -148,6 +157,41 @@ pub enum Interval {
     ThreePerfectOctavesAndMajorSeventh,
 }
 
+/// An enum representing the quality of an interval.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+pub enum IntervalQuality {
+    /// A doubly diminished interval.
+    DoublyDiminished,
+    /// A diminished interval.
+    Diminished,
+    /// A minor interval.
+    Minor,
+    /// A perfect interval.
+    Perfect,
+    /// A major interval.
+    Major,
+    /// An augmented interval.
+    Augmented,
+    /// A doubly augmented interval.
+    DoublyAugmented,
+}
+
+// Struct.
+
+/// An interval of any size, with a direction (e.g., a descending major seventeenth).
+///
+/// Unlike [`Interval`], this type is not limited to the intervals that show up in chords, so it can represent the
+/// interval between any two [`Note`]s.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct DirectedInterval {
+    /// The quality of the interval.
+    quality: IntervalQuality,
+    /// The number of the interval (e.g., `3` for a third, `8` for an octave, or `17` for two octaves and a third).
+    number: u8,
+    /// Whether or not the interval is descending.
+    descending: bool,
+}
+
 // Impls.
 
 impl HasEnharmonicDistance for Interval {
@@ -363,6 +407,374 @@ impl Display for Interval {
     }
 }
 
+impl Interval {
+    /// Returns the [`Interval`] with the given quality and number (e.g., `Minor` and `9` for a minor ninth).
+    pub fn from_quality_and_number(quality: IntervalQuality, number: u8) -> Res<Self> {
+        ALL_INTERVALS.iter().find(|i| i.quality() == quality && i.number() == number).copied().ok_or_else(|| {
+            anyhow::Error::msg(format!(
+                "There is no {} {} interval (see `DirectedInterval` for intervals of any size).",
+                quality.description(),
+                ordinal(number)
+            ))
+        })
+    }
+
+    /// Returns the quality of the interval.
+    pub fn quality(&self) -> IntervalQuality {
+        self.quality_and_number().0
+    }
+
+    /// Returns the number of the interval (e.g., `3` for a third, `8` for an octave, or `17` for two octaves and a third).
+    pub fn number(&self) -> u8 {
+        self.quality_and_number().1
+    }
+
+    /// Returns the number of semitones in the interval.
+    pub fn semitones(&self) -> u8 {
+        semitones(self.quality(), self.number()) as u8
+    }
+
+    /// Returns the inversion of the interval (e.g., a minor sixth for a major third), reducing compound intervals first.
+    pub fn inverted(&self) -> Self {
+        let inversion = DirectedInterval::from(*self).inverted();
+
+        Interval::from_quality_and_number(inversion.quality, inversion.number).unwrap()
+    }
+
+    /// Returns the quality and number of the interval.
+    fn quality_and_number(&self) -> (IntervalQuality, u8) {
+        use IntervalQuality::*;
+
+        match self {
+            Interval::PerfectUnison => (Perfect, 1),
+            Interval::DiminishedSecond => (Diminished, 2),
+
+            Interval::AugmentedUnison => (Augmented, 1),
+            Interval::MinorSecond => (Minor, 2),
+
+            Interval::MajorSecond => (Major, 2),
+            Interval::DiminishedThird => (Diminished, 3),
+
+            Interval::AugmentedSecond => (Augmented, 2),
+            Interval::MinorThird => (Minor, 3),
+
+            Interval::MajorThird => (Major, 3),
+            Interval::DiminishedFourth => (Diminished, 4),
+
+            Interval::AugmentedThird => (Augmented, 3),
+            Interval::PerfectFourth => (Perfect, 4),
+
+            Interval::AugmentedFourth => (Augmented, 4),
+            Interval::DiminishedFifth => (Diminished, 5),
+
+            Interval::PerfectFifth => (Perfect, 5),
+            Interval::DiminishedSixth => (Diminished, 6),
+
+            Interval::AugmentedFifth => (Augmented, 5),
+            Interval::MinorSixth => (Minor, 6),
+
+            Interval::MajorSixth => (Major, 6),
+            Interval::DiminishedSeventh => (Diminished, 7),
+
+            Interval::AugmentedSixth => (Augmented, 6),
+            Interval::MinorSeventh => (Minor, 7),
+
+            Interval::MajorSeventh => (Major, 7),
+            Interval::DiminishedOctave => (Diminished, 8),
+
+            Interval::AugmentedSeventh => (Augmented, 7),
+            Interval::PerfectOctave => (Perfect, 8),
+
+            Interval::MinorNinth => (Minor, 9),
+            Interval::MajorNinth => (Major, 9),
+            Interval::AugmentedNinth => (Augmented, 9),
+
+            Interval::DiminishedEleventh => (Diminished, 11),
+            Interval::PerfectEleventh => (Perfect, 11),
+            Interval::AugmentedEleventh => (Augmented, 11),
+
+            Interval::MinorThirteenth => (Minor, 13),
+            Interval::MajorThirteenth => (Major, 13),
+            Interval::AugmentedThirteenth => (Augmented, 13),
+
+            Interval::PerfectOctaveAndPerfectFifth => (Perfect, 12),
+            Interval::TwoPerfectOctaves => (Perfect, 15),
+            Interval::TwoPerfectOctavesAndMajorThird => (Major, 17),
+            Interval::TwoPerfectOctavesAndPerfectFifth => (Perfect, 19),
+            Interval::TwoPerfectOctavesAndMinorSeventh => (Minor, 21),
+            Interval::ThreePerfectOctaves => (Perfect, 22),
+            Interval::ThreePerfectOctavesAndMajorSecond => (Major, 23),
+            Interval::ThreePerfectOctavesAndMajorThird => (Major, 24),
+            Interval::ThreePerfectOctavesAndAugmentedFourth => (Augmented, 25),
+            Interval::ThreePerfectOctavesAndPerfectFifth => (Perfect, 26),
+            Interval::ThreePerfectOctavesAndMinorSixth => (Minor, 27),
+            Interval::ThreePerfectOctavesAndMinorSeventh => (Minor, 28),
+            Interval::ThreePerfectOctavesAndMajorSeventh => (Major, 28),
+        }
+    }
+}
+
+impl HasStaticName for IntervalQuality {
+    fn static_name(&self) -> &'static str {
+        match self {
+            IntervalQuality::DoublyDiminished => "dd",
+            IntervalQuality::Diminished => "d",
+            IntervalQuality::Minor => "m",
+            IntervalQuality::Perfect => "P",
+            IntervalQuality::Major => "M",
+            IntervalQuality::Augmented => "A",
+            IntervalQuality::DoublyAugmented => "AA",
+        }
+    }
+}
+
+impl HasDescription for IntervalQuality {
+    fn description(&self) -> &'static str {
+        match self {
+            IntervalQuality::DoublyDiminished => "doubly diminished",
+            IntervalQuality::Diminished => "diminished",
+            IntervalQuality::Minor => "minor",
+            IntervalQuality::Perfect => "perfect",
+            IntervalQuality::Major => "major",
+            IntervalQuality::Augmented => "augmented",
+            IntervalQuality::DoublyAugmented => "doubly augmented",
+        }
+    }
+}
+
+impl HasName for Interval {
+    fn name(&self) -> String {
+        DirectedInterval::from(*self).name()
+    }
+}
+
+impl Parsable for Interval {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let normalized = symbol.trim().replace([' ', '-', '_'], "").to_lowercase();
+
+        // Allow the full names (e.g., `PerfectFifth`, or `perfect fifth`).
+        if let Some(interval) = ALL_INTERVALS.iter().find(|i| i.to_string().to_lowercase() == normalized) {
+            return Ok(*interval);
+        }
+
+        let (quality, number) = parse_quality_and_number(symbol.trim())?;
+
+        Interval::from_quality_and_number(quality, number)
+    }
+}
+
+impl DirectedInterval {
+    /// Creates a new [`DirectedInterval`], if the quality is valid for the number (e.g., there is no perfect third).
+    pub fn new(quality: IntervalQuality, number: u8, descending: bool) -> Res<Self> {
+        if number == 0 {
+            return Err(anyhow::Error::msg("The number of an interval must be at least 1 (a unison)."));
+        }
+
+        let is_valid = match quality {
+            IntervalQuality::Perfect => is_perfect_kind(number),
+            IntervalQuality::Major | IntervalQuality::Minor => !is_perfect_kind(number),
+            _ => true,
+        };
+
+        if !is_valid {
+            return Err(anyhow::Error::msg(format!("There is no {} {} interval.", quality.description(), ordinal(number))));
+        }
+
+        let result = Self { quality, number, descending };
+
+        // A perfect unison has no direction.
+        Ok(if result.semitones() == 0 && number == 1 { Self { descending: false, ..result } } else { result })
+    }
+
+    /// Returns the interval from one [`Note`] to another (descending, if the second note is lower).
+    pub fn between(from: Note, to: Note) -> Res<Self> {
+        let steps = staff_position(to) - staff_position(from);
+        let semitones = to.semitones() - from.semitones();
+
+        let descending = steps < 0 || (steps == 0 && semitones < 0);
+        let (steps, semitones) = if descending { (-steps, -semitones) } else { (steps, semitones) };
+        let number = steps + 1;
+
+        let difference = semitones - semitones_of_number(number as u8);
+        let quality = match (is_perfect_kind(number as u8), difference) {
+            (true, -2) | (false, -3) => IntervalQuality::DoublyDiminished,
+            (true, -1) | (false, -2) => IntervalQuality::Diminished,
+            (false, -1) => IntervalQuality::Minor,
+            (true, 0) => IntervalQuality::Perfect,
+            (false, 0) => IntervalQuality::Major,
+            (_, 1) => IntervalQuality::Augmented,
+            (_, 2) => IntervalQuality::DoublyAugmented,
+            _ => {
+                return Err(anyhow::Error::msg(format!(
+                    "The interval from {} to {} is too augmented or diminished to name.",
+                    from.name(),
+                    to.name()
+                )))
+            }
+        };
+
+        Self::new(quality, u8::try_from(number)?, descending)
+    }
+
+    /// Returns the quality of the interval.
+    pub fn quality(&self) -> IntervalQuality {
+        self.quality
+    }
+
+    /// Returns the number of the interval (e.g., `3` for a third, `8` for an octave, or `17` for two octaves and a third).
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Returns whether or not the interval is descending.
+    pub fn is_descending(&self) -> bool {
+        self.descending
+    }
+
+    /// Returns whether or not the interval is compound (i.e., larger than an octave).
+    pub fn is_compound(&self) -> bool {
+        self.number > 8
+    }
+
+    /// Returns the number of semitones in the interval (negative, if the interval is descending).
+    pub fn semitones(&self) -> i32 {
+        let semitones = semitones(self.quality, self.number);
+
+        if self.descending {
+            -semitones
+        } else {
+            semitones
+        }
+    }
+
+    /// Returns the number of whole octaves that the interval spans beyond its simple interval.
+    pub fn octaves(&self) -> u8 {
+        (self.number - simple_number(self.number)) / 7
+    }
+
+    /// Returns the simple interval (within an octave) that the interval reduces to (e.g., a major third for a major tenth).
+    pub fn simple(&self) -> Self {
+        Self {
+            number: simple_number(self.number),
+            ..*self
+        }
+    }
+
+    /// Returns the interval as an (ascending) [`Interval`], if there is one.
+    pub fn interval(&self) -> Option<Interval> {
+        Interval::from_quality_and_number(self.quality, self.number).ok()
+    }
+
+    /// Returns the inversion of the (simple) interval (e.g., a minor sixth for a major third, or for a major tenth).
+    pub fn inverted(&self) -> Self {
+        let quality = match self.quality {
+            IntervalQuality::DoublyDiminished => IntervalQuality::DoublyAugmented,
+            IntervalQuality::Diminished => IntervalQuality::Augmented,
+            IntervalQuality::Minor => IntervalQuality::Major,
+            IntervalQuality::Perfect => IntervalQuality::Perfect,
+            IntervalQuality::Major => IntervalQuality::Minor,
+            IntervalQuality::Augmented => IntervalQuality::Diminished,
+            IntervalQuality::DoublyAugmented => IntervalQuality::DoublyDiminished,
+        };
+
+        Self {
+            quality,
+            number: 9 - simple_number(self.number),
+            descending: self.descending,
+        }
+    }
+}
+
+impl From<Interval> for DirectedInterval {
+    fn from(interval: Interval) -> Self {
+        Self {
+            quality: interval.quality(),
+            number: interval.number(),
+            descending: false,
+        }
+    }
+}
+
+impl HasName for DirectedInterval {
+    fn name(&self) -> String {
+        let direction = if self.descending { "descending " } else { "" };
+
+        format!("{}{} {}", direction, self.quality.description(), ordinal(self.number))
+    }
+}
+
+impl Display for DirectedInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let direction = if self.descending { "-" } else { "" };
+
+        write!(f, "{}{}{}", direction, self.quality.static_name(), self.number)
+    }
+}
+
+impl Parsable for DirectedInterval {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let symbol = symbol.trim();
+
+        let (descending, symbol) = match symbol.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, symbol.strip_prefix('+').unwrap_or(symbol)),
+        };
+
+        let interval = match Interval::parse(symbol) {
+            Ok(interval) => DirectedInterval::from(interval),
+            Err(_) => {
+                let (quality, number) = parse_quality_and_number(symbol)?;
+
+                DirectedInterval::new(quality, number, false)?
+            }
+        };
+
+        DirectedInterval::new(interval.quality, interval.number, descending)
+    }
+}
+
+impl Note {
+    /// Adds the [`DirectedInterval`] to the note, returning `None` if the result is outside of the range of notes (i.e., below `C0`, or above `B15`).
+    pub fn checked_add(self, rhs: DirectedInterval) -> Option<Note> {
+        let steps = if rhs.descending { -(rhs.number as i32 - 1) } else { rhs.number as i32 - 1 };
+        let position = staff_position(self) + steps;
+        let semitones = self.semitones() + rhs.semitones();
+
+        let letter = ["C", "D", "E", "F", "G", "A", "B"][position.rem_euclid(7) as usize];
+        let named_pitch = ALL_PITCHES.iter().find(|p| p.letter() == letter && p.pitch() as i32 == semitones.rem_euclid(12));
+
+        let universal = Note::from_semitones(semitones)?;
+
+        // Spell the note with the letter of the interval, and then fix the octave to the written octave.
+        match named_pitch.and_then(|p| universal.enharmonic(*p)) {
+            Some(note) => Some(Octave::try_from(position.div_euclid(7) as u8).map(|o| note.with_octave(o)).unwrap_or(note)),
+            None => Some(universal),
+        }
+    }
+}
+
+impl Add<DirectedInterval> for Note {
+    type Output = Self;
+
+    fn add(self, rhs: DirectedInterval) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|| panic!("{} + {} is out of range", self, rhs))
+    }
+}
+
+impl Sub<DirectedInterval> for Note {
+    type Output = Self;
+
+    fn sub(self, rhs: DirectedInterval) -> Self::Output {
+        self + DirectedInterval { descending: !rhs.descending, ..rhs }
+    }
+}
+
 // Statics.
 
 /// All known [`Interval`]s.
@@ -433,3 +845,237 @@ pub static PRIMARY_HARMONIC_SERIES: [Interval; 13] = [
     Interval::ThreePerfectOctavesAndMinorSeventh,
     Interval::ThreePerfectOctavesAndMajorSeventh,
 ];
+
+// Helpers.
+
+/// Returns whether the interval number is of the perfect kind (unisons, fourths, fifths, and octaves).
+fn is_perfect_kind(number: u8) -> bool {
+    matches!(simple_number(number), 1 | 4 | 5 | 8)
+}
+
+/// Returns the number of the simple interval (from 1 to 8) that the interval number reduces to.
+fn simple_number(number: u8) -> u8 {
+    if number <= 8 {
+        number
+    } else {
+        (number - 2) % 7 + 2
+    }
+}
+
+/// Returns the number of semitones in the major (or perfect) interval of the given number.
+fn semitones_of_number(number: u8) -> i32 {
+    let steps = number as i32 - 1;
+
+    [0, 2, 4, 5, 7, 9, 11][(steps % 7) as usize] + 12 * (steps / 7)
+}
+
+/// Returns the number of semitones in the interval of the given quality and number.
+fn semitones(quality: IntervalQuality, number: u8) -> i32 {
+    let offset = match (quality, is_perfect_kind(number)) {
+        (IntervalQuality::DoublyDiminished, true) => -2,
+        (IntervalQuality::DoublyDiminished, false) => -3,
+        (IntervalQuality::Diminished, true) => -1,
+        (IntervalQuality::Diminished, false) => -2,
+        (IntervalQuality::Minor, _) => -1,
+        (IntervalQuality::Perfect, _) | (IntervalQuality::Major, _) => 0,
+        (IntervalQuality::Augmented, _) => 1,
+        (IntervalQuality::DoublyAugmented, _) => 2,
+    };
+
+    semitones_of_number(number) + offset
+}
+
+/// Returns the position of the note on the staff (i.e., the number of letter names above C0).
+fn staff_position(note: Note) -> i32 {
+    let letter = "CDEFGAB".find(note.named_pitch().letter()).unwrap() as i32;
+
+    note.octave() as i32 * 7 + letter
+}
+
+/// Returns the ordinal name of an interval number (e.g., `third`, `octave`, or `seventeenth`).
+fn ordinal(number: u8) -> String {
+    static NAMES: [&str; 22] = [
+        "unison",
+        "second",
+        "third",
+        "fourth",
+        "fifth",
+        "sixth",
+        "seventh",
+        "octave",
+        "ninth",
+        "tenth",
+        "eleventh",
+        "twelfth",
+        "thirteenth",
+        "fourteenth",
+        "fifteenth",
+        "sixteenth",
+        "seventeenth",
+        "eighteenth",
+        "nineteenth",
+        "twentieth",
+        "twenty-first",
+        "twenty-second",
+    ];
+
+    match NAMES.get((number as usize).wrapping_sub(1)) {
+        Some(name) => name.to_string(),
+        None => match (number % 10, number % 100) {
+            (_, 11..=13) => format!("{}th", number),
+            (1, _) => format!("{}st", number),
+            (2, _) => format!("{}nd", number),
+            (3, _) => format!("{}rd", number),
+            _ => format!("{}th", number),
+        },
+    }
+}
+
+/// Parses the quality and number of an interval (e.g., `m3`, `P5`, `A4`, `dim7`, `maj9`, `b9`, `#11`, or `13`).
+fn parse_quality_and_number(symbol: &str) -> Res<(IntervalQuality, u8)> {
+    let error = || anyhow::Error::msg(format!("Unknown interval `{}` (expected, e.g., `m3`, `P5`, `A4`, `b9`, `#11`, or `M13`).", symbol));
+
+    let split = symbol.find(|c: char| c.is_ascii_digit()).ok_or_else(error)?;
+    let (prefix, number) = symbol.split_at(split);
+    let number = number.parse::<u8>().map_err(|_| error())?;
+
+    if number == 0 {
+        return Err(error());
+    }
+
+    let perfect = is_perfect_kind(number);
+
+    let quality = match prefix {
+        "P" | "perf" | "perfect" if perfect => IntervalQuality::Perfect,
+        "M" | "maj" | "major" if !perfect => IntervalQuality::Major,
+        "m" | "min" | "minor" if !perfect => IntervalQuality::Minor,
+        "d" | "dim" | "diminished" | "°" => IntervalQuality::Diminished,
+        "A" | "aug" | "augmented" | "+" => IntervalQuality::Augmented,
+        "dd" => IntervalQuality::DoublyDiminished,
+        "AA" => IntervalQuality::DoublyAugmented,
+
+        // Chord-symbol style accidentals, relative to the major (or perfect) interval.
+        "" if perfect => IntervalQuality::Perfect,
+        "" => IntervalQuality::Major,
+        "b" | "♭" if perfect => IntervalQuality::Diminished,
+        "b" | "♭" => IntervalQuality::Minor,
+        "bb" | "𝄫" if perfect => IntervalQuality::DoublyDiminished,
+        "bb" | "𝄫" => IntervalQuality::Diminished,
+        "#" | "♯" => IntervalQuality::Augmented,
+        "##" | "𝄪" => IntervalQuality::DoublyAugmented,
+
+        _ => return Err(error()),
+    };
+
+    Ok((quality, number))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_quality_and_number() {
+        for interval in ALL_INTERVALS.iter() {
+            assert_eq!(Interval::from_quality_and_number(interval.quality(), interval.number()).unwrap(), *interval);
+            assert_eq!(C + *interval, C + DirectedInterval::from(*interval), "{}", interval);
+        }
+
+        assert_eq!(Interval::from_quality_and_number(IntervalQuality::Minor, 9).unwrap(), Interval::MinorNinth);
+        assert_eq!(Interval::MajorThirteenth.semitones(), 21);
+        assert_eq!(Interval::AugmentedSeventh.semitones(), 12);
+        assert_eq!(Interval::PerfectFifth.name(), "perfect fifth");
+
+        assert!(Interval::from_quality_and_number(IntervalQuality::Major, 10).is_err());
+        assert!(Interval::from_quality_and_number(IntervalQuality::Perfect, 3).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Interval::parse("m3").unwrap(), Interval::MinorThird);
+        assert_eq!(Interval::parse("M3").unwrap(), Interval::MajorThird);
+        assert_eq!(Interval::parse("P5").unwrap(), Interval::PerfectFifth);
+        assert_eq!(Interval::parse("A4").unwrap(), Interval::AugmentedFourth);
+        assert_eq!(Interval::parse("d5").unwrap(), Interval::DiminishedFifth);
+        assert_eq!(Interval::parse("b9").unwrap(), Interval::MinorNinth);
+        assert_eq!(Interval::parse("#11").unwrap(), Interval::AugmentedEleventh);
+        assert_eq!(Interval::parse("M13").unwrap(), Interval::MajorThirteenth);
+        assert_eq!(Interval::parse("13").unwrap(), Interval::MajorThirteenth);
+        assert_eq!(Interval::parse("b5").unwrap(), Interval::DiminishedFifth);
+        assert_eq!(Interval::parse("dim7").unwrap(), Interval::DiminishedSeventh);
+        assert_eq!(Interval::parse("PerfectFifth").unwrap(), Interval::PerfectFifth);
+        assert_eq!(Interval::parse("major second").unwrap(), Interval::MajorSecond);
+
+        assert!(Interval::parse("P3").is_err());
+        assert!(Interval::parse("M5").is_err());
+        assert!(Interval::parse("M10").is_err());
+        assert!(Interval::parse("PerfectSecond").is_err());
+        assert!(Interval::parse("x").is_err());
+
+        assert_eq!(DirectedInterval::parse("M10").unwrap().to_string(), "M10");
+        assert_eq!(DirectedInterval::parse("-P5").unwrap().to_string(), "-P5");
+        assert_eq!(DirectedInterval::parse("-P1").unwrap().to_string(), "P1");
+        assert_eq!(DirectedInterval::parse("AA30").unwrap().name(), "doubly augmented 30th");
+    }
+
+    #[test]
+    fn test_inversion() {
+        assert_eq!(Interval::MajorThird.inverted(), Interval::MinorSixth);
+        assert_eq!(Interval::PerfectFourth.inverted(), Interval::PerfectFifth);
+        assert_eq!(Interval::AugmentedFourth.inverted(), Interval::DiminishedFifth);
+        assert_eq!(Interval::PerfectUnison.inverted(), Interval::PerfectOctave);
+        assert_eq!(Interval::PerfectOctave.inverted(), Interval::PerfectUnison);
+        assert_eq!(Interval::MinorNinth.inverted(), Interval::MajorSeventh);
+        assert_eq!(Interval::MajorThirteenth.inverted(), Interval::MinorThird);
+
+        for interval in ALL_INTERVALS.iter() {
+            assert_eq!((interval.semitones() + interval.inverted().semitones()) % 12, 0, "{}", interval);
+        }
+    }
+
+    #[test]
+    fn test_between() {
+        let between = |from: Note, to: Note| DirectedInterval::between(from, to).unwrap();
+
+        assert_eq!(between(C, AFive).to_string(), "M13");
+        assert_eq!(between(C, AFive).name(), "major thirteenth");
+        assert_eq!(between(C, AFive).simple().to_string(), "M6");
+        assert_eq!(between(C, AFive).octaves(), 1);
+        assert_eq!(between(C, AFive).semitones(), 21);
+        assert_eq!(between(C, AFive).interval(), Some(Interval::MajorThirteenth));
+        assert_eq!(between(C, AFive).inverted().to_string(), "m3");
+        assert!(between(C, AFive).is_compound());
+
+        assert_eq!(between(AFive, C).to_string(), "-M13");
+        assert_eq!(between(AFive, C).semitones(), -21);
+        assert_eq!(between(E, C).name(), "descending major third");
+        assert!(!between(E, C).is_compound());
+        assert_eq!(between(C, ESix).to_string(), "M17");
+        assert_eq!(between(C, ESix).octaves(), 2);
+        assert_eq!(between(C, CSix).to_string(), "P15");
+        assert_eq!(between(C, CSix).simple().to_string(), "P8");
+        assert_eq!(between(C, CFlat).to_string(), "-A1");
+        assert_eq!(between(BSharpThree, C).to_string(), "d2");
+        assert_eq!(between(C, BFlatThree).to_string(), "-M2");
+        assert_eq!(between(CFlat, FSharp).to_string(), "AA4");
+        assert_eq!(between(C, D).name(), "major second");
+        assert_eq!(between(E, GFlat).interval(), Some(Interval::DiminishedThird));
+
+        // Adding the interval to the first note gives the second note.
+        for (from, to) in [(C, AFive), (AFive, C), (C, ESix), (CFlat, FSharp), (BSharpThree, C), (G, FSharpTwo)] {
+            assert_eq!(from + between(from, to), to);
+            assert_eq!(to - between(from, to), from);
+        }
+
+        // Adding outside of the range of notes fails.
+        let octave = DirectedInterval::parse("-P8").unwrap();
+
+        assert_eq!(C + DirectedInterval::parse("-M17").unwrap(), AFlatOne);
+        assert_eq!(C.checked_add(octave), Some(CThree));
+        assert_eq!(CZero.checked_add(octave), None);
+    }
+}
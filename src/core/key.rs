@@ -0,0 +1,450 @@
+//! A module for working with keys.
+//!
+//! A key is a tonic [`Note`] and a mode (a [`Scale`]).
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::core::{
+    base::{HasName, HasStaticName, Parsable, Res},
+    chord::{Chord, Chordable, HasRoot},
+    interval::{Interval, ALL_INTERVALS},
+    known_chord::HasRelativeScale,
+    named_pitch::{HasAccidentals, HasNamedPitch, NamedPitch},
+    note::{Note, NoteRecreator},
+    octave::HasOctave,
+    pitch::HasPitch,
+    roman::{Analysis, RomanNumeral, TriadQuality},
+    scale::Scale,
+};
+
+// Traits.
+
+/// A trait for types that have a tonic.
+pub trait HasTonic {
+    /// Returns the tonic of the type (usually a [`Key`]).
+    fn tonic(&self) -> Note;
+}
+
+/// A trait for types that have a mode.
+pub trait HasMode {
+    /// Returns the mode of the type (usually a [`Key`]).
+    fn mode(&self) -> Scale;
+}
+
+// Struct.
+
+/// A key type.
+///
+/// This is a tonic [`Note`] and a mode (any [`Scale`]; usually [`Scale::Ionian`] or [`Scale::Aeolian`]).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct Key {
+    /// The tonic of the key.
+    tonic: Note,
+    /// The mode of the key.
+    mode: Scale,
+}
+
+// Impls.
+
+impl Key {
+    /// Creates a new [`Key`] from the given tonic and mode.
+    pub fn new(tonic: Note, mode: Scale) -> Self {
+        Self { tonic, mode }
+    }
+
+    /// Creates a new major [`Key`] from the given tonic.
+    pub fn major(tonic: Note) -> Self {
+        Self::new(tonic, Scale::Ionian)
+    }
+
+    /// Creates a new (natural) minor [`Key`] from the given tonic.
+    pub fn minor(tonic: Note) -> Self {
+        Self::new(tonic, Scale::Aeolian)
+    }
+}
+
+impl Key {
+    /// Returns the notes of the key, starting from the tonic.
+    pub fn notes(&self) -> Vec<Note> {
+        self.mode.notes(self.tonic)
+    }
+
+    /// Returns whether or not the key is "minor" (i.e., it has a minor third, and no major third).
+    pub fn is_minor(&self) -> bool {
+        let relative_scale = self.mode.relative_scale();
+
+        relative_scale.contains(&Interval::MinorThird) && !relative_scale.contains(&Interval::MajorThird)
+    }
+
+    /// Returns the key signature of the key.
+    ///
+    /// A positive number is the number of sharps, and a negative number is the number of flats.
+    /// Keys that are not modes of the major scale (e.g., harmonic minor) use the signature
+    /// of their parallel major or minor key.
+    pub fn signature(&self) -> i8 {
+        let key = match self.mode.parent() {
+            Some((Scale::Ionian, _)) => *self,
+            _ if self.is_minor() => Key::minor(self.tonic),
+            _ => Key::major(self.tonic),
+        };
+
+        key.notes().iter().map(|n| n.named_pitch().accidentals()).sum()
+    }
+
+    /// Returns the relative key.
+    ///
+    /// For major and minor keys, this is the relative minor and major key, respectively.  For the
+    /// other modes of a seven note scale, this is the parent scale (e.g., the relative key of D dorian
+    /// is C major).  Harmonic major takes the relative key of its major key.
+    pub fn relative(&self) -> Option<Key> {
+        let notes = self.notes();
+
+        let (tonic, mode) = match self.mode {
+            Scale::Ionian => (notes[5], Scale::Aeolian),
+            Scale::HarmonicMajor => return Key::major(self.tonic).relative(),
+            Scale::Aeolian | Scale::MelodicMinor | Scale::HarmonicMinor => (notes[2], Scale::Ionian),
+            _ => {
+                let (parent, degree) = self.mode.parent()?;
+
+                (self.tonic - parent.relative_scale()[degree], parent)
+            }
+        };
+
+        // Keep the tonic in the original octave, so that the relative of the relative is the original key.
+        Some(Key::new(tonic.with_octave(self.tonic.octave()), mode))
+    }
+
+    /// Returns the parallel key (the major or minor key with the same tonic).
+    pub fn parallel(&self) -> Key {
+        if self.is_minor() {
+            Key::major(self.tonic)
+        } else {
+            Key::minor(self.tonic)
+        }
+    }
+
+    /// Spells the given [`Note`] according to the key.
+    ///
+    /// Notes that belong to the key take the spelling of the key, and chromatic notes take sharps
+    /// or flats depending on the key signature.  The sounding pitch is never changed.
+    pub fn spell(&self, note: Note) -> Note {
+        let named_pitch = match self.notes().into_iter().find(|n| n.pitch() == note.pitch()) {
+            Some(n) => n.named_pitch(),
+            None => {
+                let flat = NamedPitch::from(note.pitch());
+
+                if self.signature() >= 0 && flat.accidentals() < 0 {
+                    flat + 12
+                } else {
+                    flat
+                }
+            }
+        };
+
+        note.enharmonic(named_pitch).unwrap_or(note)
+    }
+
+    /// Returns the diatonic triad built on the given (one-based) degree of the key.
+    pub fn triad(&self, degree: u8) -> Res<Chord> {
+        Ok(stacked_chord(&self.stacked_tones(degree, 3)?))
+    }
+
+    /// Returns the diatonic seventh chord built on the given (one-based) degree of the key.
+    pub fn seventh(&self, degree: u8) -> Res<Chord> {
+        Ok(stacked_chord(&self.stacked_tones(degree, 4)?))
+    }
+
+    /// Returns all of the diatonic triads of the key.
+    pub fn triads(&self) -> Vec<Chord> {
+        (1..=self.degrees()).map(|d| self.triad(d).unwrap()).collect()
+    }
+
+    /// Returns all of the diatonic seventh chords of the key.
+    pub fn sevenths(&self) -> Vec<Chord> {
+        (1..=self.degrees()).map(|d| self.seventh(d).unwrap()).collect()
+    }
+
+    /// Returns the roman numeral of the diatonic chord on the given (one-based) degree of the key.
+    ///
+    /// E.g., in C major, degree 2 is `ii` (or `ii7`), and degree 7 is `vii°` (or `viiø7`).
+    pub fn roman_numeral(&self, degree: u8, seventh: bool) -> Res<String> {
+        let chord = if seventh { self.seventh(degree)? } else { self.triad(degree)? };
+
+        let name = match RomanNumeral::from_chord(self, &chord) {
+            Some(numeral) => numeral.name(),
+            None => {
+                // Not a stack of thirds, so just use the chord name.
+                let name = chord.name();
+                let suffix = name.strip_prefix(chord.root().static_name()).unwrap_or_default();
+
+                format!("{}{}", RomanNumeral::new(degree, TriadQuality::Major).name(), suffix)
+            }
+        };
+
+        Ok(name)
+    }
+
+    /// Parses a progression of roman numerals (e.g., `ii7 V7/V ♭VImaj7 I6`) into [`Chord`]s in the key.
+    pub fn chords_from_numerals(&self, input: &str) -> Res<Vec<Chord>> {
+        RomanNumeral::parse_progression(input)?.iter().map(|n| n.to_chord(self)).collect()
+    }
+
+    /// Labels the given [`Chord`]s with roman numerals and harmonic functions in the key.
+    pub fn analyze(&self, chords: &[Chord]) -> Vec<Analysis> {
+        Analysis::all(self, chords)
+    }
+
+    /// Returns the number of degrees in the key.
+    fn degrees(&self) -> u8 {
+        self.mode.relative_scale().len() as u8
+    }
+
+    /// Returns the tones obtained by stacking every other note of the key, starting on the given degree.
+    fn stacked_tones(&self, degree: u8, count: usize) -> Res<Vec<Note>> {
+        let notes = self.notes();
+
+        if degree == 0 || degree as usize > notes.len() {
+            return Err(anyhow::Error::msg(format!("The degree must be between 1 and {}.", notes.len())));
+        }
+
+        let tones = (0..count)
+            .map(|k| {
+                let index = degree as usize - 1 + 2 * k;
+                let mut note = notes[index % notes.len()];
+
+                for _ in 0..(index / notes.len()) {
+                    note += Interval::PerfectOctave;
+                }
+
+                note
+            })
+            .collect();
+
+        Ok(tones)
+    }
+}
+
+impl HasTonic for Key {
+    fn tonic(&self) -> Note {
+        self.tonic
+    }
+}
+
+impl HasMode for Key {
+    fn mode(&self) -> Scale {
+        self.mode
+    }
+}
+
+impl HasName for Key {
+    fn name(&self) -> String {
+        let mode = match self.mode {
+            Scale::Ionian => "major",
+            Scale::Aeolian => "minor",
+            m => m.static_name(),
+        };
+
+        format!("{} {}", self.tonic.static_name(), mode)
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Parsable for Key {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let symbol = symbol.trim();
+
+        // Allow `C`, `Cm`, `C major`, `C minor`, `D dorian`, etc.
+        let (tonic, mode) = match symbol.split_once(char::is_whitespace) {
+            Some((tonic, mode)) => (tonic, Scale::parse(mode)?),
+            None => match symbol.strip_suffix('m') {
+                Some(tonic) => (tonic, Scale::Aeolian),
+                None => (symbol, Scale::Ionian),
+            },
+        };
+
+        Ok(Key::new(Note::parse(tonic)?, mode))
+    }
+}
+
+// Helpers.
+
+/// Returns the (simple or compound) [`Interval`] from `low` up to `high`, if one exists.
+fn interval_between(low: Note, high: Note) -> Option<Interval> {
+    ALL_INTERVALS.iter().find(|i| low + **i == high).copied()
+}
+
+/// Builds a [`Chord`] from tones stacked in thirds (i.e., a root, third, fifth, and optional seventh).
+fn stacked_chord(tones: &[Note]) -> Chord {
+    let root = tones[0];
+    let intervals = tones[1..].iter().map(|t| interval_between(root, *t)).collect::<Vec<_>>();
+
+    let chord = (|| {
+        let mut chord = Chord::new(root);
+
+        match intervals[0]? {
+            Interval::MajorThird => {}
+            Interval::MinorThird => chord = chord.minor(),
+            _ => return None,
+        }
+
+        match intervals[1]? {
+            Interval::PerfectFifth => {}
+            Interval::DiminishedFifth => chord = chord.flat5(),
+            Interval::AugmentedFifth => chord = chord.augmented(),
+            _ => return None,
+        }
+
+        if let Some(seventh) = intervals.get(2) {
+            match (*seventh)? {
+                Interval::MajorSeventh => chord = chord.major7(),
+                Interval::MinorSeventh => chord = chord.seven(),
+                Interval::DiminishedSeventh if intervals[0] == Some(Interval::MinorThird) && intervals[1] == Some(Interval::DiminishedFifth) => {
+                    chord = Chord::new(root).diminished();
+                }
+                _ => return None,
+            }
+        }
+
+        Some(chord)
+    })();
+
+    // If the tones are not a "standard" stack of thirds, then fall back to guessing the chord.
+    chord.unwrap_or_else(|| {
+        Chord::try_from_notes(tones)
+            .ok()
+            .and_then(|c| c.into_iter().find(|c| c.root().pitch() == root.pitch()))
+            .unwrap_or_else(|| Chord::new(root))
+    })
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{chord::HasChord, note::*};
+    use pretty_assertions::assert_eq;
+
+    fn names(chords: &[Chord]) -> Vec<String> {
+        chords.iter().map(|c| c.name()).collect()
+    }
+
+    #[test]
+    fn test_notes() {
+        assert_eq!(Key::major(D).notes(), vec![D, E, FSharp, G, A, B, CSharpFive]);
+        assert_eq!(Key::minor(C).notes(), vec![C, D, EFlat, F, G, AFlat, BFlat]);
+        assert_eq!(Key::new(D, Scale::Dorian).notes(), vec![D, E, F, G, A, B, CFive]);
+    }
+
+    #[test]
+    fn test_diatonic_chords() {
+        assert_eq!(names(&Key::major(C).triads()), vec!["C", "Dm", "Em", "F", "G", "Am", "Bm(♭5)"]);
+        assert_eq!(names(&Key::major(C).sevenths()), vec!["Cmaj7", "Dm7", "Em7", "Fmaj7", "G7", "Am7", "Bm7(♭5)"]);
+        assert_eq!(names(&Key::minor(A).sevenths()), vec!["Am7", "Bm7(♭5)", "Cmaj7", "Dm7", "Em7", "Fmaj7", "G7"]);
+        assert_eq!(
+            names(&Key::new(A, Scale::HarmonicMinor).sevenths()),
+            vec!["Am(maj7)", "Bm7(♭5)", "C+(maj7)", "Dm7", "E7", "Fmaj7", "G♯dim"]
+        );
+        assert_eq!(names(&Key::major(EFlat).triads()), vec!["E♭", "Fm", "Gm", "A♭", "B♭", "Cm", "Dm(♭5)"]);
+
+        assert_eq!(Key::major(C).seventh(5).unwrap().chord(), vec![G, B, DFive, FFive]);
+        assert_eq!(Key::major(C).triad(7).unwrap().chord(), vec![B, DFive, FFive]);
+        assert!(Key::major(C).triad(0).is_err());
+        assert!(Key::major(C).triad(8).is_err());
+    }
+
+    #[test]
+    fn test_roman_numerals() {
+        let key = Key::major(C);
+
+        assert_eq!(
+            (1..=7).map(|d| key.roman_numeral(d, false).unwrap()).collect::<Vec<_>>(),
+            vec!["I", "ii", "iii", "IV", "V", "vi", "vii°"]
+        );
+        assert_eq!(
+            (1..=7).map(|d| key.roman_numeral(d, true).unwrap()).collect::<Vec<_>>(),
+            vec!["Imaj7", "ii7", "iii7", "IVmaj7", "V7", "vi7", "viiø7"]
+        );
+
+        let key = Key::new(C, Scale::HarmonicMinor);
+
+        assert_eq!(key.roman_numeral(3, false).unwrap(), "III+");
+        assert_eq!(key.roman_numeral(7, true).unwrap(), "vii°7");
+
+        assert_eq!(Key::new(C, Scale::MajorPentatonic).roman_numeral(2, false).unwrap(), "II");
+    }
+
+    #[test]
+    fn test_numerals() {
+        let key = Key::major(G);
+
+        assert_eq!(names(&key.chords_from_numerals("I vi ii7 V7").unwrap()), vec!["G", "Em", "Am7", "D7"]);
+        assert_eq!(key.analyze(&key.chords_from_numerals("I V7/vi vi").unwrap())[1].numeral.as_ref().unwrap().name(), "V7/vi");
+    }
+
+    #[test]
+    fn test_signature() {
+        assert_eq!(Key::major(C).signature(), 0);
+        assert_eq!(Key::major(D).signature(), 2);
+        assert_eq!(Key::major(FSharp).signature(), 6);
+        assert_eq!(Key::major(BFlat).signature(), -2);
+        assert_eq!(Key::minor(C).signature(), -3);
+        assert_eq!(Key::new(E, Scale::Phrygian).signature(), 0);
+        assert_eq!(Key::new(A, Scale::HarmonicMinor).signature(), 0);
+    }
+
+    #[test]
+    fn test_spell() {
+        assert_eq!(Key::major(D).spell(GFlat), FSharp);
+        assert_eq!(Key::major(D).spell(BFlat), ASharp);
+        assert_eq!(Key::major(F).spell(ASharp), BFlat);
+        assert_eq!(Key::major(F).spell(CSharp), DFlat);
+        assert_eq!(Key::major(CSharp).spell(CFive), BSharp);
+        assert_eq!(Key::major(GFlat).spell(B), CFlatFive);
+        assert_eq!(Key::major(C).spell(C), C);
+    }
+
+    #[test]
+    fn test_related_keys() {
+        assert_eq!(Key::major(C).relative(), Some(Key::minor(A)));
+        assert_eq!(Key::minor(A).relative(), Some(Key::major(C)));
+        assert_eq!(Key::minor(GSharpFive).relative(), Some(Key::major(BFive)));
+        assert_eq!(Key::new(D, Scale::Dorian).relative(), Some(Key::major(C)));
+        assert_eq!(Key::new(B, Scale::Altered).relative(), Some(Key::new(C, Scale::MelodicMinor)));
+        assert_eq!(Key::new(C, Scale::WholeTone).relative(), None);
+        assert_eq!(Key::new(C, Scale::HarmonicMajor).relative(), Some(Key::minor(A)));
+
+        for key in [Key::major(C), Key::major(FSharp), Key::major(B), Key::minor(A), Key::minor(EFlat), Key::minor(GSharpFive)] {
+            assert_eq!(key.relative().and_then(|k| k.relative()), Some(key));
+        }
+
+        assert_eq!(Key::major(C).parallel(), Key::minor(C));
+        assert_eq!(Key::minor(C).parallel(), Key::major(C));
+        assert_eq!(Key::new(D, Scale::Dorian).parallel(), Key::major(D));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Key::parse("C").unwrap(), Key::major(C));
+        assert_eq!(Key::parse("F#m").unwrap(), Key::minor(FSharp));
+        assert_eq!(Key::parse("Bb major").unwrap(), Key::major(BFlat));
+        assert_eq!(Key::parse("D dorian").unwrap(), Key::new(D, Scale::Dorian));
+        assert_eq!(Key::parse("F harmonic minor").unwrap(), Key::new(F, Scale::HarmonicMinor));
+        assert!(Key::parse("H").is_err());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(Key::major(BFlat).name(), "B♭ major");
+        assert_eq!(Key::minor(C).to_string(), "C minor");
+        assert_eq!(Key::new(D, Scale::Dorian).name(), "D dorian");
+    }
+}
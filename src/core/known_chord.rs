@@ -4,6 +4,7 @@ use crate::core::{
     base::{HasDescription, HasName, HasStaticName},
     interval::Interval,
     modifier::Degree,
+    scale::{HasKnownScale, Scale},
 };
 
 // Traits.
@@ -90,141 +91,34 @@ impl HasDescription for KnownChord {
     }
 }
 
-impl HasRelativeScale for KnownChord {
-    fn relative_scale(&self) -> Vec<Interval> {
+impl HasKnownScale for KnownChord {
+    fn known_scale(&self) -> Scale {
         match self {
             KnownChord::Unknown => unreachable!(),
-            KnownChord::Major => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::Minor => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MinorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::Major7 => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::Dominant(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::MinorMajor7 => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::MinorDominant(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::DominantSharp11(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::Augmented => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::PerfectFourth,
-                Interval::AugmentedFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::AugmentedMajor7 => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::AugmentedFifth,
-                Interval::MajorSixth,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::AugmentedDominant(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::AugmentedFifth,
-                Interval::AugmentedSixth,
-            ],
-            KnownChord::HalfDiminished(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::DiminishedFifth,
-                Interval::MinorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::Diminished => vec![
-                Interval::PerfectUnison,
-                Interval::MajorSecond,
-                Interval::MinorThird,
-                Interval::PerfectFourth,
-                Interval::DiminishedFifth,
-                Interval::MinorSixth,
-                Interval::DiminishedSeventh,
-                Interval::MajorSeventh,
-            ],
-            KnownChord::DominantFlat9(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MinorSecond,
-                Interval::MinorThird,
-                Interval::MajorThird,
-                Interval::AugmentedFourth,
-                Interval::PerfectFifth,
-                Interval::MajorSixth,
-                Interval::MinorSeventh,
-            ],
-            KnownChord::DominantSharp9(_) => vec![
-                Interval::PerfectUnison,
-                Interval::MinorSecond,
-                Interval::MinorThird,
-                Interval::DiminishedFourth,
-                Interval::DiminishedFifth,
-                Interval::MinorSixth,
-                Interval::MinorSeventh,
-            ],
+            KnownChord::Major => Scale::Ionian,
+            KnownChord::Minor => Scale::Aeolian,
+            KnownChord::Major7 => Scale::Ionian,
+            KnownChord::Dominant(_) => Scale::Mixolydian,
+            KnownChord::MinorMajor7 => Scale::MelodicMinor,
+            KnownChord::MinorDominant(_) => Scale::Dorian,
+            KnownChord::DominantSharp11(_) => Scale::LydianDominant,
+            KnownChord::Augmented => Scale::IonianAugmented,
+            KnownChord::AugmentedMajor7 => Scale::LydianAugmented,
+            KnownChord::AugmentedDominant(_) => Scale::WholeTone,
+            KnownChord::HalfDiminished(_) => Scale::LocrianNatural2,
+            KnownChord::Diminished => Scale::WholeHalfDiminished,
+            KnownChord::DominantFlat9(_) => Scale::HalfWholeDiminished,
+            KnownChord::DominantSharp9(_) => Scale::Altered,
         }
     }
 }
 
+impl HasRelativeScale for KnownChord {
+    fn relative_scale(&self) -> Vec<Interval> {
+        self.known_scale().relative_scale()
+    }
+}
+
 impl HasRelativeChord for KnownChord {
     fn relative_chord(&self) -> Vec<Interval> {
         match self {
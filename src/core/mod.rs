@@ -2,13 +2,28 @@
 
 pub mod base;
 pub mod chord;
+pub mod chord_scale;
+pub mod fretboard;
 pub mod helpers;
 pub mod interval;
+pub mod key;
 pub mod known_chord;
 pub mod modifier;
+pub mod name_style;
 pub mod named_pitch;
+pub mod negative;
+pub mod neo_riemannian;
 pub mod note;
 pub mod octave;
 #[allow(missing_docs)]
 pub mod parser;
 pub mod pitch;
+pub mod pitch_class_set;
+pub mod progression;
+pub mod reharm;
+pub mod roman;
+pub mod scale;
+pub mod tone_row;
+pub mod tuning;
+pub mod voice_leading;
+pub mod voicing;
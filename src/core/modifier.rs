@@ -100,6 +100,11 @@ pub enum Extension {
     Add11,
     /// Add13 extension.
     Add13,
+
+    /// No3 extension (i.e., the third is omitted; e.g., a power chord).
+    No3,
+    /// No5 extension (i.e., the fifth is omitted).
+    No5,
 }
 
 // Impls.
@@ -163,6 +168,9 @@ impl HasStaticName for Extension {
             Extension::Add9 => "add9",
             Extension::Add11 => "add11",
             Extension::Add13 => "add13",
+
+            Extension::No3 => "no3",
+            Extension::No5 => "no5",
         }
     }
 }
@@ -186,11 +194,12 @@ pub fn likely_extension_sets() -> &'static [Vec<Extension>] {
 
 // Statics.
 
-static KNOWN_MODIFIER_SETS: Lazy<[Vec<Modifier>; 35]> = Lazy::new(|| {
+static KNOWN_MODIFIER_SETS: Lazy<[Vec<Modifier>; 36]> = Lazy::new(|| {
     [
         vec![],
         vec![Modifier::Minor],
         vec![Modifier::Major7],
+        vec![Modifier::Major7, Modifier::Dominant(Degree::Nine)],
         vec![Modifier::Dominant(Degree::Seven)],
         vec![Modifier::Dominant(Degree::Nine)],
         vec![Modifier::Dominant(Degree::Eleven)],
@@ -237,7 +246,7 @@ static ONE_OFF_MODIFIER_SETS: Lazy<[Vec<Modifier>; 6]> = Lazy::new(|| {
     ]
 });
 
-static LIKELY_EXTENSION_SETS: Lazy<[Vec<Extension>; 12]> = Lazy::new(|| {
+static LIKELY_EXTENSION_SETS: Lazy<[Vec<Extension>; 15]> = Lazy::new(|| {
     [
         vec![],
         vec![Extension::Sus2],
@@ -251,5 +260,9 @@ static LIKELY_EXTENSION_SETS: Lazy<[Vec<Extension>; 12]> = Lazy::new(|| {
         vec![Extension::Flat11],
         vec![Extension::Flat13],
         vec![Extension::Sharp13],
+        // Shell voicings (e.g., on guitar) tend to leave out the fifth (these are only tried with a seventh).
+        vec![Extension::No5],
+        vec![Extension::Add13, Extension::No5],
+        vec![Extension::No3],
     ]
 });
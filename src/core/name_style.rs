@@ -0,0 +1,271 @@
+//! A module for the styles in which chord names can be written (e.g., jazz shorthand, Berklee, ASCII-only, iReal Pro, or LaTeX).
+
+use crate::core::{
+    base::{HasStaticName, Parsable, Res},
+    note::Spelling,
+};
+
+// Enum.
+
+/// A style in which chord names are written (see [`NamingOptions`]).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum NameStyle {
+    /// The standard style, with parenthesized Unicode alterations (e.g., `Cm9(♭5)(add2)/E`).
+    #[default]
+    Standard,
+    /// Jazz shorthand, with `ø`, `Δ`, and `°` (e.g., `Cø9(add2)/E`).
+    Jazz,
+    /// The Berklee style, with `-` for minor, and the alterations in one set of parentheses (e.g., `C-7(b5)`).
+    Berklee,
+    /// ASCII characters only, without parentheses (e.g., `Cm7b5`), which can be parsed again.
+    Ascii,
+    /// The chord qualities of iReal Pro (e.g., `Ch7`, `C^9`, `C-^7`, or `C7b9#11`).
+    IReal,
+    /// LaTeX math, with the extensions as a superscript (e.g., `$\mathrm{C}\mathrm{m}^{7(\flat 5)}$`).
+    Latex,
+}
+
+// Structs.
+
+/// The options with which a chord is written; i.e., the [`Spelling`] policy of its notes, and the [`NameStyle`] of its name.
+///
+/// These only change how a chord is written, so they are passed in when it is written (e.g., [`Chord::name_with`](crate::core::chord::Chord::name_with)),
+/// rather than being kept in the chord.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct NamingOptions {
+    /// The [`Spelling`] policy used for the notes.
+    spelling: Spelling,
+    /// The [`NameStyle`] used for the name.
+    style: NameStyle,
+}
+
+// Impls.
+
+impl HasStaticName for NameStyle {
+    fn static_name(&self) -> &'static str {
+        match self {
+            NameStyle::Standard => "standard",
+            NameStyle::Jazz => "jazz",
+            NameStyle::Berklee => "berklee",
+            NameStyle::Ascii => "ascii",
+            NameStyle::IReal => "ireal",
+            NameStyle::Latex => "latex",
+        }
+    }
+}
+
+impl Parsable for NameStyle {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        match symbol.trim().to_lowercase().replace([' ', '-', '_'], "").as_str() {
+            "standard" | "default" | "unicode" => Ok(NameStyle::Standard),
+            "jazz" => Ok(NameStyle::Jazz),
+            "berklee" => Ok(NameStyle::Berklee),
+            "ascii" | "classical" | "plain" => Ok(NameStyle::Ascii),
+            "ireal" | "irealpro" | "irealb" => Ok(NameStyle::IReal),
+            "latex" | "tex" => Ok(NameStyle::Latex),
+            _ => Err(anyhow::Error::msg(format!(
+                "Unknown name style `{}` (expected `standard`, `jazz`, `berklee`, `ascii`, `ireal`, or `latex`).",
+                symbol
+            ))),
+        }
+    }
+}
+
+impl NamingOptions {
+    /// Returns the options with the given [`Spelling`] policy.
+    pub fn with_spelling(mut self, spelling: Spelling) -> Self {
+        self.spelling = spelling;
+
+        self
+    }
+
+    /// Returns the options with the given [`NameStyle`].
+    pub fn with_style(mut self, style: NameStyle) -> Self {
+        self.style = style;
+
+        self
+    }
+
+    /// Returns the [`Spelling`] policy.
+    pub fn spelling(&self) -> Spelling {
+        self.spelling
+    }
+
+    /// Returns the [`NameStyle`].
+    pub fn style(&self) -> NameStyle {
+        self.style
+    }
+}
+
+impl NameStyle {
+    /// Writes a chord symbol in this style, from the standard names of its parts; i.e., the root (e.g., `B♭`), the quality
+    /// (e.g., `m9`), the alterations and extensions (e.g., `♭5` and `add2`), and the slash note (e.g., `E`).
+    pub(crate) fn write(&self, root: &str, quality: &str, alterations: &[&str], slash: Option<&str>) -> String {
+        let slash = |f: fn(&str) -> String| slash.map(|s| format!("/{}", f(s))).unwrap_or_default();
+
+        match self {
+            NameStyle::Standard => format!("{}{}{}{}", root, quality, parenthesized(alterations), slash(str::to_owned)),
+            NameStyle::Jazz => {
+                let (quality, alterations) = jazz_quality(quality, alterations);
+
+                format!("{}{}{}{}", root, quality, parenthesized(&alterations), slash(str::to_owned))
+            }
+            NameStyle::Berklee => {
+                let quality = match quality {
+                    "dim" => "o7".to_owned(),
+                    q if q.starts_with('m') && !q.starts_with("maj") => format!("-{}", &q[1..]),
+                    q => q.to_owned(),
+                };
+                let alterations = alterations.iter().map(|a| ascii(a)).collect::<Vec<_>>();
+                let alterations = if alterations.is_empty() { String::new() } else { format!("({})", alterations.join(",")) };
+
+                format!("{}{}{}{}", ascii(root), quality, alterations, slash(ascii))
+            }
+            NameStyle::Ascii => format!("{}{}{}{}", ascii(root), ascii(quality), ascii(&alterations.concat()), slash(ascii)),
+            NameStyle::IReal => {
+                let (quality, alterations) = ireal_quality(quality, alterations);
+                let alterations = alterations.iter().map(|a| ascii(a)).collect::<String>();
+
+                format!("{}{}{}{}", ascii(root), quality, alterations, slash(ascii))
+            }
+            NameStyle::Latex => {
+                // Minor and augmented qualities stay on the line, and everything else is raised.
+                let (line, raised) = match quality {
+                    "dim" => ("", "\\circ 7".to_owned()),
+                    q if q.starts_with('m') && !q.starts_with("maj") => ("m", latex(&q[1..])),
+                    q if q.starts_with('+') => ("+", latex(&q[1..])),
+                    q => ("", latex(q)),
+                };
+                let raised = format!("{}{}", raised, latex(&parenthesized(alterations)));
+                let raised = if raised.is_empty() { String::new() } else { format!("^{{{}}}", raised) };
+
+                format!("${}{}{}{}$", latex(root), latex(line), raised, slash(latex))
+            }
+        }
+    }
+}
+
+// Helpers.
+
+/// Returns the alterations, each in parentheses (e.g., `(♭5)(add2)`).
+fn parenthesized(alterations: &[impl AsRef<str>]) -> String {
+    alterations.iter().map(|a| format!("({})", a.as_ref())).collect()
+}
+
+/// Rewrites the quality in jazz shorthand (e.g., `m7` with a `♭5` is `ø7`, `maj9` is `Δ9`, and `m` with a `maj7` is `mΔ7`).
+fn jazz_quality<'a>(quality: &str, alterations: &[&'a str]) -> (String, Vec<&'a str>) {
+    let mut alterations = alterations.to_vec();
+    let major = alterations.iter().position(|a| a.starts_with("maj"));
+
+    let quality = match (quality, major) {
+        ("dim", _) => "°7".to_owned(),
+        ("m7" | "m9" | "m11" | "m13", _) if alterations.contains(&"♭5") => {
+            alterations.retain(|a| *a != "♭5");
+
+            format!("ø{}", &quality[1..])
+        }
+        ("m" | "+", Some(k)) => format!("{}Δ{}", quality, &alterations.remove(k)[3..]),
+        (q, _) if q.starts_with("maj") => format!("Δ{}", &q[3..]),
+        (q, _) => q.to_owned(),
+    };
+
+    (quality, alterations)
+}
+
+/// Rewrites the quality for iReal Pro (e.g., `m7` with a `♭5` is `h7`, `maj9` is `^9`, `m` is `-`, and `sus4` is `sus`).
+fn ireal_quality<'a>(quality: &str, alterations: &[&'a str]) -> (String, Vec<&'a str>) {
+    let mut alterations = alterations.iter().map(|a| if *a == "sus4" { "sus" } else { *a }).collect::<Vec<_>>();
+    let major = alterations.iter().position(|a| a.starts_with("maj"));
+
+    let quality = match (quality, major) {
+        ("dim", _) => "o7".to_owned(),
+        ("m7" | "m9", _) if alterations.contains(&"♭5") => {
+            alterations.retain(|a| *a != "♭5");
+
+            format!("h{}", &quality[1..])
+        }
+        ("m", Some(k)) => format!("-^{}", &alterations.remove(k)[3..]),
+        ("+", Some(k)) => format!("^{}#5", &alterations.remove(k)[3..]),
+        (q, _) if q.starts_with("maj") => format!("^{}", &q[3..]),
+        (q, _) if q.starts_with('m') => format!("-{}", &q[1..]),
+        (q, _) => q.to_owned(),
+    };
+
+    // Sixths are written as part of the quality (e.g., `C6`, `C-6`, or `C69`).
+    if let Some(k) = alterations.iter().position(|a| *a == "add6") {
+        alterations.remove(k);
+
+        let nine = alterations.iter().position(|a| *a == "add9").map(|k| alterations.remove(k));
+
+        return (format!("{}6{}", quality, if nine.is_some() { "9" } else { "" }), alterations);
+    }
+
+    (quality, alterations)
+}
+
+/// Rewrites the symbol with ASCII characters only (e.g., `B♭` is `Bb`, and `F𝄲` is `Fqs`).
+fn ascii(symbol: &str) -> String {
+    symbol.replace('♭', "b").replace('♯', "#").replace('𝄫', "bb").replace('𝄪', "##").replace('𝄲', "qs").replace('𝄳', "qf")
+}
+
+/// Rewrites the symbol as LaTeX math (e.g., `B♭` is `\mathrm{B}\flat`, and `add9` is `\mathrm{add}9`).
+fn latex(symbol: &str) -> String {
+    let mut result = String::new();
+    let mut letters = String::new();
+
+    // Letters are grouped into upright text, and a trailing space ends the last group.
+    for c in symbol.replace('𝄲', "qs").replace('𝄳', "qf").chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_alphabetic() {
+            letters.push(c);
+            continue;
+        }
+
+        if !letters.is_empty() {
+            result.push_str(&format!("\\mathrm{{{}}}", letters));
+            letters.clear();
+        }
+
+        match c {
+            '♭' => result.push_str("\\flat "),
+            '♯' => result.push_str("\\sharp "),
+            '𝄫' => result.push_str("\\flat\\flat "),
+            '𝄪' => result.push_str("\\sharp\\sharp "),
+            ' ' => {}
+            c => result.push(c),
+        }
+    }
+
+    result.trim_end().to_owned()
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(NameStyle::parse("Jazz").unwrap(), NameStyle::Jazz);
+        assert_eq!(NameStyle::parse("iReal Pro").unwrap(), NameStyle::IReal);
+        assert_eq!(NameStyle::parse("tex").unwrap(), NameStyle::Latex);
+        assert_eq!(NameStyle::parse(NameStyle::Berklee.static_name()).unwrap(), NameStyle::Berklee);
+        assert!(NameStyle::parse("fancy").is_err());
+    }
+
+    #[test]
+    fn test_write() {
+        let write = |style: NameStyle| style.write("B♭", "m9", &["♭5", "add2"], Some("E"));
+
+        assert_eq!(write(NameStyle::Standard), "B♭m9(♭5)(add2)/E");
+        assert_eq!(write(NameStyle::Jazz), "B♭ø9(add2)/E");
+        assert_eq!(write(NameStyle::Berklee), "Bb-9(b5,add2)/E");
+        assert_eq!(write(NameStyle::Ascii), "Bbm9b5add2/E");
+        assert_eq!(write(NameStyle::IReal), "Bbh9add2/E");
+        assert_eq!(write(NameStyle::Latex), "$\\mathrm{B}\\flat\\mathrm{m}^{9(\\flat 5)(\\mathrm{add}2)}/\\mathrm{E}$");
+    }
+}
@@ -21,6 +21,12 @@ pub trait HasLetter {
     fn letter(&self) -> &'static str;
 }
 
+/// A trait for types that have accidentals.
+pub trait HasAccidentals {
+    /// Returns the number of accidentals of the type (positive for sharps, and negative for flats).
+    fn accidentals(&self) -> i8;
+}
+
 // Enum.
 
 /// An enum representing named pitch.
@@ -208,6 +214,13 @@ impl HasLetter for NamedPitch {
     }
 }
 
+impl HasAccidentals for NamedPitch {
+    fn accidentals(&self) -> i8 {
+        // The named pitches are ordered by fifths, so every seven steps from `F` adds an accidental.
+        (*self as i8 - NamedPitch::F as i8).div_euclid(7)
+    }
+}
+
 impl HasStaticName for NamedPitch {
     #[no_coverage]
     fn static_name(&self) -> &'static str {
@@ -385,7 +398,8 @@ impl From<&Pitch> for NamedPitch {
 
 // Statics.
 
-static ALL_PITCHES: [NamedPitch; 49] = [
+/// All of the [`NamedPitch`]es, ordered by fifths.
+pub static ALL_PITCHES: [NamedPitch; 49] = [
     NamedPitch::FTripleFlat,
     NamedPitch::CTripleFlat,
     NamedPitch::GTripleFlat,
@@ -454,6 +468,12 @@ mod tests {
     #[test]
     fn test_properties() {
         assert_eq!(NamedPitch::A.named_pitch(), NamedPitch::A);
+        assert_eq!(NamedPitch::C.accidentals(), 0);
+        assert_eq!(NamedPitch::B.accidentals(), 0);
+        assert_eq!(NamedPitch::FSharp.accidentals(), 1);
+        assert_eq!(NamedPitch::BFlat.accidentals(), -1);
+        assert_eq!(NamedPitch::EDoubleSharp.accidentals(), 2);
+        assert_eq!(NamedPitch::FTripleFlat.accidentals(), -3);
     }
 
     #[test]
@@ -0,0 +1,136 @@
+//! A module for negative harmony.
+//!
+//! Negative harmony mirrors pitches across the axis between the tonic and the dominant of a key (e.g., in C, the axis
+//! lies between `E♭` and `E`, so `C` ⇄ `G`, `D` ⇄ `F`, `E` ⇄ `E♭`, `A` ⇄ `B♭`, and `B` ⇄ `A♭`).
+
+use crate::core::{
+    base::{HasName, Res},
+    chord::{Chord, Chordable, HasChord, HasRoot},
+    key::{HasTonic, Key},
+    named_pitch::{HasNamedPitch, ALL_PITCHES},
+    note::Note,
+    octave::HasOctave,
+    progression::Progression,
+};
+
+// Traits.
+
+/// A trait for types that can be mirrored into their negative harmony.
+pub trait Negatable: Sized {
+    /// Returns the type mirrored across the tonic/dominant axis of the given [`Key`].
+    fn negative(&self, key: &Key) -> Res<Self>;
+}
+
+// Impls.
+
+impl Negatable for Note {
+    fn negative(&self, key: &Key) -> Res<Self> {
+        let tonic = key.tonic();
+
+        // Mirror the pitch across the axis (which lies three and a half semitones above the tonic).
+        let mirrored = Note::from_semitones(2 * tonic.semitones() + 7 - self.semitones()).ok_or_else(|| anyhow::Error::msg(format!("The negative of `{}` is out of range.", self.name())))?;
+
+        // Mirror the spelling along the line of fifths (where the axis lies between the tonic and the dominant).
+        let index = |n: &Note| ALL_PITCHES.iter().position(|p| *p == n.named_pitch()).map(|k| k as i32);
+        let named_pitch = index(&tonic).zip(index(self)).and_then(|(t, k)| usize::try_from(2 * t + 1 - k).ok()).and_then(|k| ALL_PITCHES.get(k));
+
+        Ok(named_pitch.and_then(|p| mirrored.enharmonic(*p)).unwrap_or(mirrored))
+    }
+}
+
+impl Negatable for Chord {
+    fn negative(&self, key: &Key) -> Res<Self> {
+        let notes = self.chord().iter().map(|n| n.negative(key)).collect::<Res<Vec<_>>>()?;
+
+        // Move the notes into the octave above the (new) bass, and drop doubled pitch classes, so that the chord can be identified.
+        let bass = notes.iter().min().copied().ok_or_else(|| anyhow::Error::msg("Cannot take the negative of an empty chord."))?;
+        let mut close = vec![bass];
+
+        for note in notes {
+            let moved = Note::from_semitones(bass.semitones() + (note.semitones() - bass.semitones()).rem_euclid(12))
+                .and_then(|n| n.enharmonic(note.named_pitch()))
+                .unwrap_or(note);
+
+            if !close.iter().any(|n| (n.semitones() - moved.semitones()).rem_euclid(12) == 0) {
+                close.push(moved);
+            }
+        }
+
+        let chord = Chord::try_from_notes(&close)?.into_iter().next().ok_or_else(|| {
+            let names = close.iter().map(|n| n.name()).collect::<Vec<_>>().join(" ");
+
+            anyhow::Error::msg(format!("No chord matches the negative of `{}` ({}).", self.name(), names))
+        })?;
+
+        // Keep the root in the same octave as the original.
+        Ok(chord.with_octave(self.root().octave()))
+    }
+}
+
+impl Negatable for Progression {
+    fn negative(&self, key: &Key) -> Res<Self> {
+        self.try_map_chords(|c| c.negative(key))
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::Parsable, note::*};
+    use pretty_assertions::assert_eq;
+
+    fn negative(symbols: &str, key: &str) -> String {
+        let key = Key::parse(key).unwrap();
+
+        Progression::parse(symbols)
+            .unwrap()
+            .negative(&key)
+            .unwrap()
+            .chords()
+            .iter()
+            .map(|c| c.name())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_note() {
+        let c = Key::major(C);
+
+        assert_eq!(C.negative(&c).unwrap(), G);
+        assert_eq!(G.negative(&c).unwrap(), C);
+        assert_eq!(E.negative(&c).unwrap(), EFlat);
+        assert_eq!(A.negative(&c).unwrap(), BFlatThree);
+        assert_eq!(B.negative(&c).unwrap(), AFlatThree);
+        assert_eq!(FSharp.negative(&c).unwrap(), DFlat);
+        assert_eq!(D.negative(&c).unwrap().negative(&c).unwrap(), D);
+
+        let e_flat = Key::major(EFlat);
+
+        assert_eq!(EFlat.negative(&e_flat).unwrap(), BFlat);
+        assert_eq!(G.negative(&e_flat).unwrap(), GFlat);
+        assert_eq!(D.negative(&e_flat).unwrap(), CFlatFive);
+    }
+
+    #[test]
+    fn test_chord() {
+        assert_eq!(negative("C", "C"), "Cm");
+        assert_eq!(negative("G", "C"), "Fm");
+        assert_eq!(negative("Fm7 Bb7 Cmaj7", "C"), "Em7 Bm7(♭5) A♭maj7");
+        assert_eq!(negative("Dm7 G7 Cmaj7", "C"), "Gm7 Dm7(♭5) A♭maj7");
+        assert_eq!(negative("Am E7 | N.C. |", "Am"), "A Bm7(♭5)");
+
+        // Rests, durations, and the tempo are kept.
+        let progression = Progression::parse("3/4 90bpm | C | N.C. |").unwrap().negative(&Key::major(C)).unwrap();
+        assert_eq!(progression.entries().len(), 2);
+        assert_eq!(progression.beats(), 6.0);
+        assert_eq!(progression.tempo(), 90.0);
+        assert_eq!(progression.chords()[0], Chord::new(C).minor());
+
+        let progression = Progression::parse("|: C | G7 :|x3").unwrap().negative(&Key::major(C)).unwrap();
+        assert_eq!(progression.entries().len(), 6);
+        assert_eq!(progression.repeats(), Progression::parse("|: C | G7 :|x3").unwrap().repeats());
+    }
+}
@@ -0,0 +1,314 @@
+//! A module for Neo-Riemannian transformations of triads, and navigation of the Tonnetz.
+//!
+//! The three basic transformations ([`Transform::Parallel`], [`Transform::LeadingTone`], and [`Transform::Relative`]) each
+//! move a single voice of a major or minor triad, and connect the 24 triads into the Tonnetz.
+
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    fmt::{self, Display, Formatter},
+};
+
+use crate::core::{
+    base::{HasDescription, HasName, HasStaticName, Parsable, Res},
+    chord::{Chord, Chordable, HasRoot},
+    interval::Interval,
+    known_chord::HasRelativeChord,
+    note::{Respellable, Spelling},
+    octave::HasOctave,
+};
+
+// Traits.
+
+/// A trait for types that can be transformed by Neo-Riemannian transformations.
+pub trait Transformable: Sized {
+    /// Returns the result of applying the given transformation.
+    fn transformed(&self, transform: Transform) -> Res<Self>;
+
+    /// Returns the result of applying the given transformations, in order.
+    fn transformed_by(&self, transforms: &[Transform]) -> Res<Self>;
+
+    /// Returns the shortest sequence of basic transformations (P, L, and R) that leads to the target.
+    fn tonnetz_path(&self, target: &Self) -> Res<Vec<Transform>>;
+}
+
+// Enum.
+
+/// A Neo-Riemannian transformation.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+pub enum Transform {
+    /// Exchanges a triad for its parallel (e.g., `C` ⇄ `Cm`).
+    Parallel,
+    /// Exchanges a triad for its leading-tone exchange (e.g., `C` ⇄ `Em`).
+    LeadingTone,
+    /// Exchanges a triad for its relative (e.g., `C` ⇄ `Am`).
+    Relative,
+    /// The "Nebenverwandt" transformation, RLP (e.g., `C` ⇄ `Fm`).
+    Nebenverwandt,
+    /// The slide transformation, LPR (e.g., `C` ⇄ `C♯m`).
+    Slide,
+    /// The hexatonic pole transformation, LPL (e.g., `C` ⇄ `G♯m`).
+    HexatonicPole,
+}
+
+// Statics.
+
+/// The basic transformations, which connect the triads on the Tonnetz.
+pub static BASIC_TRANSFORMS: [Transform; 3] = [Transform::Parallel, Transform::LeadingTone, Transform::Relative];
+
+// Impls.
+
+impl Transform {
+    /// Returns the basic transformations (P, L, and R) that make up this transformation.
+    pub fn basic(&self) -> Vec<Transform> {
+        match self {
+            Transform::Parallel | Transform::LeadingTone | Transform::Relative => vec![*self],
+            Transform::Nebenverwandt => vec![Transform::Relative, Transform::LeadingTone, Transform::Parallel],
+            Transform::Slide => vec![Transform::LeadingTone, Transform::Parallel, Transform::Relative],
+            Transform::HexatonicPole => vec![Transform::LeadingTone, Transform::Parallel, Transform::LeadingTone],
+        }
+    }
+
+    /// Parses a sequence of transformations (e.g., `PLR`, `L P L`, or `N, S`).
+    pub fn parse_sequence(symbol: &str) -> Res<Vec<Transform>> {
+        symbol
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|s| !s.is_empty())
+            .map(|s| match Transform::parse(s) {
+                Ok(transform) => Ok(vec![transform]),
+                Err(_) => s.chars().map(|c| Transform::parse(&c.to_string())).collect(),
+            })
+            .collect::<Res<Vec<_>>>()
+            .map(|t| t.concat())
+    }
+}
+
+impl HasStaticName for Transform {
+    fn static_name(&self) -> &'static str {
+        match self {
+            Transform::Parallel => "P",
+            Transform::LeadingTone => "L",
+            Transform::Relative => "R",
+            Transform::Nebenverwandt => "N",
+            Transform::Slide => "S",
+            Transform::HexatonicPole => "H",
+        }
+    }
+}
+
+impl HasDescription for Transform {
+    fn description(&self) -> &'static str {
+        match self {
+            Transform::Parallel => "parallel",
+            Transform::LeadingTone => "leading-tone exchange",
+            Transform::Relative => "relative",
+            Transform::Nebenverwandt => "nebenverwandt",
+            Transform::Slide => "slide",
+            Transform::HexatonicPole => "hexatonic pole",
+        }
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.static_name())
+    }
+}
+
+impl Parsable for Transform {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let transform = match symbol.trim() {
+            "P" | "p" => Transform::Parallel,
+            "L" | "l" => Transform::LeadingTone,
+            "R" | "r" => Transform::Relative,
+            "N" | "n" => Transform::Nebenverwandt,
+            "S" | "s" => Transform::Slide,
+            "H" | "h" => Transform::HexatonicPole,
+            other => match other.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+                "parallel" => Transform::Parallel,
+                "leadingtone" | "leadingtoneexchange" | "leittonwechsel" => Transform::LeadingTone,
+                "relative" => Transform::Relative,
+                "nebenverwandt" => Transform::Nebenverwandt,
+                "slide" => Transform::Slide,
+                "hexatonic" | "hexatonicpole" => Transform::HexatonicPole,
+                _ => return Err(anyhow::Error::msg(format!("Unknown Neo-Riemannian transformation `{}`.", symbol))),
+            },
+        };
+
+        Ok(transform)
+    }
+}
+
+impl Transformable for Chord {
+    fn transformed(&self, transform: Transform) -> Res<Self> {
+        transform.basic().into_iter().try_fold(self.clone(), |chord, basic| basic_transform(&chord, basic))
+    }
+
+    fn transformed_by(&self, transforms: &[Transform]) -> Res<Self> {
+        transforms.iter().try_fold(self.clone(), |chord, transform| chord.transformed(*transform))
+    }
+
+    fn tonnetz_path(&self, target: &Self) -> Res<Vec<Transform>> {
+        let start = (pitch_class(self), triad_quality(self)?);
+        let goal = (pitch_class(target), triad_quality(target)?);
+
+        // Breadth-first search over the 24 triads, remembering how each was reached.
+        let mut parents = HashMap::from([(start, None)]);
+        let mut queue = VecDeque::from([self.clone()]);
+
+        while let Some(chord) = queue.pop_front() {
+            let node = (pitch_class(&chord), triad_quality(&chord)?);
+
+            if node == goal {
+                let mut path = vec![];
+                let mut current = node;
+
+                while let Some(Some((previous, transform))) = parents.get(&current) {
+                    path.push(*transform);
+                    current = *previous;
+                }
+
+                path.reverse();
+
+                return Ok(path);
+            }
+
+            for transform in BASIC_TRANSFORMS {
+                let next = basic_transform(&chord, transform)?;
+                let key = (pitch_class(&next), triad_quality(&next)?);
+
+                if let Entry::Vacant(entry) = parents.entry(key) {
+                    entry.insert(Some((node, transform)));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        unreachable!("The Tonnetz is connected.")
+    }
+}
+
+// Helpers.
+
+/// Returns whether the chord is a minor triad (or an error, if it is not a major or minor triad).
+fn triad_quality(chord: &Chord) -> Res<bool> {
+    let intervals = chord.relative_chord();
+
+    if intervals == [Interval::PerfectUnison, Interval::MajorThird, Interval::PerfectFifth] {
+        Ok(false)
+    } else if intervals == [Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFifth] {
+        Ok(true)
+    } else {
+        Err(anyhow::Error::msg(format!("Neo-Riemannian transformations require a major or minor triad (got `{}`).", chord.name())))
+    }
+}
+
+/// Returns the pitch class of the root of the chord.
+fn pitch_class(chord: &Chord) -> i32 {
+    chord.root().semitones().rem_euclid(12)
+}
+
+/// Applies a basic transformation (P, L, or R) to a major or minor triad, keeping the root in the same octave (and
+/// avoiding double accidentals, which would otherwise accumulate along a path).
+fn basic_transform(chord: &Chord, transform: Transform) -> Res<Chord> {
+    let is_minor = triad_quality(chord)?;
+    let root = chord.root();
+
+    let (root, to_minor) = match (transform, is_minor) {
+        (Transform::Parallel, _) => (root, !is_minor),
+        (Transform::LeadingTone, false) => (root + Interval::MajorThird, true),
+        (Transform::LeadingTone, true) => (root + Interval::MinorSixth, false),
+        (Transform::Relative, false) => (root + Interval::MajorSixth, true),
+        (Transform::Relative, true) => (root + Interval::MinorThird, false),
+        _ => unreachable!("Only the basic transformations are applied directly."),
+    };
+
+    let result = Chord::new(root.respelled(Spelling::NoDoubleAccidentals)).with_octave(chord.root().octave());
+
+    Ok(if to_minor { result.minor() } else { result })
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::*;
+    use pretty_assertions::assert_eq;
+
+    fn transformed(symbol: &str, transforms: &str) -> String {
+        Chord::parse(symbol).unwrap().transformed_by(&Transform::parse_sequence(transforms).unwrap()).unwrap().name()
+    }
+
+    #[test]
+    fn test_transforms() {
+        assert_eq!(Chord::new(C).transformed(Transform::Parallel).unwrap(), Chord::new(C).minor());
+        assert_eq!(Chord::new(C).minor().transformed(Transform::Parallel).unwrap(), Chord::new(C));
+        assert_eq!(Chord::new(C).transformed(Transform::LeadingTone).unwrap(), Chord::new(E).minor());
+        assert_eq!(Chord::new(C).minor().transformed(Transform::LeadingTone).unwrap(), Chord::new(AFlat));
+        assert_eq!(Chord::new(C).transformed(Transform::Relative).unwrap(), Chord::new(A).minor());
+        assert_eq!(Chord::new(A).minor().transformed(Transform::Relative).unwrap(), Chord::new(C));
+
+        assert_eq!(transformed("C", "N"), "Fm");
+        assert_eq!(transformed("Fm", "N"), "C");
+        assert_eq!(transformed("C", "S"), "C♯m");
+        assert_eq!(transformed("C♯m", "S"), "C");
+        assert_eq!(transformed("C", "H"), "G♯m");
+        assert_eq!(transformed("Ebm", "H"), "G");
+
+        assert_eq!(transformed("C", "PLR"), "Fm");
+
+        // The hexatonic and octatonic cycles return to the start (enharmonically).
+        let c = Chord::new(C);
+        assert_eq!(pitch_class(&c.transformed_by(&Transform::parse_sequence("LPLPLP").unwrap()).unwrap()), 0);
+        assert_eq!(pitch_class(&c.transformed_by(&Transform::parse_sequence("RPRPRPRP").unwrap()).unwrap()), 0);
+        assert_eq!(pitch_class(&c.transformed_by(&Transform::parse_sequence("RL".repeat(12).as_str()).unwrap()).unwrap()), 0);
+
+        // The root stays in the same octave.
+        assert_eq!(Chord::new(C).transformed(Transform::Relative).unwrap().root(), A);
+        assert_eq!(Chord::new(A).minor().transformed(Transform::Relative).unwrap().root(), C);
+
+        assert!(Chord::new(C).seven().transformed(Transform::Parallel).is_err());
+        assert!(Chord::new(C).diminished().transformed(Transform::Relative).is_err());
+    }
+
+    #[test]
+    fn test_tonnetz_path() {
+        let path = |from: &str, to: &str| {
+            let from = Chord::parse(from).unwrap();
+            let to = Chord::parse(to).unwrap();
+            let path = from.tonnetz_path(&to).unwrap();
+
+            assert_eq!(from.transformed_by(&path).unwrap().root().semitones().rem_euclid(12), to.root().semitones().rem_euclid(12));
+
+            path.iter().map(|t| t.static_name()).collect::<String>()
+        };
+
+        assert_eq!(path("C", "C"), "");
+        assert_eq!(path("C", "Am"), "R");
+        assert_eq!(path("C", "Fm"), "PLR");
+        assert_eq!(path("C", "Ab"), "PL");
+        assert_eq!(path("C", "G#m"), "PLP");
+        assert_eq!(path("C", "Db").len(), 4);
+        assert_eq!(path("C", "F#"), "PRPR");
+
+        assert!(Chord::new(C).tonnetz_path(&Chord::new(D).seven()).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Transform::parse("P").unwrap(), Transform::Parallel);
+        assert_eq!(Transform::parse("leading-tone").unwrap(), Transform::LeadingTone);
+        assert_eq!(Transform::parse("hexatonic pole").unwrap(), Transform::HexatonicPole);
+        assert_eq!(
+            Transform::parse_sequence("PLR, N s").unwrap(),
+            vec![Transform::Parallel, Transform::LeadingTone, Transform::Relative, Transform::Nebenverwandt, Transform::Slide]
+        );
+        assert_eq!(Transform::parse_sequence("slide").unwrap(), vec![Transform::Slide]);
+
+        assert!(Transform::parse("X").is_err());
+        assert!(Transform::parse_sequence("PXL").is_err());
+    }
+}
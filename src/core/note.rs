@@ -7,18 +7,21 @@
 
 use std::{
     cmp::Ordering,
+    collections::HashMap,
     fmt::{self, Display, Formatter},
-    ops::{Add, AddAssign, Sub},
+    ops::{Add, AddAssign, Sub, SubAssign},
 };
 
 use crate::core::{
-    base::{HasName, HasStaticName, Parsable, Res},
+    base::{HasDescription, HasName, HasStaticName, Parsable, Res},
     chord::Chord,
     interval::{HasEnharmonicDistance, Interval, PRIMARY_HARMONIC_SERIES},
-    named_pitch::{HasNamedPitch, NamedPitch},
+    key::Key,
+    named_pitch::{HasAccidentals, HasNamedPitch, NamedPitch, ALL_PITCHES as ALL_NAMED_PITCHES},
     octave::{HasOctave, Octave, ALL_OCTAVES},
     parser::{note_str_to_note, octave_str_to_octave, ChordParser, Rule},
-    pitch::{HasBaseFrequency, HasFrequency, HasPitch, Pitch, ALL_PITCHES},
+    pitch::{HasFrequency, HasPitch, Pitch, ALL_PITCHES},
+    tuning::Tuning,
 };
 use once_cell::sync::Lazy;
 use paste::paste;
@@ -36,6 +39,7 @@ macro_rules! define_note {
             pub const [<$name$octave_num>]: Note = Note {
                 named_pitch: $named_pitch,
                 octave: $octave,
+                quarter_tone: QuarterTone::Natural,
             };
         }
     };
@@ -116,6 +120,14 @@ pub trait NoteRecreator {
     fn with_named_pitch(self, named_pitch: NamedPitch) -> Self;
     /// Recreates this [`Note`] with the given [`Octave`].
     fn with_octave(self, octave: Octave) -> Self;
+    /// Recreates this [`Note`] with the given [`QuarterTone`] inflection.
+    fn with_quarter_tone(self, quarter_tone: QuarterTone) -> Self;
+}
+
+/// A trait for types that have a quarter-tone inflection.
+pub trait HasQuarterTone {
+    /// Returns the quarter-tone inflection of the type (usually a [`Note`]).
+    fn quarter_tone(&self) -> QuarterTone;
 }
 
 /// A trait which allows for obtaining the primary harmonic series of the note.
@@ -145,6 +157,17 @@ pub trait HasNoteId {
         Self: Sized;
 }
 
+/// A trait for types that can be enharmonically respelled (without changing how they sound).
+pub trait Respellable: Sized {
+    /// Returns the type respelled according to the given [`Spelling`] policy.
+    fn respelled(self, spelling: Spelling) -> Self;
+
+    /// Returns the type respelled with its simplest enharmonic spelling (i.e., the fewest accidentals).
+    fn simplified(self) -> Self {
+        self.respelled(Spelling::Simplest)
+    }
+}
+
 /// A trait which allows for converting a note to the same octave, but using universal [`Pitch`]es.
 ///
 /// Essentially, this would convert an F#4 into a Gb4, since [`Pitch`]es prefer the flats.
@@ -153,18 +176,52 @@ pub trait ToUniversal {
     fn to_universal(self) -> Self;
 }
 
+// Enum.
+
+/// An enharmonic spelling policy (see [`Respellable`]).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub enum Spelling {
+    /// The theoretically correct spelling (i.e., notes are left as they are).
+    #[default]
+    Theoretical,
+    /// Avoids double accidentals (e.g., `E𝄫` becomes `D`), but otherwise leaves notes as they are.
+    NoDoubleAccidentals,
+    /// Uses the fewest accidentals (e.g., `F♭` becomes `E`, and `E𝄫` becomes `D`).
+    Simplest,
+    /// Spells notes as they would appear in the given [`Key`] (see [`Key::spell`]).
+    Key(Key),
+    /// Uses naturals and sharps only (e.g., `G♭` becomes `F♯`).
+    Sharps,
+    /// Uses naturals and flats only (e.g., `F♯` becomes `G♭`).
+    Flats,
+}
+
+/// A quarter-tone inflection of a [`Note`] (e.g., the half sharp of `C𝄲`), for microtonal music.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default, Ord, PartialOrd)]
+pub enum QuarterTone {
+    /// Lowered by a quarter tone (half of a flat).
+    HalfFlat,
+    /// Not inflected.
+    #[default]
+    Natural,
+    /// Raised by a quarter tone (half of a sharp).
+    HalfSharp,
+}
+
 // Struct.
 
 /// A note type.
 ///
-/// This is a named pitch with an octave.  This type allows for correctly attributing octave changes
-/// across an interval from one [`Note`] to another.
+/// This is a named pitch with an octave (and, optionally, a quarter-tone inflection).  This type allows for correctly
+/// attributing octave changes across an interval from one [`Note`] to another.
 #[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
 pub struct Note {
     /// The octave of the note.
     octave: Octave,
     /// The named pitch of the note.
     named_pitch: NamedPitch,
+    /// The quarter-tone inflection of the note.
+    quarter_tone: QuarterTone,
 }
 
 impl Display for Note {
@@ -178,7 +235,51 @@ impl Display for Note {
 impl Note {
     /// Creates a new [`Note`] from the given [`NamedPitch`] and [`Octave`].
     pub fn new(pitch: NamedPitch, octave: Octave) -> Self {
-        Self { named_pitch: pitch, octave }
+        Self {
+            named_pitch: pitch,
+            octave,
+            quarter_tone: QuarterTone::Natural,
+        }
+    }
+
+    /// Returns the enharmonic equivalent of this [`Note`] that is spelled with the given [`NamedPitch`].
+    ///
+    /// The octave is adjusted as needed (e.g., C4 is B♯3), and `None` is returned if the pitches differ.
+    pub fn enharmonic(self, named_pitch: NamedPitch) -> Option<Self> {
+        if named_pitch.pitch() != self.pitch() {
+            return None;
+        }
+
+        [0, -1, 1]
+            .into_iter()
+            .filter_map(|delta| Octave::try_from(u8::try_from(self.octave as i8 + delta).ok()?).ok())
+            .map(|octave| Note::new(named_pitch, octave).with_quarter_tone(self.quarter_tone))
+            .find(|n| n.semitones() == self.semitones())
+    }
+
+    /// Returns the number of semitones from C0 up to this [`Note`] (e.g., C4 is 48).
+    pub(crate) fn semitones(&self) -> i32 {
+        // Some spellings cross the octave boundary (e.g., B♯4 is C5).
+        let octave = match self.named_pitch {
+            NamedPitch::ATripleSharp | NamedPitch::BTripleSharp | NamedPitch::BDoubleSharp | NamedPitch::BSharp => self.octave as i32 + 1,
+            NamedPitch::DTripleFlat | NamedPitch::CTripleFlat | NamedPitch::CDoubleFlat | NamedPitch::CFlat => self.octave as i32 - 1,
+            _ => self.octave as i32,
+        };
+
+        12 * octave + self.pitch() as i32
+    }
+
+    /// Returns the nearest (universally spelled) [`Note`] to the given frequency in the default [`Tuning`], and how far
+    /// the frequency is from that note (in cents, where positive means sharp).
+    ///
+    /// Use [`Tuning::nearest_note`] to honor another reference pitch or temperament.
+    pub fn from_frequency(frequency: f32) -> Res<(Self, f32)> {
+        Tuning::default().nearest_note(frequency)
+    }
+
+    /// Returns the (universally spelled) [`Note`] that is the given number of semitones above C0, if it exists.
+    pub(crate) fn from_semitones(semitones: i32) -> Option<Self> {
+        usize::try_from(semitones).ok().and_then(|k| ALL_PITCH_NOTES.get(k)).copied()
     }
 }
 
@@ -190,17 +291,33 @@ impl Note {
     #[no_coverage]
     #[cfg(feature = "analyze_mic")]
     pub async fn try_from_mic(length_in_seconds: u8) -> Res<Vec<Note>> {
+        Self::try_from_mic_in(length_in_seconds, &Tuning::default()).await
+    }
+
+    /// Attempts to use the default microphone to listen to audio for the specified time
+    /// to identify the notes in the recorded audio, as tuned in the given [`Tuning`].
+    ///
+    /// Currently, this does not work with WASM.
+    #[no_coverage]
+    #[cfg(feature = "analyze_mic")]
+    pub async fn try_from_mic_in(length_in_seconds: u8, tuning: &Tuning) -> Res<Vec<Note>> {
         use crate::analyze::mic::get_notes_from_microphone;
 
-        get_notes_from_microphone(length_in_seconds).await
+        get_notes_from_microphone(length_in_seconds, tuning).await
     }
 
     /// Attempts to use the provided to identify the notes in the audio data.
     #[cfg(feature = "analyze_base")]
     pub fn try_from_audio(data: &[f32], length_in_seconds: u8) -> Res<Vec<Note>> {
+        Self::try_from_audio_in(data, length_in_seconds, &Tuning::default())
+    }
+
+    /// Attempts to use the provided to identify the notes in the audio data, as tuned in the given [`Tuning`].
+    #[cfg(feature = "analyze_base")]
+    pub fn try_from_audio_in(data: &[f32], length_in_seconds: u8, tuning: &Tuning) -> Res<Vec<Note>> {
         use crate::analyze::base::get_notes_from_audio_data;
 
-        get_notes_from_audio_data(data, length_in_seconds)
+        get_notes_from_audio_data(data, length_in_seconds, tuning)
     }
 
     /// Attempts to use the default microphone to listen to audio for the specified time
@@ -244,34 +361,50 @@ impl HasOctave for Note {
     }
 }
 
+impl HasQuarterTone for Note {
+    fn quarter_tone(&self) -> QuarterTone {
+        self.quarter_tone
+    }
+}
+
 impl HasStaticName for Note {
     fn static_name(&self) -> &'static str {
-        self.named_pitch.static_name()
+        match self.quarter_tone {
+            QuarterTone::Natural => self.named_pitch.static_name(),
+            quarter_tone => QUARTER_TONE_NAMES[&(self.named_pitch, quarter_tone)].as_str(),
+        }
     }
 }
 
 impl HasName for Note {
     fn name(&self) -> String {
-        format!("{}{}", self.named_pitch.static_name(), self.octave.static_name())
+        format!("{}{}", self.static_name(), self.octave.static_name())
     }
 }
 
-impl HasFrequency for Note {
-    fn frequency(&self) -> f32 {
-        let mut octave = self.octave();
-        let base_frequency = self.pitch().base_frequency();
+impl HasStaticName for QuarterTone {
+    fn static_name(&self) -> &'static str {
+        match self {
+            QuarterTone::HalfFlat => "𝄳",
+            QuarterTone::Natural => "",
+            QuarterTone::HalfSharp => "𝄲",
+        }
+    }
+}
 
-        match self.named_pitch {
-            NamedPitch::ATripleSharp | NamedPitch::BTripleSharp | NamedPitch::BDoubleSharp | NamedPitch::BSharp => {
-                octave += 1;
-            }
-            NamedPitch::DTripleFlat | NamedPitch::CTripleFlat | NamedPitch::CDoubleFlat | NamedPitch::CFlat => {
-                octave -= 1;
-            }
-            _ => {}
+impl HasDescription for QuarterTone {
+    fn description(&self) -> &'static str {
+        match self {
+            QuarterTone::HalfFlat => "half flat",
+            QuarterTone::Natural => "natural",
+            QuarterTone::HalfSharp => "half sharp",
         }
+    }
+}
 
-        base_frequency * 2.0_f32.powf(octave as u8 as f32)
+impl HasFrequency for Note {
+    fn frequency_in(&self, tuning: &Tuning) -> f32 {
+        tuning.frequency(self)
     }
 }
 
@@ -281,6 +414,23 @@ impl IntoChord for Note {
     }
 }
 
+impl Parsable for Spelling {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        // Allow `theoretical`, `no-doubles`, `simplest`, `sharps`, `flats`, or the name of a key (e.g., `Eb`, `F#m`, `D dorian`).
+        match symbol.trim().to_lowercase().as_str() {
+            "theoretical" => Ok(Spelling::Theoretical),
+            "no-doubles" => Ok(Spelling::NoDoubleAccidentals),
+            "simplest" => Ok(Spelling::Simplest),
+            "sharps" => Ok(Spelling::Sharps),
+            "flats" => Ok(Spelling::Flats),
+            _ => Ok(Spelling::Key(Key::parse(symbol).map_err(|_| anyhow::Error::msg(format!("Unknown spelling `{}`.", symbol)))?)),
+        }
+    }
+}
+
 impl Parsable for Note {
     fn parse(input: &str) -> Res<Self>
     where
@@ -312,11 +462,15 @@ impl Parsable for Note {
 
 impl NoteRecreator for Note {
     fn with_named_pitch(self, named_pitch: NamedPitch) -> Self {
-        Self::new(named_pitch, self.octave)
+        Self { named_pitch, ..self }
     }
 
     fn with_octave(self, octave: Octave) -> Self {
-        Self::new(self.named_pitch, octave)
+        Self { octave, ..self }
+    }
+
+    fn with_quarter_tone(self, quarter_tone: QuarterTone) -> Self {
+        Self { quarter_tone, ..self }
     }
 }
 
@@ -378,6 +532,28 @@ impl HasNoteId for Note {
     }
 }
 
+impl Respellable for Note {
+    fn respelled(self, spelling: Spelling) -> Self {
+        let accidentals = self.named_pitch.accidentals();
+
+        let candidates = ALL_NAMED_PITCHES.iter().copied().filter(|p| p.pitch() == self.pitch()).filter(|p| match spelling {
+            Spelling::Sharps => p.accidentals() >= 0,
+            Spelling::Flats => p.accidentals() <= 0,
+            _ => true,
+        });
+
+        let named_pitch = match spelling {
+            Spelling::Theoretical => return self,
+            Spelling::NoDoubleAccidentals if accidentals.abs() < 2 => return self,
+            Spelling::Key(key) => return key.spell(self),
+            // Prefer the fewest accidentals, and then the same "direction" of accidentals as the current spelling.
+            _ => candidates.min_by_key(|p| (p.accidentals().abs(), p.accidentals().signum() != accidentals.signum())),
+        };
+
+        named_pitch.and_then(|p| self.enharmonic(p)).unwrap_or(self)
+    }
+}
+
 impl ToUniversal for Note {
     fn to_universal(self) -> Note {
         self.with_named_pitch(NamedPitch::from(self.pitch()))
@@ -413,19 +589,11 @@ impl Add<Interval> for Note {
         // Basically, if we were already "on" the weird one (this is a perfect unision, or perfect octave, etc.), then we don't
         // do anything special.  Otherwise, if we landed on on of these edge cases, then we need to adjust the octave.
         let mut special_octave = 0;
-        
+
         if self.named_pitch != new_pitch {
-            if new_pitch == NamedPitch::CFlat
-                || new_pitch == NamedPitch::CDoubleFlat
-                || new_pitch == NamedPitch::CTripleFlat
-                || new_pitch == NamedPitch::DTripleFlat
-            {
+            if new_pitch == NamedPitch::CFlat || new_pitch == NamedPitch::CDoubleFlat || new_pitch == NamedPitch::CTripleFlat || new_pitch == NamedPitch::DTripleFlat {
                 special_octave = 1;
-            } else if new_pitch == NamedPitch::BSharp
-                || new_pitch == NamedPitch::BDoubleSharp
-                || new_pitch == NamedPitch::BTripleSharp
-                || new_pitch == NamedPitch::ATripleSharp
-            {
+            } else if new_pitch == NamedPitch::BSharp || new_pitch == NamedPitch::BDoubleSharp || new_pitch == NamedPitch::BTripleSharp || new_pitch == NamedPitch::ATripleSharp {
                 special_octave = -1
             }
         }
@@ -436,6 +604,7 @@ impl Add<Interval> for Note {
         Note {
             octave: self.octave + wrapping_octave + special_octave + interval_octave,
             named_pitch: new_pitch,
+            quarter_tone: self.quarter_tone,
         }
     }
 }
@@ -453,19 +622,11 @@ impl Sub<Interval> for Note {
         // Basically, if we were already "on" the weird one (this is a perfect unision, or perfect octave, etc.), then we don't
         // do anything special.  Otherwise, if we landed on on of these edge cases, then we need to adjust the octave.
         let mut special_octave = 0;
-        
+
         if self.named_pitch != new_pitch {
-            if new_pitch == NamedPitch::CFlat
-                || new_pitch == NamedPitch::CDoubleFlat
-                || new_pitch == NamedPitch::CTripleFlat
-                || new_pitch == NamedPitch::DTripleFlat
-            {
+            if new_pitch == NamedPitch::CFlat || new_pitch == NamedPitch::CDoubleFlat || new_pitch == NamedPitch::CTripleFlat || new_pitch == NamedPitch::DTripleFlat {
                 special_octave = -1;
-            } else if new_pitch == NamedPitch::BSharp
-                || new_pitch == NamedPitch::BDoubleSharp
-                || new_pitch == NamedPitch::BTripleSharp
-                || new_pitch == NamedPitch::ATripleSharp
-            {
+            } else if new_pitch == NamedPitch::BSharp || new_pitch == NamedPitch::BDoubleSharp || new_pitch == NamedPitch::BTripleSharp || new_pitch == NamedPitch::ATripleSharp {
                 special_octave = 1
             }
         }
@@ -476,6 +637,7 @@ impl Sub<Interval> for Note {
         Note {
             octave: self.octave - wrapping_octave - special_octave - interval_octave,
             named_pitch: new_pitch,
+            quarter_tone: self.quarter_tone,
         }
     }
 }
@@ -486,6 +648,12 @@ impl AddAssign<Interval> for Note {
     }
 }
 
+impl SubAssign<Interval> for Note {
+    fn sub_assign(&mut self, rhs: Interval) {
+        *self = *self - rhs;
+    }
+}
+
 impl PartialOrd for Note {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.frequency().partial_cmp(&other.frequency())
@@ -630,6 +798,7 @@ pub static ALL_PITCH_NOTES: Lazy<[Note; 192]> = Lazy::new(|| {
             all_notes.push(Note {
                 octave: *octave,
                 named_pitch: pitch.into(),
+                quarter_tone: QuarterTone::Natural,
             });
         }
     }
@@ -637,15 +806,17 @@ pub static ALL_PITCH_NOTES: Lazy<[Note; 192]> = Lazy::new(|| {
     all_notes.try_into().unwrap()
 });
 
-/// All the notes in all octaves with their frequency.
-pub static ALL_PITCH_NOTES_WITH_FREQUENCY: Lazy<[(Note, f32); 192]> = Lazy::new(|| {
-    let mut all_notes = Vec::with_capacity(132);
+/// The names of the quarter-tone inflected named pitches (e.g., `C𝄲` or `E♭𝄳`).
+static QUARTER_TONE_NAMES: Lazy<HashMap<(NamedPitch, QuarterTone), String>> = Lazy::new(|| {
+    let mut names = HashMap::new();
 
-    for note in ALL_PITCH_NOTES.iter() {
-        all_notes.push((*note, note.frequency()));
+    for named_pitch in ALL_NAMED_PITCHES.iter() {
+        for quarter_tone in [QuarterTone::HalfFlat, QuarterTone::HalfSharp] {
+            names.insert((*named_pitch, quarter_tone), format!("{}{}", named_pitch.static_name(), quarter_tone.static_name()));
+        }
     }
 
-    all_notes.try_into().unwrap()
+    names
 });
 
 // Tests.
@@ -777,6 +948,35 @@ mod tests {
         assert_eq!(BDoubleSharpFive.with_named_pitch(NamedPitch::A).frequency(), AFive.frequency());
     }
 
+    #[test]
+    fn test_quarter_tones() {
+        let c_half_sharp = Note::parse("Cqs4").unwrap();
+
+        assert_eq!(c_half_sharp, C.with_quarter_tone(QuarterTone::HalfSharp));
+        assert_eq!(Note::parse("C𝄲").unwrap(), c_half_sharp);
+        assert_eq!(c_half_sharp.name(), "C𝄲4");
+        assert_eq!(Note::parse("E♭𝄳3").unwrap().name(), "E♭𝄳3");
+        assert_eq!(Note::parse("F#qs").unwrap().static_name(), "F♯𝄲");
+        assert!((c_half_sharp.frequency() / C.frequency() - 2f32.powf(1.0 / 24.0)).abs() < 0.0001);
+
+        // Intervals and octaves keep the inflection.
+        assert_eq!((c_half_sharp + Interval::MajorThird).name(), "E𝄲4");
+        assert_eq!(c_half_sharp.with_octave(Octave::Five).name(), "C𝄲5");
+        assert_eq!(c_half_sharp.enharmonic(NamedPitch::BSharp).unwrap().name(), "B♯𝄲3");
+    }
+
+    #[test]
+    fn test_from_frequency() {
+        assert_eq!(Note::from_frequency(440.0).unwrap(), (A, 0.0));
+        assert_eq!(Note::from_frequency(C.frequency()).unwrap(), (C, 0.0));
+        assert_eq!(Note::from_frequency(FSharpTwo.frequency()).unwrap().0, GFlatTwo);
+
+        let (note, cents) = Note::from_frequency(435.0).unwrap();
+
+        assert_eq!(note, A);
+        assert!((cents + 19.79).abs() < 0.01);
+    }
+
     #[test]
     fn test_harmonics() {
         assert_eq!(
@@ -819,4 +1019,43 @@ mod tests {
     fn test_universal() {
         assert_eq!(FSharpFive.to_universal(), Note::parse("Gb5").unwrap());
     }
+
+    #[test]
+    fn test_enharmonics() {
+        assert_eq!(C.enharmonic(NamedPitch::BSharp), Some(BSharpThree));
+        assert_eq!(B.enharmonic(NamedPitch::CFlat), Some(CFlatFive));
+        assert_eq!(FSharp.enharmonic(NamedPitch::GFlat), Some(GFlat));
+        assert_eq!(C.enharmonic(NamedPitch::D), None);
+
+        assert_eq!(EDoubleSharp.simplified(), FSharp);
+        assert_eq!(ADoubleFlat.simplified(), G);
+        assert_eq!(FFlat.simplified(), E);
+        assert_eq!(BSharpThree.simplified(), C);
+        assert_eq!(GFlat.simplified(), GFlat);
+        assert_eq!(FSharp.simplified(), FSharp);
+        assert_eq!(CDoubleFlat.simplified(), BFlatThree);
+    }
+
+    #[test]
+    fn test_spelling() {
+        assert_eq!(EDoubleFlat.respelled(Spelling::Theoretical), EDoubleFlat);
+        assert_eq!(EDoubleFlat.respelled(Spelling::NoDoubleAccidentals), D);
+        assert_eq!(FFlat.respelled(Spelling::NoDoubleAccidentals), FFlat);
+        assert_eq!(FFlat.respelled(Spelling::Simplest), E);
+        assert_eq!(GFlat.respelled(Spelling::Sharps), FSharp);
+        assert_eq!(ESharp.respelled(Spelling::Sharps), F);
+        assert_eq!(FSharp.respelled(Spelling::Flats), GFlat);
+        assert_eq!(CFlatFive.respelled(Spelling::Flats), B);
+
+        let e_flat = Spelling::Key(Key::major(EFlat));
+
+        assert_eq!(DSharp.respelled(e_flat), EFlat);
+        assert_eq!(GSharp.respelled(e_flat), AFlat);
+        assert_eq!(FSharp.respelled(Spelling::Key(Key::major(D))), FSharp);
+
+        assert_eq!(Spelling::parse("no-doubles").unwrap(), Spelling::NoDoubleAccidentals);
+        assert_eq!(Spelling::parse("Sharps").unwrap(), Spelling::Sharps);
+        assert_eq!(Spelling::parse("F#m").unwrap(), Spelling::Key(Key::minor(FSharp)));
+        assert!(Spelling::parse("weird").is_err());
+    }
 }
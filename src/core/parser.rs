@@ -4,7 +4,7 @@ use pest_derive::Parser;
 
 use crate::core::{
     base::Res,
-    note::{self, Note},
+    note::{self, Note, NoteRecreator, QuarterTone},
     octave::Octave,
 };
 
@@ -15,11 +15,46 @@ use crate::core::{
 #[grammar = "../chord.pest"]
 pub struct ChordParser;
 
+/// A module for the parser for roman numeral symbols.
+///
+/// This lives in its own module, since each grammar generates its own `Rule` enum.
+pub mod roman {
+    use pest_derive::Parser;
+
+    /// A parser for roman numeral symbols and progressions.
+    ///
+    /// This is built from a PEG grammar defined in `roman.pest`.
+    #[derive(Parser)]
+    #[grammar = "../roman.pest"]
+    pub struct RomanNumeralParser;
+}
+
+/// A module for the parser for chord progressions.
+pub mod progression {
+    use pest_derive::Parser;
+
+    /// A parser for chord progressions.
+    ///
+    /// This is built from a PEG grammar defined in `progression.pest`.
+    #[derive(Parser)]
+    #[grammar = "../progression.pest"]
+    pub struct ProgressionParser;
+}
+
 // Helpers.
 
 /// Parses a [`Note`] [`str`] into a [`Note`].
 #[no_coverage]
 pub fn note_str_to_note(note_str: &str) -> Res<Note> {
+    // Peel off any quarter-tone inflection (e.g., `C#qs`, or `E𝄳`).
+    let (note_str, quarter_tone) = match note_str {
+        s if s.ends_with("qs") => (&s[..s.len() - 2], QuarterTone::HalfSharp),
+        s if s.ends_with("qf") => (&s[..s.len() - 2], QuarterTone::HalfFlat),
+        s if s.ends_with('𝄲') => (s.trim_end_matches('𝄲'), QuarterTone::HalfSharp),
+        s if s.ends_with('𝄳') => (s.trim_end_matches('𝄳'), QuarterTone::HalfFlat),
+        s => (s, QuarterTone::Natural),
+    };
+
     let chord = match note_str {
         "A" => note::A,
         "A#" => note::ASharp,
@@ -87,7 +122,7 @@ pub fn note_str_to_note(note_str: &str) -> Res<Note> {
         _ => return Err(crate::core::base::Err::msg("Please use fairly standard notes (e.g., don't use triple sharps / flats).")),
     };
 
-    Ok(chord)
+    Ok(chord.with_quarter_tone(quarter_tone))
 }
 
 /// Parses an [`Octave`] [`str`] into an [`Octave`].
@@ -4,7 +4,7 @@
 
 use once_cell::sync::Lazy;
 
-use super::helpers::mel;
+use super::{helpers::mel, named_pitch::NamedPitch, note::Note, octave::Octave, tuning::Tuning};
 
 /// A trait for types that have a pitch property.
 pub trait HasPitch {
@@ -20,8 +20,13 @@ pub trait HasBaseFrequency {
 
 /// A trait for types that have a frequency property.
 pub trait HasFrequency {
-    /// Returns the frequency of the type (usually a [`Note`]).
-    fn frequency(&self) -> f32;
+    /// Returns the frequency of the type (usually a [`Note`]) in the given [`Tuning`].
+    fn frequency_in(&self, tuning: &Tuning) -> f32;
+
+    /// Returns the frequency of the type (usually a [`Note`]) in the default [`Tuning`] (twelve-tone equal temperament at A4 = 440 Hz).
+    fn frequency(&self) -> f32 {
+        self.frequency_in(&Tuning::default())
+    }
 }
 
 /// A trait for types that have a mel property.
@@ -36,7 +41,8 @@ pub trait HasMel: HasFrequency {
 
 /// An enum representing the pitch of a note.
 ///
-/// The frequencies of the pitches are based on the [A4 frequency](https://en.wikipedia.org/wiki/A4_(pitch_standard)).
+/// The frequencies of the pitches are based on the default [`Tuning`] (twelve-tone equal temperament at
+/// the [A4 frequency](https://en.wikipedia.org/wiki/A4_(pitch_standard)) of 440 Hz).
 /// There is no enharmonic representation here, so all of the sharps are represented.
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Ord, PartialOrd)]
 #[repr(u8)]
@@ -70,22 +76,8 @@ pub enum Pitch {
 // Pitch impls.
 
 impl HasBaseFrequency for Pitch {
-    #[no_coverage]
     fn base_frequency(&self) -> f32 {
-        match self {
-            Pitch::C => 16.35,
-            Pitch::DFlat => 17.32,
-            Pitch::D => 18.35,
-            Pitch::EFlat => 19.45,
-            Pitch::E => 20.60,
-            Pitch::F => 21.83,
-            Pitch::GFlat => 23.12,
-            Pitch::G => 24.50,
-            Pitch::AFlat => 25.96,
-            Pitch::A => 27.50,
-            Pitch::BFlat => 29.14,
-            Pitch::B => 30.87,
-        }
+        Tuning::default().frequency(&Note::new(NamedPitch::from(*self), Octave::Zero))
     }
 }
 
@@ -147,6 +139,6 @@ mod tests {
     #[test]
     fn test_properties() {
         assert_eq!(Pitch::G.pitch(), Pitch::G);
-        assert_eq!(Pitch::G.base_frequency(), 24.50);
+        assert!((Pitch::G.base_frequency() - 24.50).abs() < 0.01);
     }
 }
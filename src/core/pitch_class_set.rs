@@ -0,0 +1,531 @@
+//! A module for pitch-class set theory.
+//!
+//! A [`PitchClassSet`] is an unordered set of pitch classes (where `0` is `C`, `1` is `C♯`, and so on), which supports the
+//! usual post-tonal operations: normal form, prime form, Forte numbers, interval-class vectors, transposition and
+//! inversion (Tn and TnI), and Z-relations.
+
+use std::fmt::{self, Display, Formatter};
+
+use once_cell::sync::Lazy;
+
+use crate::core::{
+    base::{HasName, Parsable, Res},
+    chord::{Chord, HasChord},
+    note::Note,
+    pitch::{HasPitch, Pitch},
+};
+
+// Traits.
+
+/// A trait for types that have a [`PitchClassSet`].
+pub trait HasPitchClassSet {
+    /// Returns the pitch-class set of the type (usually a [`Chord`]).
+    fn pitch_class_set(&self) -> PitchClassSet;
+}
+
+// Statics.
+
+/// The prime forms of the set classes with six or fewer pitch classes, by Forte number.
+///
+/// The prime forms are computed with Rahn's algorithm (which differs from Forte's for a handful of set classes, such
+/// as `5-20`), and the set classes with more than six pitch classes are the complements of these.
+static FORTE_PRIME_FORMS: [(&str, &str); 137] = [
+    ("0-1", ""),
+    ("1-1", "0"),
+    ("2-1", "01"),
+    ("2-2", "02"),
+    ("2-3", "03"),
+    ("2-4", "04"),
+    ("2-5", "05"),
+    ("2-6", "06"),
+    ("3-1", "012"),
+    ("3-2", "013"),
+    ("3-3", "014"),
+    ("3-4", "015"),
+    ("3-5", "016"),
+    ("3-6", "024"),
+    ("3-7", "025"),
+    ("3-8", "026"),
+    ("3-9", "027"),
+    ("3-10", "036"),
+    ("3-11", "037"),
+    ("3-12", "048"),
+    ("4-1", "0123"),
+    ("4-2", "0124"),
+    ("4-3", "0134"),
+    ("4-4", "0125"),
+    ("4-5", "0126"),
+    ("4-6", "0127"),
+    ("4-7", "0145"),
+    ("4-8", "0156"),
+    ("4-9", "0167"),
+    ("4-10", "0235"),
+    ("4-11", "0135"),
+    ("4-12", "0236"),
+    ("4-13", "0136"),
+    ("4-14", "0237"),
+    ("4-Z15", "0146"),
+    ("4-16", "0157"),
+    ("4-17", "0347"),
+    ("4-18", "0147"),
+    ("4-19", "0148"),
+    ("4-20", "0158"),
+    ("4-21", "0246"),
+    ("4-22", "0247"),
+    ("4-23", "0257"),
+    ("4-24", "0248"),
+    ("4-25", "0268"),
+    ("4-26", "0358"),
+    ("4-27", "0258"),
+    ("4-28", "0369"),
+    ("4-Z29", "0137"),
+    ("5-1", "01234"),
+    ("5-2", "01235"),
+    ("5-3", "01245"),
+    ("5-4", "01236"),
+    ("5-5", "01237"),
+    ("5-6", "01256"),
+    ("5-7", "01267"),
+    ("5-8", "02346"),
+    ("5-9", "01246"),
+    ("5-10", "01346"),
+    ("5-11", "02347"),
+    ("5-Z12", "01356"),
+    ("5-13", "01248"),
+    ("5-14", "01257"),
+    ("5-15", "01268"),
+    ("5-16", "01347"),
+    ("5-Z17", "01348"),
+    ("5-Z18", "01457"),
+    ("5-19", "01367"),
+    ("5-20", "01568"),
+    ("5-21", "01458"),
+    ("5-22", "01478"),
+    ("5-23", "02357"),
+    ("5-24", "01357"),
+    ("5-25", "02358"),
+    ("5-26", "02458"),
+    ("5-27", "01358"),
+    ("5-28", "02368"),
+    ("5-29", "01368"),
+    ("5-30", "01468"),
+    ("5-31", "01369"),
+    ("5-32", "01469"),
+    ("5-33", "02468"),
+    ("5-34", "02469"),
+    ("5-35", "02479"),
+    ("5-Z36", "01247"),
+    ("5-Z37", "03458"),
+    ("5-Z38", "01258"),
+    ("6-1", "012345"),
+    ("6-2", "012346"),
+    ("6-Z3", "012356"),
+    ("6-Z4", "012456"),
+    ("6-5", "012367"),
+    ("6-Z6", "012567"),
+    ("6-7", "012678"),
+    ("6-8", "023457"),
+    ("6-9", "012357"),
+    ("6-Z10", "013457"),
+    ("6-Z11", "012457"),
+    ("6-Z12", "012467"),
+    ("6-Z13", "013467"),
+    ("6-14", "013458"),
+    ("6-15", "012458"),
+    ("6-16", "014568"),
+    ("6-Z17", "012478"),
+    ("6-18", "012578"),
+    ("6-Z19", "013478"),
+    ("6-20", "014589"),
+    ("6-21", "023468"),
+    ("6-22", "012468"),
+    ("6-Z23", "023568"),
+    ("6-Z24", "013468"),
+    ("6-Z25", "013568"),
+    ("6-Z26", "013578"),
+    ("6-27", "013469"),
+    ("6-Z28", "013569"),
+    ("6-Z29", "023679"),
+    ("6-30", "013679"),
+    ("6-31", "014579"),
+    ("6-32", "024579"),
+    ("6-33", "023579"),
+    ("6-34", "013579"),
+    ("6-35", "02468T"),
+    ("6-Z36", "012347"),
+    ("6-Z37", "012348"),
+    ("6-Z38", "012378"),
+    ("6-Z39", "023458"),
+    ("6-Z40", "012358"),
+    ("6-Z41", "012368"),
+    ("6-Z42", "012369"),
+    ("6-Z43", "012568"),
+    ("6-Z44", "012569"),
+    ("6-Z45", "023469"),
+    ("6-Z46", "012469"),
+    ("6-Z47", "012479"),
+    ("6-Z48", "012579"),
+    ("6-Z49", "013479"),
+    ("6-Z50", "014679"),
+];
+
+/// All of the set classes, by Forte number (including the complements of [`FORTE_PRIME_FORMS`]).
+static SET_CLASSES: Lazy<Vec<(String, PitchClassSet)>> = Lazy::new(|| {
+    let base = FORTE_PRIME_FORMS.iter().map(|(name, prime)| (name.to_string(), PitchClassSet::parse_compact(prime).unwrap()));
+
+    // Forte numbers the complement of each set class with the same ordinal (e.g., `7-35` is the complement of `5-35`).
+    let complements = FORTE_PRIME_FORMS.iter().filter(|(name, _)| !name.starts_with("6-")).map(|(name, prime)| {
+        let (cardinality, ordinal) = name.split_once('-').unwrap();
+        let cardinality = 12 - cardinality.parse::<u8>().unwrap();
+
+        (format!("{}-{}", cardinality, ordinal), PitchClassSet::parse_compact(prime).unwrap().complement().prime())
+    });
+
+    base.chain(complements).collect()
+});
+
+// Struct.
+
+/// A set of pitch classes (stored as a 12-bit mask, where bit `k` represents pitch class `k`).
+///
+/// Sets are displayed as `{0, 4, 7, 10}`, named compactly as `047T` (where `T` and `E` stand for 10 and 11), and parsed from
+/// either form (or from `[0,4,7,10]`, `0 4 7 10`, etc.).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Default)]
+pub struct PitchClassSet {
+    /// The mask of the pitch classes.
+    mask: u16,
+}
+
+// Impls.
+
+impl PitchClassSet {
+    /// Creates a new [`PitchClassSet`] from a 12-bit mask (where bit `k` represents pitch class `k`).
+    pub fn new(mask: u16) -> Self {
+        Self { mask: mask & 0xFFF }
+    }
+
+    /// Creates a new [`PitchClassSet`] from the given pitch classes (reduced modulo 12).
+    pub fn from_pitch_classes(pitch_classes: &[u8]) -> Self {
+        Self::new(pitch_classes.iter().fold(0, |mask, p| mask | 1 << (p % 12)))
+    }
+
+    /// Creates a new [`PitchClassSet`] from the given [`Pitch`]es.
+    pub fn from_pitches(pitches: &[Pitch]) -> Self {
+        Self::from_pitch_classes(&pitches.iter().map(|p| *p as u8).collect::<Vec<_>>())
+    }
+
+    /// Creates a new [`PitchClassSet`] from the pitches of the given [`Note`]s.
+    pub fn from_notes(notes: &[Note]) -> Self {
+        Self::from_pitches(&notes.iter().map(|n| n.pitch()).collect::<Vec<_>>())
+    }
+
+    /// Returns the mask of the pitch classes.
+    pub fn mask(&self) -> u16 {
+        self.mask
+    }
+
+    /// Returns the pitch classes, in ascending order.
+    pub fn pitch_classes(&self) -> Vec<u8> {
+        (0..12).filter(|k| self.contains(*k)).collect()
+    }
+
+    /// Returns the [`Pitch`]es, in ascending order.
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.pitch_classes().into_iter().map(|k| Pitch::try_from(k).unwrap()).collect()
+    }
+
+    /// Returns the number of pitch classes (i.e., the cardinality).
+    pub fn len(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    /// Returns whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.mask == 0
+    }
+
+    /// Returns whether the set contains the given pitch class.
+    pub fn contains(&self, pitch_class: u8) -> bool {
+        pitch_class < 12 && self.mask & (1 << pitch_class) != 0
+    }
+
+    /// Returns the complement of the set (i.e., the pitch classes that are not in the set).
+    pub fn complement(&self) -> Self {
+        Self::new(!self.mask)
+    }
+
+    /// Returns the set transposed by `n` semitones (Tn).
+    pub fn transposed(&self, n: u8) -> Self {
+        let n = n % 12;
+
+        Self::new(self.mask << n | self.mask >> (12 - n))
+    }
+
+    /// Returns the set inverted around `0`, and then transposed by `n` semitones (TnI).
+    pub fn inverted(&self, n: u8) -> Self {
+        Self::from_pitch_classes(&self.pitch_classes().into_iter().map(|k| (n % 12 + 12 - k) % 12).collect::<Vec<_>>())
+    }
+
+    /// Returns the normal form of the set (i.e., the most compact ascending ordering).
+    ///
+    /// Ties are broken with Rahn's algorithm (i.e., by the interval from the first pitch class to the second to last, then to
+    /// the third to last, and so on), and then by the lowest first pitch class.
+    pub fn normal_form(&self) -> Vec<u8> {
+        let pitch_classes = self.pitch_classes();
+
+        (0..pitch_classes.len())
+            .map(|k| [&pitch_classes[k..], &pitch_classes[..k]].concat())
+            .min_by_key(|rotation| (packing(rotation), rotation[0]))
+            .unwrap_or_default()
+    }
+
+    /// Returns the prime form of the set (i.e., the most compact of the normal forms of the set and its inversion, transposed to `0`).
+    pub fn prime_form(&self) -> Vec<u8> {
+        let zeroed = |set: PitchClassSet| {
+            let normal = set.normal_form();
+
+            normal.iter().map(|k| (k + 12 - normal[0]) % 12).collect::<Vec<_>>()
+        };
+
+        [zeroed(*self), zeroed(self.inverted(0))].into_iter().min_by_key(|p| packing(p)).unwrap_or_default()
+    }
+
+    /// Returns the set class of the set, as the set of its prime form.
+    pub fn prime(&self) -> Self {
+        Self::from_pitch_classes(&self.prime_form())
+    }
+
+    /// Returns the Forte number of the set class (e.g., `3-11` for a major or minor triad, or `4-Z15`).
+    pub fn forte_number(&self) -> &'static str {
+        let prime = self.prime();
+
+        SET_CLASSES.iter().find(|(_, p)| *p == prime).map(|(name, _)| name.as_str()).unwrap()
+    }
+
+    /// Returns the interval-class vector of the set (i.e., the number of each interval class, from 1 to 6).
+    pub fn interval_vector(&self) -> [u8; 6] {
+        let pitch_classes = self.pitch_classes();
+        let mut result = [0; 6];
+
+        for (k, a) in pitch_classes.iter().enumerate() {
+            for b in &pitch_classes[k + 1..] {
+                let interval = b - a;
+
+                result[interval.min(12 - interval) as usize - 1] += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Returns whether the sets are related by transposition or inversion (i.e., they are in the same set class).
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.prime() == other.prime()
+    }
+
+    /// Returns whether the sets are Z-related (i.e., they share an interval-class vector, but not a set class).
+    pub fn is_z_related(&self, other: &Self) -> bool {
+        self.interval_vector() == other.interval_vector() && !self.is_equivalent(other)
+    }
+
+    /// Returns the prime form of the Z-correspondent of the set class, if there is one (e.g., `4-Z29` for `4-Z15`).
+    pub fn z_correspondent(&self) -> Option<Self> {
+        SET_CLASSES.iter().map(|(_, p)| *p).find(|p| p.len() == self.len() && self.is_z_related(p))
+    }
+
+    /// Parses a compact string of pitch classes (e.g., `014T`, where `T` and `E` stand for 10 and 11).
+    fn parse_compact(symbol: &str) -> Res<Self> {
+        symbol.chars().map(parse_pitch_class).collect::<Res<Vec<_>>>().map(|p| Self::from_pitch_classes(&p))
+    }
+}
+
+impl HasName for PitchClassSet {
+    fn name(&self) -> String {
+        compact(&self.pitch_classes())
+    }
+}
+
+impl Display for PitchClassSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", self.pitch_classes().iter().map(|k| k.to_string()).collect::<Vec<_>>().join(", "))
+    }
+}
+
+impl Parsable for PitchClassSet {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let trimmed = symbol.trim().trim_start_matches(['{', '[', '(']).trim_end_matches(['}', ']', ')']);
+        let tokens = trimmed.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty()).collect::<Vec<_>>();
+
+        // A single bare token is always compact (e.g., `05` is `{0, 5}`), and brackets or commas make a list (e.g., `[11]`).
+        let is_list = trimmed.len() != symbol.trim().len() || trimmed.contains(',');
+
+        match tokens.as_slice() {
+            [token] if !is_list => Self::parse_compact(token),
+            _ => tokens
+                .iter()
+                .map(|t| match t.parse::<u8>() {
+                    Ok(k) if k < 12 => Ok(k),
+                    _ => parse_pitch_class(t.chars().next().filter(|_| t.len() == 1).unwrap_or('?')),
+                })
+                .collect::<Res<Vec<_>>>()
+                .map(|p| Self::from_pitch_classes(&p)),
+        }
+    }
+}
+
+impl HasPitchClassSet for Chord {
+    fn pitch_class_set(&self) -> PitchClassSet {
+        PitchClassSet::from_notes(&self.chord())
+    }
+}
+
+// Helpers.
+
+/// Returns the intervals from the first pitch class to the others, from the last to the first (i.e., the ordering used
+/// to find the most compact form).
+fn packing(pitch_classes: &[u8]) -> Vec<u8> {
+    pitch_classes.iter().rev().map(|k| (k + 12 - pitch_classes[0]) % 12).collect()
+}
+
+/// Parses a single pitch class character (`0` to `9`, `T` for 10, and `E` for 11).
+fn parse_pitch_class(c: char) -> Res<u8> {
+    match c {
+        '0'..='9' => Ok(c as u8 - b'0'),
+        'T' | 't' => Ok(10),
+        'E' | 'e' => Ok(11),
+        _ => Err(anyhow::Error::msg(format!("Invalid pitch class `{}`.", c))),
+    }
+}
+
+/// Returns the compact representation of the pitch classes (e.g., `047T`).
+fn compact(pitch_classes: &[u8]) -> String {
+    pitch_classes
+        .iter()
+        .map(|k| match k {
+            10 => 'T',
+            11 => 'E',
+            k => (b'0' + k) as char,
+        })
+        .collect()
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{chord::Chordable, note::*};
+    use pretty_assertions::assert_eq;
+
+    fn set(symbol: &str) -> PitchClassSet {
+        PitchClassSet::parse(symbol).unwrap()
+    }
+
+    #[test]
+    fn test_basics() {
+        let set = set("{0, 4, 7, 10}");
+
+        assert_eq!(set.mask(), 0b0100_1001_0001);
+        assert_eq!(set.pitch_classes(), vec![0, 4, 7, 10]);
+        assert_eq!(set.pitches(), vec![Pitch::C, Pitch::E, Pitch::G, Pitch::BFlat]);
+        assert_eq!(set.len(), 4);
+        assert_eq!(set.complement().len(), 8);
+        assert_eq!(set.to_string(), "{0, 4, 7, 10}");
+        assert_eq!(set.name(), "047T");
+        assert_eq!(Chord::new(C).seven().pitch_class_set(), set);
+        assert_eq!(PitchClassSet::from_notes(&[C, E, G, BFlat, CFive]), set);
+    }
+
+    #[test]
+    fn test_operations() {
+        let c_major = set("047");
+
+        assert_eq!(c_major.transposed(2), set("269"));
+        assert_eq!(c_major.transposed(7), set("2 7 11"));
+        assert_eq!(c_major.inverted(0), set("058"));
+        assert_eq!(c_major.inverted(7), set("037"));
+        assert!(c_major.is_equivalent(&set("037")));
+        assert!(!c_major.is_equivalent(&set("036")));
+    }
+
+    #[test]
+    fn test_forms() {
+        assert_eq!(set("047").normal_form(), vec![0, 4, 7]);
+        assert_eq!(set("7 0 4").normal_form(), vec![0, 4, 7]);
+        assert_eq!(set("E 0 4").normal_form(), vec![11, 0, 4]);
+        assert_eq!(set("047T").normal_form(), vec![4, 7, 10, 0]);
+        assert_eq!(set("048").normal_form(), vec![0, 4, 8]);
+
+        assert_eq!(set("047").prime_form(), vec![0, 3, 7]);
+        assert_eq!(set("047T").prime_form(), vec![0, 2, 5, 8]);
+        assert_eq!(set("0 2 4 5 7 9 11").prime_form(), vec![0, 1, 3, 5, 6, 8, 10]);
+
+        // Rahn's algorithm (rather than Forte's).
+        assert_eq!(set("01378").prime_form(), vec![0, 1, 5, 6, 8]);
+    }
+
+    #[test]
+    fn test_forte_number() {
+        assert_eq!(set("037").forte_number(), "3-11");
+        assert_eq!(set("047T").forte_number(), "4-27");
+        assert_eq!(set("0146").forte_number(), "4-Z15");
+        assert_eq!(set("0 2 4 5 7 9 11").forte_number(), "7-35");
+        assert_eq!(set("0 1 3 4 6 7 9 10").forte_number(), "8-28");
+        assert_eq!(set("02468T").forte_number(), "6-35");
+        assert_eq!(PitchClassSet::default().forte_number(), "0-1");
+        assert_eq!(PitchClassSet::new(0xFFF).forte_number(), "12-1");
+
+        // Every set class has a single Forte number.
+        let mut names = (0..4096u16).map(|m| PitchClassSet::new(m).forte_number()).collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), 224);
+    }
+
+    #[test]
+    fn test_interval_vector() {
+        assert_eq!(set("037").interval_vector(), [0, 0, 1, 1, 1, 0]);
+        assert_eq!(set("0 2 4 5 7 9 11").interval_vector(), [2, 5, 4, 3, 6, 1]);
+        assert_eq!(set("0146").interval_vector(), [1, 1, 1, 1, 1, 1]);
+        assert_eq!(set("0 1 2 3 4 5 6 7 8 9 10 11").interval_vector(), [12, 12, 12, 12, 12, 6]);
+    }
+
+    #[test]
+    fn test_z_relation() {
+        assert!(set("0146").is_z_related(&set("0137")));
+        assert!(!set("0146").is_z_related(&set("0146").transposed(3)));
+        assert_eq!(set("0146").z_correspondent(), Some(set("0137")));
+        assert_eq!(set("012356").z_correspondent().map(|s| s.forte_number()), Some("6-Z36"));
+        assert_eq!(set("037").z_correspondent(), None);
+
+        // The Z flags agree with the interval vectors.
+        for (name, prime) in SET_CLASSES.iter() {
+            assert_eq!(name.contains('Z'), prime.z_correspondent().is_some(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(set("[0,1,4]"), PitchClassSet::from_pitch_classes(&[0, 1, 4]));
+        assert_eq!(set("014TE"), PitchClassSet::from_pitch_classes(&[0, 1, 4, 10, 11]));
+        assert_eq!(set("0 1 4 10 11"), PitchClassSet::from_pitch_classes(&[0, 1, 4, 10, 11]));
+        assert_eq!(set("[11]"), PitchClassSet::from_pitch_classes(&[11]));
+        assert_eq!(set("{10,}"), PitchClassSet::from_pitch_classes(&[10]));
+        assert_eq!(set("T"), PitchClassSet::from_pitch_classes(&[10]));
+        assert_eq!(set("E"), PitchClassSet::from_pitch_classes(&[11]));
+        assert_eq!(set("7"), PitchClassSet::from_pitch_classes(&[7]));
+
+        // Single tokens are compact, even when they look like a number.
+        assert_eq!(set("05"), PitchClassSet::from_pitch_classes(&[0, 5]));
+        assert_eq!(set("01"), PitchClassSet::from_pitch_classes(&[0, 1]));
+        assert_eq!(set("11"), PitchClassSet::from_pitch_classes(&[1]));
+        assert_eq!(set("01").forte_number(), "2-1");
+        assert_eq!(set("05").forte_number(), "2-5");
+
+        assert!(PitchClassSet::parse("0 1 12").is_err());
+        assert!(PitchClassSet::parse("C E G").is_err());
+    }
+}
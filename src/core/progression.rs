@@ -0,0 +1,592 @@
+//! A module for working with chord progressions.
+
+use std::{
+    fmt::{self, Display, Formatter, Write},
+    ops::{Add, Sub},
+};
+
+use pest::{iterators::Pair, Parser};
+
+use crate::core::{
+    base::{Parsable, Res},
+    chord::Chord,
+    interval::Interval,
+    name_style::NamingOptions,
+    note::{Respellable, Spelling},
+    parser::progression::{ProgressionParser, Rule},
+};
+
+// Statics.
+
+/// The default tempo of a [`Progression`] (in beats per minute).
+pub const DEFAULT_TEMPO: f32 = 120.0;
+
+// Structs.
+
+/// A time signature (e.g., `4/4`, or `6/8`).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct TimeSignature {
+    /// The number of beats in a bar.
+    pub beats: u8,
+    /// The note value of a beat (e.g., `4` for a quarter note).
+    pub unit: u8,
+}
+
+/// A single entry of a [`Progression`]: a chord (or a rest) that lasts some number of beats.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ProgressionEntry {
+    /// The chord, or `None` for a rest (i.e., `N.C.`).
+    pub chord: Option<Chord>,
+    /// The length of the entry (in beats).
+    pub beats: f32,
+}
+
+/// A repeated section of a [`Progression`] (i.e., `|: ... :|`).
+///
+/// The section is played `times` times, and each repetition is also in the entries of the progression.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct Repeat {
+    /// The index of the first entry of the section.
+    pub start: usize,
+    /// The number of entries in the section.
+    pub length: usize,
+    /// The number of times the section is played.
+    pub times: usize,
+}
+
+/// A chord progression.
+///
+/// This is a sequence of [`ProgressionEntry`]s, along with a [`TimeSignature`] and a tempo.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Progression {
+    /// The entries of the progression (with any repeats written out).
+    entries: Vec<ProgressionEntry>,
+    /// The repeated sections of the progression.
+    repeats: Vec<Repeat>,
+    /// The time signature of the progression.
+    time_signature: TimeSignature,
+    /// The tempo of the progression (in beats per minute).
+    tempo: f32,
+}
+
+// Impls.
+
+impl TimeSignature {
+    /// Creates a new [`TimeSignature`].
+    pub fn new(beats: u8, unit: u8) -> Self {
+        Self { beats, unit }
+    }
+}
+
+impl Default for TimeSignature {
+    fn default() -> Self {
+        Self::new(4, 4)
+    }
+}
+
+impl Display for TimeSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.beats, self.unit)
+    }
+}
+
+impl ProgressionEntry {
+    /// Creates a new [`ProgressionEntry`] for the given [`Chord`].
+    pub fn chord(chord: Chord, beats: f32) -> Self {
+        Self { chord: Some(chord), beats }
+    }
+
+    /// Creates a new rest (i.e., `N.C.`) [`ProgressionEntry`].
+    pub fn rest(beats: f32) -> Self {
+        Self { chord: None, beats }
+    }
+}
+
+impl Progression {
+    /// Creates a new [`Progression`] from the given entries (in 4/4, at the default tempo).
+    pub fn new(entries: Vec<ProgressionEntry>) -> Self {
+        Self {
+            entries,
+            repeats: Vec::new(),
+            time_signature: TimeSignature::default(),
+            tempo: DEFAULT_TEMPO,
+        }
+    }
+
+    /// Creates a new [`Progression`] from the given chords, with one chord per bar.
+    pub fn from_chords(chords: &[Chord]) -> Self {
+        let beats = TimeSignature::default().beats as f32;
+
+        Self::new(chords.iter().map(|c| ProgressionEntry::chord(c.clone(), beats)).collect())
+    }
+
+    /// Returns a new [`Progression`] with the given time signature.
+    pub fn with_time_signature(mut self, time_signature: TimeSignature) -> Self {
+        self.time_signature = time_signature;
+
+        self
+    }
+
+    /// Returns a new [`Progression`] with the given tempo (in beats per minute), which must be positive.
+    pub fn with_tempo(mut self, tempo: f32) -> Res<Self> {
+        if !tempo.is_finite() || tempo <= 0.0 {
+            return Err(anyhow::Error::msg("The tempo must be positive."));
+        }
+
+        self.tempo = tempo;
+
+        Ok(self)
+    }
+
+    /// Returns the entries of the progression (with any repeats written out).
+    pub fn entries(&self) -> &[ProgressionEntry] {
+        &self.entries
+    }
+
+    /// Returns the repeated sections of the progression.
+    pub fn repeats(&self) -> &[Repeat] {
+        &self.repeats
+    }
+
+    /// Returns the time signature of the progression.
+    pub fn time_signature(&self) -> TimeSignature {
+        self.time_signature
+    }
+
+    /// Returns the tempo of the progression (in beats per minute).
+    pub fn tempo(&self) -> f32 {
+        self.tempo
+    }
+
+    /// Returns the chords of the progression (skipping rests).
+    pub fn chords(&self) -> Vec<Chord> {
+        self.entries.iter().filter_map(|e| e.chord.clone()).collect()
+    }
+
+    /// Returns the total length of the progression (in beats).
+    pub fn beats(&self) -> f32 {
+        self.entries.iter().map(|e| e.beats).sum()
+    }
+
+    /// Returns the length of a single beat (in seconds).
+    pub fn seconds_per_beat(&self) -> f32 {
+        60.0 / self.tempo
+    }
+
+    /// Returns the total length of the progression (in seconds).
+    pub fn length_in_seconds(&self) -> f32 {
+        self.beats() * self.seconds_per_beat()
+    }
+
+    /// Transposes every chord of the progression by the interval (down, if `descending`), returning an error if a
+    /// chord would leave the range of octaves.
+    pub fn checked_transpose(&self, interval: Interval, descending: bool) -> Res<Self> {
+        self.try_map_chords(|c| c.clone().checked_transpose(interval, descending))
+    }
+
+    /// Returns a new [`Progression`] with each chord replaced by the result of the given function (keeping the
+    /// durations, and the repeats).
+    pub(crate) fn try_map_chords(&self, f: impl Fn(&Chord) -> Res<Chord>) -> Res<Self> {
+        let mut result = self.clone();
+
+        for entry in &mut result.entries {
+            entry.chord = entry.chord.as_ref().map(&f).transpose()?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl Progression {
+    /// Returns the progression written with the given [`NamingOptions`] for its chords.
+    pub fn to_string_with(&self, options: &NamingOptions) -> String {
+        let mut result = String::new();
+
+        self.write_with(&mut result, options).expect("Writing to a string cannot fail.");
+
+        result
+    }
+
+    /// Writes the progression (see [`Progression::to_string_with`]).
+    fn write_with(&self, f: &mut impl Write, options: &NamingOptions) -> fmt::Result {
+        let bar = self.time_signature.beats as f32;
+
+        let bar_line = |k: usize| if self.repeats.iter().any(|r| r.start == k) { "|:" } else { "|" };
+
+        write!(f, "{} {}bpm {}", self.time_signature, self.tempo, bar_line(0))?;
+
+        let mut position = 0.0;
+        let mut k = 0;
+
+        while let Some(entry) = self.entries.get(k) {
+            match &entry.chord {
+                Some(chord) => write!(f, " {}", chord.precise_name_with(options))?,
+                None => write!(f, " N.C.")?,
+            }
+
+            // A chord that fills an entire bar needs no duration.
+            if position % bar != 0.0 || entry.beats != bar {
+                write!(f, ":{}", entry.beats)?;
+            }
+
+            position += entry.beats;
+            k += 1;
+
+            // The repetitions of a repeated section are not written out.
+            if let Some(repeat) = self.repeats.iter().find(|r| r.start + r.length == k) {
+                match repeat.times {
+                    2 => write!(f, " :|")?,
+                    times => write!(f, " :|x{}", times)?,
+                }
+
+                let skipped = repeat.length * repeat.times.saturating_sub(1);
+
+                position += self.entries[k..k + skipped].iter().map(|e| e.beats).sum::<f32>();
+                k += skipped;
+
+                if self.repeats.iter().any(|r| r.start == k) {
+                    write!(f, " |:")?;
+                }
+            } else if position % bar == 0.0 {
+                write!(f, " {}", bar_line(k))?;
+            }
+        }
+
+        if position % bar != 0.0 {
+            write!(f, " |")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Progression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_with(f, &NamingOptions::default())
+    }
+}
+
+impl Add<Interval> for Progression {
+    type Output = Self;
+
+    fn add(self, rhs: Interval) -> Self::Output {
+        self.checked_transpose(rhs, false).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl Sub<Interval> for Progression {
+    type Output = Self;
+
+    fn sub(self, rhs: Interval) -> Self::Output {
+        self.checked_transpose(rhs, true).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl Respellable for Progression {
+    fn respelled(mut self, spelling: Spelling) -> Self {
+        for entry in &mut self.entries {
+            entry.chord = entry.chord.take().map(|c| c.respelled(spelling));
+        }
+
+        self
+    }
+}
+
+impl Parsable for Progression {
+    fn parse(input: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let root = ProgressionParser::parse(Rule::progression, input)?.next().unwrap();
+
+        assert_eq!(Rule::progression, root.as_rule());
+
+        let components = root.into_inner().collect::<Vec<_>>();
+
+        // If there are no bar lines, then every entry is its own bar.
+        let has_bar_lines = components.iter().any(|c| matches!(c.as_rule(), Rule::bar_line | Rule::repeat_start | Rule::repeat_end));
+
+        let mut time_signature = TimeSignature::default();
+        let mut tempo = DEFAULT_TEMPO;
+
+        let mut bars: Vec<Vec<ProgressionEntry>> = Vec::new();
+        let mut current: Vec<RawEntry> = Vec::new();
+        let mut repeats = Vec::new();
+        let mut repeat_start = 0;
+
+        for component in components {
+            match component.as_rule() {
+                Rule::time_signature => {
+                    let mut parts = component.into_inner();
+
+                    let beats = parts.next().unwrap().as_str().parse::<u8>()?;
+                    let unit = parts.next().unwrap().as_str().parse::<u8>()?;
+
+                    if beats == 0 || !unit.is_power_of_two() {
+                        return Err(anyhow::Error::msg(format!("Invalid time signature `{}/{}`.", beats, unit)));
+                    }
+
+                    time_signature = TimeSignature::new(beats, unit);
+                }
+                Rule::tempo => {
+                    tempo = component.into_inner().next().unwrap().as_str().parse::<f32>()?;
+                }
+                Rule::entry => {
+                    current.push(RawEntry::parse(component, time_signature)?);
+
+                    if !has_bar_lines {
+                        close_bar(&mut bars, &mut current, time_signature)?;
+                    }
+                }
+                Rule::bar_line => {
+                    close_bar(&mut bars, &mut current, time_signature)?;
+                }
+                Rule::repeat_start => {
+                    close_bar(&mut bars, &mut current, time_signature)?;
+
+                    repeat_start = bars.len();
+                }
+                Rule::repeat_end => {
+                    close_bar(&mut bars, &mut current, time_signature)?;
+
+                    let times = match component.into_inner().next() {
+                        Some(times) => times.as_str().parse::<usize>()?,
+                        None => 2,
+                    };
+
+                    if times == 0 {
+                        return Err(anyhow::Error::msg("A repeated section must be played at least once."));
+                    }
+
+                    let section = bars[repeat_start..].to_vec();
+                    let length = section.iter().map(Vec::len).sum();
+
+                    if length > 0 {
+                        repeats.push(Repeat {
+                            start: bars[..repeat_start].iter().map(Vec::len).sum(),
+                            length,
+                            times,
+                        });
+                    }
+
+                    for _ in 1..times {
+                        bars.extend(section.iter().cloned());
+                    }
+
+                    repeat_start = bars.len();
+                }
+                Rule::EOI => {
+                    close_bar(&mut bars, &mut current, time_signature)?;
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        let mut progression = Progression::new(bars.into_iter().flatten().collect()).with_time_signature(time_signature).with_tempo(tempo)?;
+        progression.repeats = repeats;
+
+        Ok(progression)
+    }
+}
+
+// Helpers.
+
+/// An entry, as written in the progression (before its duration is resolved).
+enum RawEntry {
+    /// A chord (or a rest) with an optional explicit duration (in beats).
+    Entry(Option<Chord>, Option<f32>),
+    /// A "repeat the previous bar" mark (i.e., `%`).
+    Simile,
+}
+
+impl RawEntry {
+    /// Parses an entry [`Pair`] into a [`RawEntry`].
+    fn parse(pair: Pair<'_, Rule>, time_signature: TimeSignature) -> Res<Self> {
+        let mut components = pair.into_inner();
+
+        let symbol = components.next().unwrap();
+
+        let chord = match symbol.as_rule() {
+            Rule::simile => return Ok(RawEntry::Simile),
+            Rule::no_chord => None,
+            Rule::chord_symbol => Some(Chord::parse(symbol.as_str())?),
+            _ => unreachable!(),
+        };
+
+        let beats = match components.next() {
+            Some(duration) => {
+                let mut parts = duration.into_inner();
+
+                let mut beats = parts.next().unwrap().as_str().parse::<f32>()?;
+
+                if parts.next().is_some() {
+                    beats *= time_signature.beats as f32;
+                }
+
+                if beats <= 0.0 {
+                    return Err(anyhow::Error::msg("Durations must be positive."));
+                }
+
+                Some(beats)
+            }
+            None => None,
+        };
+
+        Ok(RawEntry::Entry(chord, beats))
+    }
+}
+
+/// Resolves the durations of the current bar, and adds it to the list of bars.
+///
+/// Entries without an explicit duration evenly share whatever is left of the bar.
+fn close_bar(bars: &mut Vec<Vec<ProgressionEntry>>, current: &mut Vec<RawEntry>, time_signature: TimeSignature) -> Res<()> {
+    if current.is_empty() {
+        return Ok(());
+    }
+
+    let raw = std::mem::take(current);
+
+    if let [RawEntry::Simile] = raw.as_slice() {
+        let previous = bars.last().cloned().ok_or_else(|| anyhow::Error::msg("A `%` must follow another bar."))?;
+
+        bars.push(previous);
+
+        return Ok(());
+    }
+
+    let mut explicit = 0.0;
+    let mut implicit = 0;
+
+    for entry in &raw {
+        match entry {
+            RawEntry::Entry(_, Some(beats)) => explicit += beats,
+            RawEntry::Entry(_, None) => implicit += 1,
+            RawEntry::Simile => return Err(anyhow::Error::msg("A `%` must be the only entry in its bar.")),
+        }
+    }
+
+    let remaining = time_signature.beats as f32 - explicit;
+
+    if implicit > 0 && remaining <= 0.0 {
+        return Err(anyhow::Error::msg(format!("Bar {} has no room left for the chords without durations.", bars.len() + 1)));
+    }
+
+    let bar = raw
+        .into_iter()
+        .map(|entry| match entry {
+            RawEntry::Entry(chord, beats) => ProgressionEntry {
+                chord,
+                beats: beats.unwrap_or(remaining / implicit as f32),
+            },
+            RawEntry::Simile => unreachable!(),
+        })
+        .collect();
+
+    bars.push(bar);
+
+    Ok(())
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::HasName, chord::Chordable, note::*};
+    use pretty_assertions::assert_eq;
+
+    fn summary(progression: &Progression) -> Vec<String> {
+        progression
+            .entries()
+            .iter()
+            .map(|e| format!("{}:{}", e.chord.as_ref().map(|c| c.name()).unwrap_or_else(|| "N.C.".to_owned()), e.beats))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let progression = Progression::parse("| Dm7 G7 | Cmaj7 | N.C. |").unwrap();
+
+        assert_eq!(summary(&progression), vec!["Dm7:2", "G7:2", "Cmaj7:4", "N.C.:4"]);
+        assert_eq!(progression.chords(), vec![Chord::new(D).minor().seven(), Chord::new(G).seven(), Chord::new(C).major7()]);
+        assert_eq!(progression.time_signature(), TimeSignature::new(4, 4));
+        assert_eq!(progression.tempo(), DEFAULT_TEMPO);
+
+        let progression = Progression::parse("3/4 90bpm | C:1 F G | C:2bars |").unwrap();
+
+        assert_eq!(summary(&progression), vec!["C:1", "F:1", "G:1", "C:6"]);
+        assert_eq!(progression.tempo(), 90.0);
+        assert_eq!(progression.beats(), 9.0);
+        assert_eq!(progression.length_in_seconds(), 6.0);
+
+        // Without bar lines, every entry is a bar.
+        let progression = Progression::parse("Cm7 F7:2 Bbmaj7").unwrap();
+
+        assert_eq!(summary(&progression), vec!["Cm7:4", "F7:2", "B♭maj7:4"]);
+    }
+
+    #[test]
+    fn test_repeats() {
+        let progression = Progression::parse("| C | % |: F | G :| Am | Dm :|x3").unwrap();
+
+        assert_eq!(summary(&progression), vec!["C:4", "C:4", "F:4", "G:4", "F:4", "G:4", "Am:4", "Dm:4", "Am:4", "Dm:4", "Am:4", "Dm:4"]);
+        assert_eq!(progression.repeats(), [Repeat { start: 2, length: 2, times: 2 }, Repeat { start: 6, length: 2, times: 3 }]);
+
+        // The repeats are written back.
+        assert_eq!(progression.to_string(), "4/4 120bpm | C | C |: F | G :| |: Am | Dm :|x3");
+        assert_eq!((progression.clone() + Interval::PerfectFourth).to_string(), "4/4 120bpm | F | F |: B♭ | C :| |: Dm | Gm :|x3");
+        assert_eq!(Progression::parse(&progression.to_string()).unwrap(), progression);
+
+        let progression = Progression::parse("|: C:2 G:2 :| |: F :|x1 |").unwrap();
+
+        assert_eq!(progression.to_string(), "4/4 120bpm |: C:2 G:2 :| |: F :|x1");
+        assert_eq!(Progression::parse(&progression.to_string()).unwrap(), progression);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(Progression::parse("| C | H7 |").is_err());
+        assert!(Progression::parse("| C:4 F |").is_err());
+        assert!(Progression::parse("| % | C |").is_err());
+        assert!(Progression::parse("| C % |").is_err());
+        assert!(Progression::parse("| C:0 |").is_err());
+        assert!(Progression::parse("4/3 | C |").is_err());
+        assert!(Progression::parse("0bpm | C |").is_err());
+        assert!(Progression::parse("|: C :|x0").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let progression = Progression::parse("6/8 | Dm7 G7 | Cmaj7@5 | N.C.:3 C/E:9 |").unwrap();
+
+        assert_eq!(progression.to_string(), "6/8 120bpm | Dm7:3 G7:3 | Cmaj7@5 | N.C.:3 C/E:9 |");
+        assert_eq!(Progression::parse(&progression.to_string()).unwrap(), progression);
+
+        let progression = Progression::from_chords(&[Chord::new(C), Chord::new(F)]).with_tempo(60.0).unwrap();
+
+        assert_eq!(progression.to_string(), "4/4 60bpm | C | F |");
+        assert!(progression.clone().with_tempo(0.0).is_err());
+        assert!(progression.with_tempo(-60.0).is_err());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let progression = Progression::parse("3/4 | Dm7 G7 | Cmaj7/E:3 | N.C. |").unwrap();
+
+        assert_eq!((progression.clone() + Interval::MinorThird).to_string(), "3/4 120bpm | Fm7:1.5 B♭7:1.5 | E♭maj7/G | N.C. |");
+        assert_eq!((progression.clone() - Interval::MajorSecond).to_string(), "3/4 120bpm | Cm7:1.5 F7:1.5 | B♭maj7/D | N.C. |");
+        assert_eq!((progression + Interval::DiminishedThird).simplified().to_string(), "3/4 120bpm | Em7:1.5 A7:1.5 | Dmaj7/F♯ | N.C. |");
+
+        // Polychords stay whole, rather than being split at the `|`.
+        let progression = Progression::parse("| D|C7 | Db|C |").unwrap();
+
+        assert_eq!(progression.to_string(), "4/4 120bpm | D/C7 | D♭|C |");
+        assert_eq!((progression + Interval::MajorSecond).to_string(), "4/4 120bpm | E/D7 | E♭|D |");
+
+        // Transposing out of the range of octaves is an error.
+        let progression = Progression::parse("C@0 | Dm@0").unwrap();
+
+        assert_eq!(progression.checked_transpose(Interval::MajorSecond, true).unwrap().to_string(), "4/4 120bpm | B♭@0 | Cm@0 |");
+        assert!(progression.checked_transpose(Interval::PerfectOctave, true).is_err());
+    }
+}
@@ -0,0 +1,567 @@
+//! A module for reharmonizing chord progressions.
+//!
+//! A [`Reharmonizer`] proposes [`Reharmonization`]s of a sequence of [`Chord`]s (e.g., tritone substitutions, secondary
+//! dominants, or ii-V insertions), each of which is the whole sequence with the transformation applied.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::core::{
+    base::{HasName, HasStaticName, Parsable, Res},
+    chord::{Chord, Chordable, HasRoot},
+    interval::Interval,
+    key::{HasTonic, Key},
+    known_chord::HasRelativeChord,
+    name_style::NamingOptions,
+    note::Note,
+    octave::HasOctave,
+    pitch::HasPitch,
+    roman::HarmonicFunction,
+};
+
+// Enum.
+
+/// A reharmonization technique.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+pub enum Technique {
+    /// Replaces a dominant chord with the dominant a tritone away (e.g., `D♭7` for `G7`).
+    TritoneSubstitution,
+    /// Replaces the chord before a target with the dominant of the target (e.g., `A7` before `Dm7`).
+    SecondaryDominant,
+    /// Replaces the chord before a major target with the dominant a whole step below the target (e.g., `B♭7` before `C`).
+    BackdoorDominant,
+    /// Inserts the related ii before a dominant, or a ii-V before any other target (e.g., `Em7 A7` before `Dm7`).
+    RelatedTwoFive,
+    /// Inserts a diminished seventh chord between chords with roots a whole step apart (e.g., `C♯dim` between `C` and `Dm7`).
+    DiminishedPassing,
+    /// Replaces a diatonic chord with the chord on the same degree of the parallel key (e.g., `Fm7` for `Fmaj7` in C major).
+    ModalInterchange,
+}
+
+/// A part of the label of a [`Reharmonization`]; i.e., some text, or a chord, whose name is written with the label.
+#[derive(PartialEq, Clone, Debug)]
+enum LabelPart {
+    /// Some text (e.g., ` replaces `).
+    Text(String),
+    /// A chord.
+    Chord(Chord),
+}
+
+// Statics.
+
+/// All of the reharmonization techniques.
+pub static ALL_TECHNIQUES: [Technique; 6] = [
+    Technique::TritoneSubstitution,
+    Technique::SecondaryDominant,
+    Technique::BackdoorDominant,
+    Technique::RelatedTwoFive,
+    Technique::DiminishedPassing,
+    Technique::ModalInterchange,
+];
+
+// Structs.
+
+/// A reharmonization of a sequence of chords.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Reharmonization {
+    /// The technique used.
+    technique: Technique,
+    /// The index of the (original) chord that the technique was applied to.
+    index: usize,
+    /// The reharmonized sequence of chords.
+    chords: Vec<Chord>,
+    /// A label explaining the transformation.
+    label: Vec<LabelPart>,
+}
+
+/// A reharmonizer, which proposes [`Reharmonization`]s of a sequence of chords.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Reharmonizer {
+    /// The techniques to use.
+    techniques: Vec<Technique>,
+    /// The key of the sequence (used for modal interchange), or `None` to use the key that the chords fit best.
+    key: Option<Key>,
+}
+
+// Impls.
+
+impl HasStaticName for Technique {
+    fn static_name(&self) -> &'static str {
+        match self {
+            Technique::TritoneSubstitution => "tritone substitution",
+            Technique::SecondaryDominant => "secondary dominant",
+            Technique::BackdoorDominant => "backdoor dominant",
+            Technique::RelatedTwoFive => "related ii-V",
+            Technique::DiminishedPassing => "diminished passing chord",
+            Technique::ModalInterchange => "modal interchange",
+        }
+    }
+}
+
+impl Display for Technique {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.static_name())
+    }
+}
+
+impl Parsable for Technique {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let normalized = symbol.trim().to_lowercase().replace(['-', '_', ' '], "");
+
+        let technique = match normalized.as_str() {
+            "tritone" | "tritonesub" | "tritonesubstitution" => Technique::TritoneSubstitution,
+            "secondary" | "secondarydominant" => Technique::SecondaryDominant,
+            "backdoor" | "backdoordominant" => Technique::BackdoorDominant,
+            "iiv" | "twofive" | "relatediiv" | "relatedtwofive" => Technique::RelatedTwoFive,
+            "diminished" | "passing" | "diminishedpassing" | "diminishedpassingchord" => Technique::DiminishedPassing,
+            "modal" | "borrowed" | "modalinterchange" => Technique::ModalInterchange,
+            _ => return Err(anyhow::Error::msg(format!("Unknown reharmonization technique `{}`.", symbol))),
+        };
+
+        Ok(technique)
+    }
+}
+
+impl Reharmonization {
+    /// Returns the technique used.
+    pub fn technique(&self) -> Technique {
+        self.technique
+    }
+
+    /// Returns the index of the (original) chord that the technique was applied to.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the reharmonized sequence of chords.
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+
+    /// Returns the label explaining the transformation (e.g., `tritone substitution: D♭7 replaces G7`).
+    pub fn label(&self) -> String {
+        self.label_with(&NamingOptions::default())
+    }
+
+    /// Returns the label explaining the transformation, with the chords written with the given [`NamingOptions`].
+    pub fn label_with(&self, options: &NamingOptions) -> String {
+        let label = self
+            .label
+            .iter()
+            .map(|part| match part {
+                LabelPart::Text(text) => text.clone(),
+                LabelPart::Chord(chord) => chord.name_with(options),
+            })
+            .collect::<String>();
+
+        format!("{}: {}", self.technique.static_name(), label)
+    }
+
+    /// Returns the names of the reharmonized chords, written with the given [`NamingOptions`].
+    pub fn name_with(&self, options: &NamingOptions) -> String {
+        self.chords.iter().map(|c| c.name_with(options)).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Returns the label and the reharmonized chords, written with the given [`NamingOptions`].
+    pub fn to_string_with(&self, options: &NamingOptions) -> String {
+        format!("{}\n   {}", self.label_with(options), self.name_with(options))
+    }
+}
+
+impl HasName for Reharmonization {
+    fn name(&self) -> String {
+        self.name_with(&NamingOptions::default())
+    }
+}
+
+impl Display for Reharmonization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with(&NamingOptions::default()))
+    }
+}
+
+impl From<&str> for LabelPart {
+    fn from(text: &str) -> Self {
+        LabelPart::Text(text.to_owned())
+    }
+}
+
+impl From<String> for LabelPart {
+    fn from(text: String) -> Self {
+        LabelPart::Text(text)
+    }
+}
+
+impl From<&Chord> for LabelPart {
+    fn from(chord: &Chord) -> Self {
+        LabelPart::Chord(chord.clone())
+    }
+}
+
+impl Reharmonizer {
+    /// Creates a new [`Reharmonizer`] that uses all of the techniques, and infers the key.
+    pub fn new() -> Self {
+        Self {
+            techniques: ALL_TECHNIQUES.to_vec(),
+            key: None,
+        }
+    }
+
+    /// Sets the techniques to use.
+    pub fn with_techniques(mut self, techniques: &[Technique]) -> Self {
+        self.techniques = techniques.to_vec();
+
+        self
+    }
+
+    /// Sets the key of the sequence (used for modal interchange).
+    pub fn with_key(mut self, key: Key) -> Self {
+        self.key = Some(key);
+
+        self
+    }
+
+    /// Proposes reharmonizations of the given chords (ordered by technique, and then by position).
+    pub fn reharmonize(&self, chords: &[Chord]) -> Vec<Reharmonization> {
+        let mut result = vec![];
+
+        for technique in ALL_TECHNIQUES.iter().filter(|t| self.techniques.contains(t)) {
+            for index in 0..chords.len() {
+                result.extend(self.apply(*technique, chords, index));
+            }
+        }
+
+        result
+    }
+
+    /// Applies the technique to the chord at the given index, if possible.
+    fn apply(&self, technique: Technique, chords: &[Chord], index: usize) -> Option<Reharmonization> {
+        let chord = &chords[index];
+        let previous = index.checked_sub(1).map(|k| &chords[k]);
+
+        let (start, end, replacement, label) = match technique {
+            Technique::TritoneSubstitution => {
+                if !is_dominant(chord) {
+                    return None;
+                }
+
+                let substitute = near(chord, Interval::DiminishedFifth).seven();
+                let label = vec![(&substitute).into(), " replaces ".into(), chord.into()];
+
+                (index, index + 1, vec![substitute], label)
+            }
+            Technique::SecondaryDominant => {
+                let previous = previous?;
+
+                if is_dominant(chord) || !has_fifth(chord) {
+                    return None;
+                }
+
+                let dominant = near(chord, Interval::PerfectFifth).seven();
+
+                if is_dominant(previous) && same_root(previous, &dominant) {
+                    return None;
+                }
+
+                let label = vec![(&dominant).into(), " (V7/".into(), chord.into(), ") replaces ".into(), previous.into()];
+
+                (index - 1, index, vec![dominant], label)
+            }
+            Technique::BackdoorDominant => {
+                let previous = previous?;
+
+                if !is_major(chord) {
+                    return None;
+                }
+
+                let dominant = near(chord, Interval::MinorSeventh).seven();
+
+                if is_dominant(previous) && same_root(previous, &dominant) {
+                    return None;
+                }
+
+                let label = vec![(&dominant).into(), " (♭VII7/".into(), chord.into(), ") replaces ".into(), previous.into()];
+
+                (index - 1, index, vec![dominant], label)
+            }
+            Technique::RelatedTwoFive => {
+                let inserted = if is_dominant(chord) {
+                    vec![near(chord, Interval::PerfectFifth).minor().seven()]
+                } else if has_fifth(chord) {
+                    let (two, five) = if is_minor(chord) {
+                        (near(chord, Interval::MajorSecond).half_diminished(), near(chord, Interval::PerfectFifth).seven().flat9())
+                    } else {
+                        (near(chord, Interval::MajorSecond).minor().seven(), near(chord, Interval::PerfectFifth).seven())
+                    };
+
+                    vec![two, five]
+                } else {
+                    return None;
+                };
+
+                // Skip chords that are already approached by the inserted chord.
+                if previous.map(|p| same_root(p, &inserted[inserted.len() - 1])).unwrap_or(false) {
+                    return None;
+                }
+
+                let mut label = names(&inserted);
+                label.extend([" before ".into(), chord.into()]);
+                let mut replacement = inserted;
+                replacement.push(chord.clone());
+
+                (index, index + 1, replacement, label)
+            }
+            Technique::DiminishedPassing => {
+                let next = chords.get(index + 1)?;
+                let step = (pitch_class(next.root()) - pitch_class(chord.root())).rem_euclid(12);
+
+                let passing = match step {
+                    2 => near(chord, Interval::AugmentedUnison).diminished(),
+                    10 => near(chord, Interval::MajorSeventh).diminished(),
+                    _ => return None,
+                };
+
+                let label = vec![(&passing).into(), " between ".into(), chord.into(), " and ".into(), next.into()];
+
+                (index, index + 1, vec![chord.clone(), passing], label)
+            }
+            Technique::ModalInterchange => {
+                let key = self.key.or_else(|| infer_key(chords))?;
+                let parallel = key.parallel();
+                let has_seventh = chord.relative_chord().len() > 3;
+
+                let degree = (1..=7).find(|d| {
+                    let diatonic = if has_seventh { key.seventh(*d) } else { key.triad(*d) };
+
+                    diatonic.map(|c| same_root(&c, chord) && c.relative_chord() == chord.relative_chord()).unwrap_or(false)
+                })?;
+
+                let borrowed = if has_seventh { parallel.seventh(degree) } else { parallel.triad(degree) }.ok()?;
+
+                if borrowed.relative_chord() == chord.relative_chord() {
+                    return None;
+                }
+
+                let borrowed = borrowed.with_octave(chord.root().octave());
+                let label = vec![(&borrowed).into(), format!(" (from {}) replaces ", parallel.name()).into(), chord.into()];
+
+                (index, index + 1, vec![borrowed], label)
+            }
+        };
+
+        let mut result = chords[..start].to_vec();
+        result.extend(replacement);
+        result.extend_from_slice(&chords[end..]);
+
+        Some(Reharmonization { technique, index, chords: result, label })
+    }
+}
+
+impl Default for Reharmonizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Helpers.
+
+/// Returns a new chord rooted at the given interval above the root of the given chord (moved into the same octave).
+fn near(chord: &Chord, interval: Interval) -> Chord {
+    let root = chord.root() + interval;
+
+    Chord::new(root).with_octave(chord.root().octave())
+}
+
+/// Returns the pitch class of the note (i.e., the number of semitones above C).
+fn pitch_class(note: Note) -> i32 {
+    note.semitones().rem_euclid(12)
+}
+
+/// Returns whether the chords have the same root (enharmonically).
+fn same_root(a: &Chord, b: &Chord) -> bool {
+    pitch_class(a.root()) == pitch_class(b.root())
+}
+
+/// Returns whether the chord is a dominant chord (i.e., it has a major third and a minor seventh).
+fn is_dominant(chord: &Chord) -> bool {
+    let intervals = chord.relative_chord();
+
+    intervals.contains(&Interval::MajorThird) && intervals.contains(&Interval::MinorSeventh)
+}
+
+/// Returns whether the chord is a major chord (i.e., it has a major third and a perfect fifth, and is not a dominant).
+fn is_major(chord: &Chord) -> bool {
+    let intervals = chord.relative_chord();
+
+    intervals.contains(&Interval::MajorThird) && intervals.contains(&Interval::PerfectFifth) && !intervals.contains(&Interval::MinorSeventh)
+}
+
+/// Returns whether the chord is a minor chord (i.e., it has a minor third and a perfect fifth).
+fn is_minor(chord: &Chord) -> bool {
+    let intervals = chord.relative_chord();
+
+    intervals.contains(&Interval::MinorThird) && intervals.contains(&Interval::PerfectFifth)
+}
+
+/// Returns whether the chord has a perfect fifth (i.e., it can be the target of a dominant).
+fn has_fifth(chord: &Chord) -> bool {
+    chord.relative_chord().contains(&Interval::PerfectFifth)
+}
+
+/// Infers the key of a sequence of chords; i.e., the major or minor key that the chords fit best (with ties going to
+/// the keys whose tonic chord begins or ends the sequence, and then to the key of the last chord).
+fn infer_key(chords: &[Chord]) -> Option<Key> {
+    // The candidates are the keys on each root, and on the resolution of each root (as if it were a dominant).
+    let candidates = chords
+        .iter()
+        .rev()
+        .flat_map(|c| [c.root(), c.root() + Interval::PerfectFourth])
+        .flat_map(|tonic| [Key::major(tonic), Key::minor(tonic)]);
+
+    let score = |key: &Key| {
+        let fit = key
+            .analyze(chords)
+            .iter()
+            .map(|a| match a.function {
+                HarmonicFunction::Tonic | HarmonicFunction::Subdominant | HarmonicFunction::Dominant => 2,
+                HarmonicFunction::SecondaryDominant => 1,
+                HarmonicFunction::Borrowed | HarmonicFunction::Chromatic => 0,
+            })
+            .sum::<usize>();
+        let is_tonic = |chord: Option<&Chord>| chord.is_some_and(|c| c.root().pitch() == key.tonic().pitch() && is_minor(c) == key.is_minor()) as usize;
+
+        fit + is_tonic(chords.first()) + is_tonic(chords.last())
+    };
+
+    candidates
+        .map(|key| (score(&key), key))
+        .fold(None, |best: Option<(usize, Key)>, (score, key)| match best {
+            Some((best_score, _)) if best_score >= score => best,
+            _ => Some((score, key)),
+        })
+        .map(|(_, key)| key)
+}
+
+/// Returns the label parts for the chords, separated by spaces.
+fn names(chords: &[Chord]) -> Vec<LabelPart> {
+    let mut result = Vec::new();
+
+    for (k, chord) in chords.iter().enumerate() {
+        if k > 0 {
+            result.push(" ".into());
+        }
+
+        result.push(chord.into());
+    }
+
+    result
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{name_style::NameStyle, note::Spelling};
+    use pretty_assertions::assert_eq;
+
+    fn chords(symbols: &str) -> Vec<Chord> {
+        symbols.split_whitespace().map(|s| Chord::parse(s).unwrap()).collect()
+    }
+
+    fn suggestions(technique: Technique, symbols: &str) -> Vec<String> {
+        Reharmonizer::new()
+            .with_techniques(&[technique])
+            .reharmonize(&chords(symbols))
+            .iter()
+            .map(|r| format!("{} => {}", r.label(), r.name()))
+            .collect()
+    }
+
+    #[test]
+    fn test_reharmonize() {
+        assert_eq!(
+            suggestions(Technique::TritoneSubstitution, "Dm7 G7 Cmaj7"),
+            vec!["tritone substitution: D♭7 replaces G7 => Dm7 D♭7 Cmaj7"]
+        );
+        assert_eq!(
+            suggestions(Technique::SecondaryDominant, "Cmaj7 Am7 Dm7 G7 Cmaj7"),
+            vec![
+                "secondary dominant: E7 (V7/Am7) replaces Cmaj7 => E7 Am7 Dm7 G7 Cmaj7",
+                "secondary dominant: A7 (V7/Dm7) replaces Am7 => Cmaj7 A7 Dm7 G7 Cmaj7"
+            ]
+        );
+        assert_eq!(
+            suggestions(Technique::BackdoorDominant, "Dm7 G7 Cmaj7"),
+            vec!["backdoor dominant: B♭7 (♭VII7/Cmaj7) replaces G7 => Dm7 B♭7 Cmaj7"]
+        );
+        assert_eq!(
+            suggestions(Technique::RelatedTwoFive, "Cmaj7 Am7 G7 Cmaj7"),
+            vec![
+                "related ii-V: Dm7 G7 before Cmaj7 => Dm7 G7 Cmaj7 Am7 G7 Cmaj7",
+                "related ii-V: Bm7(♭5) E7(♭9) before Am7 => Cmaj7 Bm7(♭5) E7(♭9) Am7 G7 Cmaj7",
+                "related ii-V: Dm7 before G7 => Cmaj7 Am7 Dm7 G7 Cmaj7"
+            ]
+        );
+        assert_eq!(
+            suggestions(Technique::DiminishedPassing, "C Dm7 Em7 Dm7"),
+            vec![
+                "diminished passing chord: C♯dim between C and Dm7 => C C♯dim Dm7 Em7 Dm7",
+                "diminished passing chord: D♯dim between Dm7 and Em7 => C Dm7 D♯dim Em7 Dm7",
+                "diminished passing chord: D♯dim between Em7 and Dm7 => C Dm7 Em7 D♯dim Dm7"
+            ]
+        );
+        assert_eq!(
+            suggestions(Technique::ModalInterchange, "C F G C"),
+            vec![
+                "modal interchange: Cm (from C minor) replaces C => Cm F G C",
+                "modal interchange: Fm (from C minor) replaces F => C Fm G C",
+                "modal interchange: Gm (from C minor) replaces G => C F Gm C",
+                "modal interchange: Cm (from C minor) replaces C => C F G Cm"
+            ]
+        );
+
+        // The key is inferred from the whole sequence (not just the last chord).
+        assert_eq!(
+            suggestions(Technique::ModalInterchange, "Cmaj7 Am7 Dm7 G7"),
+            vec![
+                "modal interchange: Cm7 (from C minor) replaces Cmaj7 => Cm7 Am7 Dm7 G7",
+                "modal interchange: A♭maj7 (from C minor) replaces Am7 => Cmaj7 A♭maj7 Dm7 G7",
+                "modal interchange: Dm7(♭5) (from C minor) replaces Dm7 => Cmaj7 Am7 Dm7(♭5) G7",
+                "modal interchange: Gm7 (from C minor) replaces G7 => Cmaj7 Am7 Dm7 Gm7"
+            ]
+        );
+        assert_eq!(infer_key(&chords("Dm7 G7")).map(|k| k.name()), Some("C major".to_owned()));
+        assert_eq!(infer_key(&chords("Am Dm E7 Am")).map(|k| k.name()), Some("A minor".to_owned()));
+        assert_eq!(infer_key(&[]), None);
+
+        // The key can be given, rather than inferred.
+        let reharmonizations = Reharmonizer::new()
+            .with_techniques(&[Technique::ModalInterchange])
+            .with_key(Key::minor(Note::parse("A").unwrap()))
+            .reharmonize(&chords("Am Dm Em"));
+        assert_eq!(reharmonizations.iter().map(|r| r.name()).collect::<Vec<_>>(), vec!["A Dm Em", "Am D Em", "Am Dm E"]);
+
+        let all = Reharmonizer::default().reharmonize(&chords("Dm7 G7 Cmaj7"));
+        assert_eq!(all[0].technique(), Technique::TritoneSubstitution);
+        assert_eq!(all[0].index(), 1);
+        assert_eq!(all[0].chords().len(), 3);
+        assert_eq!(all[0].to_string(), "tritone substitution: D♭7 replaces G7\n   Dm7 D♭7 Cmaj7");
+
+        // The chords in the label are written with the naming options, too.
+        let options = NamingOptions::default().with_spelling(Spelling::Sharps).with_style(NameStyle::Jazz);
+        assert_eq!(all[0].to_string_with(&options), "tritone substitution: C♯7 replaces G7\n   Dm7 C♯7 CΔ7");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Technique::parse("tritone").unwrap(), Technique::TritoneSubstitution);
+        assert_eq!(Technique::parse("Secondary Dominant").unwrap(), Technique::SecondaryDominant);
+        assert_eq!(Technique::parse("ii-V").unwrap(), Technique::RelatedTwoFive);
+        assert_eq!(Technique::parse("modal_interchange").unwrap(), Technique::ModalInterchange);
+
+        assert!(Technique::parse("foo").is_err());
+    }
+}
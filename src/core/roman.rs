@@ -0,0 +1,617 @@
+//! A module for working with roman numerals (functional harmony).
+
+use std::fmt::{self, Display, Formatter};
+
+use pest::{iterators::Pair, Parser};
+
+use crate::core::{
+    base::{HasName, HasStaticName, Parsable, Res},
+    chord::{Chord, Chordable, HasInversion, HasRoot},
+    interval::Interval,
+    key::{HasMode, HasTonic, Key},
+    known_chord::HasRelativeChord,
+    named_pitch::{HasLetter, HasNamedPitch, ALL_PITCHES},
+    note::{Note, NoteRecreator},
+    parser::roman::{RomanNumeralParser, Rule},
+    pitch::HasPitch,
+    scale::Scale,
+};
+
+// Enums.
+
+/// An enum representing the quality of the triad of a roman numeral.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum TriadQuality {
+    /// A major triad (e.g., `V`).
+    Major,
+    /// A minor triad (e.g., `ii`).
+    Minor,
+    /// A diminished triad (e.g., `vii°`).
+    Diminished,
+    /// An augmented triad (e.g., `III+`).
+    Augmented,
+}
+
+/// An enum representing the quality of the seventh of a roman numeral.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum SeventhQuality {
+    /// A major seventh (e.g., `Imaj7`).
+    Major,
+    /// A minor seventh (e.g., `V7`, or `viiø7`).
+    Minor,
+    /// A diminished seventh (e.g., `vii°7`).
+    Diminished,
+}
+
+/// An enum representing the harmonic function of a chord in a key.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub enum HarmonicFunction {
+    /// A diatonic chord with tonic function (`I`, `iii`, `vi`).
+    Tonic,
+    /// A diatonic chord with subdominant function (`ii`, `IV`).
+    Subdominant,
+    /// A diatonic chord with dominant function (`V`, `vii°`).
+    Dominant,
+    /// A dominant of a diatonic chord other than the tonic (e.g., `V7/V`).
+    SecondaryDominant,
+    /// A chord borrowed from the parallel key (e.g., `♭VI` in a major key).
+    Borrowed,
+    /// A chord that does not otherwise belong to the key.
+    Chromatic,
+}
+
+// Structs.
+
+/// A roman numeral, which describes a chord relative to a [`Key`].
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+pub struct RomanNumeral {
+    /// The (one-based) scale degree of the root.
+    pub degree: u8,
+    /// The accidental applied to the scale degree (e.g., `-1` for `♭VI`).
+    pub accidental: i8,
+    /// The quality of the triad.
+    pub triad: TriadQuality,
+    /// The quality of the seventh, if any.
+    pub seventh: Option<SeventhQuality>,
+    /// Whether or not the chord has an added sixth.
+    pub sixth: bool,
+    /// The inversion of the chord.
+    pub inversion: u8,
+    /// The chord that this chord is applied to (e.g., the `V` in `V7/V`), if any.
+    pub secondary: Option<Box<RomanNumeral>>,
+}
+
+/// The roman numeral analysis of a [`Chord`] in a [`Key`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Analysis {
+    /// The chord being analyzed.
+    pub chord: Chord,
+    /// The roman numeral of the chord, if it can be expressed as one.
+    pub numeral: Option<RomanNumeral>,
+    /// The harmonic function of the chord.
+    pub function: HarmonicFunction,
+}
+
+// Impls.
+
+impl RomanNumeral {
+    /// Creates a new [`RomanNumeral`] from a scale degree and triad quality.
+    pub fn new(degree: u8, triad: TriadQuality) -> Self {
+        Self {
+            degree,
+            accidental: 0,
+            triad,
+            seventh: None,
+            sixth: false,
+            inversion: 0,
+            secondary: None,
+        }
+    }
+
+    /// Parses a progression of roman numerals (e.g., `ii7 V7/V ♭VImaj7 I6`).
+    ///
+    /// The numerals may be separated by whitespace, `-`, `|`, or `,`.
+    pub fn parse_progression(input: &str) -> Res<Vec<Self>> {
+        let root = RomanNumeralParser::parse(Rule::progression, input)?.next().unwrap();
+
+        assert_eq!(Rule::progression, root.as_rule());
+
+        root.into_inner().filter(|p| p.as_rule() == Rule::numeral).map(numeral_from_pair).collect()
+    }
+
+    /// Builds the [`Chord`] that this roman numeral represents in the given [`Key`].
+    ///
+    /// Returns an error if the key does not have seven degrees (e.g., a pentatonic key), or if the
+    /// root cannot be spelled (e.g., `♭IV` in `F𝄫`).
+    pub fn to_chord(&self, key: &Key) -> Res<Chord> {
+        // Secondary chords are built in the key of the chord they are applied to.
+        let key = match &self.secondary {
+            Some(target) => {
+                let tonic = target.root(key)?;
+
+                match target.triad {
+                    TriadQuality::Minor | TriadQuality::Diminished => Key::minor(tonic),
+                    _ => Key::major(tonic),
+                }
+            }
+            None => *key,
+        };
+
+        let root = self.root(&key)?;
+        let mut chord = Chord::new(root);
+
+        chord = match self.triad {
+            TriadQuality::Major => chord,
+            TriadQuality::Minor => chord.minor(),
+            TriadQuality::Diminished => chord.minor().flat5(),
+            TriadQuality::Augmented => chord.augmented(),
+        };
+
+        chord = match self.seventh {
+            None => chord,
+            Some(SeventhQuality::Major) => chord.major7(),
+            Some(SeventhQuality::Minor) => chord.seven(),
+            Some(SeventhQuality::Diminished) => Chord::new(root).diminished(),
+        };
+
+        if self.sixth {
+            chord = chord.add6();
+        }
+
+        Ok(chord.with_inversion(self.inversion))
+    }
+
+    /// Describes the given [`Chord`] as a [`RomanNumeral`] in the given [`Key`].
+    ///
+    /// Returns `None` if the chord is not a triad or seventh chord, or if its root
+    /// cannot be expressed as a scale degree of the key.
+    pub fn from_chord(key: &Key, chord: &Chord) -> Option<Self> {
+        let root = chord.root();
+        let notes = key.notes();
+
+        let index = notes.iter().position(|n| n.named_pitch().letter() == root.named_pitch().letter())?;
+        let accidental = match (root.pitch() as i8 - notes[index].pitch() as i8).rem_euclid(12) {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            10 => -2,
+            11 => -1,
+            _ => return None,
+        };
+
+        let intervals = chord.relative_chord();
+
+        let triad = match (
+            intervals.iter().find(|i| matches!(i, Interval::MajorThird | Interval::MinorThird))?,
+            intervals.iter().find(|i| matches!(i, Interval::PerfectFifth | Interval::DiminishedFifth | Interval::AugmentedFifth))?,
+        ) {
+            (Interval::MajorThird, Interval::PerfectFifth) => TriadQuality::Major,
+            (Interval::MinorThird, Interval::PerfectFifth) => TriadQuality::Minor,
+            (Interval::MinorThird, Interval::DiminishedFifth) => TriadQuality::Diminished,
+            (Interval::MajorThird, Interval::AugmentedFifth) => TriadQuality::Augmented,
+            _ => return None,
+        };
+
+        let accidental = accidental - leading_tone(key, index as u8 + 1, triad);
+
+        let seventh = if intervals.contains(&Interval::MajorSeventh) {
+            Some(SeventhQuality::Major)
+        } else if intervals.contains(&Interval::MinorSeventh) {
+            Some(SeventhQuality::Minor)
+        } else if intervals.contains(&Interval::DiminishedSeventh) {
+            Some(SeventhQuality::Diminished)
+        } else {
+            None
+        };
+
+        Some(Self {
+            degree: index as u8 + 1,
+            accidental,
+            triad,
+            seventh,
+            sixth: seventh.is_none() && intervals.contains(&Interval::MajorSixth),
+            inversion: chord.inversion(),
+            secondary: None,
+        })
+    }
+
+    /// Returns the root of the roman numeral in the given [`Key`] (ignoring any secondary).
+    fn root(&self, key: &Key) -> Res<Note> {
+        let notes = key.notes();
+
+        // The degrees of roman numerals only make sense in keys with seven notes.
+        if notes.len() != 7 {
+            return Err(anyhow::Error::msg(format!("Roman numerals need a key with seven degrees, but `{}` has {}.", key, notes.len())));
+        }
+
+        let note = notes[self.degree as usize - 1];
+
+        // Each accidental moves the root by seven steps around the circle of fifths.
+        let shift = 7 * (self.accidental + leading_tone(key, self.degree, self.triad));
+        let named_pitch = ALL_PITCHES
+            .iter()
+            .position(|p| *p == note.named_pitch())
+            .and_then(|k| usize::try_from(k as i8 + shift).ok())
+            .and_then(|k| ALL_PITCHES.get(k))
+            .ok_or_else(|| anyhow::Error::msg(format!("The root of `{}` cannot be spelled in the key `{}`.", self.name(), key)))?;
+
+        Ok(note.with_named_pitch(*named_pitch))
+    }
+
+    /// Returns whether or not this numeral has the same harmony as another (ignoring inversions and sixths).
+    fn same_harmony(&self, other: &Self) -> bool {
+        self.degree == other.degree && self.accidental == other.accidental && self.triad == other.triad && self.seventh == other.seventh
+    }
+}
+
+impl HasName for RomanNumeral {
+    fn name(&self) -> String {
+        const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+        let mut name = String::new();
+
+        let accidental = if self.accidental < 0 { "♭" } else { "♯" };
+        name.push_str(&accidental.repeat(self.accidental.unsigned_abs() as usize));
+
+        let numeral = NUMERALS[(self.degree as usize - 1) % NUMERALS.len()];
+        match self.triad {
+            TriadQuality::Major | TriadQuality::Augmented => name.push_str(numeral),
+            TriadQuality::Minor | TriadQuality::Diminished => name.push_str(&numeral.to_lowercase()),
+        }
+
+        match (self.triad, self.seventh) {
+            (TriadQuality::Diminished, Some(SeventhQuality::Minor)) => name.push('ø'),
+            (TriadQuality::Diminished, _) => name.push('°'),
+            (TriadQuality::Augmented, _) => name.push('+'),
+            _ => {}
+        }
+
+        match self.seventh {
+            Some(SeventhQuality::Major) => name.push_str("maj7"),
+            Some(_) => name.push('7'),
+            None if self.sixth => name.push('6'),
+            None => {}
+        }
+
+        if let Some(target) = &self.secondary {
+            name.push('/');
+            name.push_str(&target.name());
+        }
+
+        if self.inversion > 0 {
+            name.push_str(&format!("^{}", self.inversion));
+        }
+
+        name
+    }
+}
+
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Parsable for RomanNumeral {
+    fn parse(input: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let root = RomanNumeralParser::parse(Rule::roman_numeral, input)?.next().unwrap();
+
+        assert_eq!(Rule::roman_numeral, root.as_rule());
+
+        numeral_from_pair(root.into_inner().next().unwrap())
+    }
+}
+
+impl HasStaticName for HarmonicFunction {
+    fn static_name(&self) -> &'static str {
+        match self {
+            HarmonicFunction::Tonic => "tonic",
+            HarmonicFunction::Subdominant => "subdominant",
+            HarmonicFunction::Dominant => "dominant",
+            HarmonicFunction::SecondaryDominant => "secondary dominant",
+            HarmonicFunction::Borrowed => "borrowed",
+            HarmonicFunction::Chromatic => "chromatic",
+        }
+    }
+}
+
+impl Analysis {
+    /// Analyzes the given [`Chord`] in the given [`Key`].
+    pub fn new(key: &Key, chord: &Chord) -> Self {
+        let numeral = RomanNumeral::from_chord(key, chord);
+
+        // Diatonic chords.
+
+        if let Some(degree) = diatonic_degree(key, chord) {
+            let function = match degree {
+                1 | 3 | 6 => HarmonicFunction::Tonic,
+                2 | 4 => HarmonicFunction::Subdominant,
+                _ => HarmonicFunction::Dominant,
+            };
+
+            return Self::with(chord, numeral, function);
+        }
+
+        // Dominant (and secondary dominant) chords.
+
+        let dominant = numeral
+            .clone()
+            .filter(|n| n.triad == TriadQuality::Major && matches!(n.seventh, None | Some(SeventhQuality::Minor)) && !n.sixth);
+        let target = key.notes().iter().position(|n| n.pitch() == (chord.root() + Interval::PerfectFourth).pitch());
+
+        // This is just the dominant (e.g., `V` in a minor key).
+        if let (Some(mut dominant), Some(0)) = (dominant.clone(), target) {
+            dominant.degree = 5;
+            dominant.accidental = 0;
+
+            return Self::with(chord, Some(dominant), HarmonicFunction::Dominant);
+        }
+
+        // Borrowed chords.
+
+        if diatonic_degree(&key.parallel(), chord).is_some() {
+            return Self::with(chord, numeral, HarmonicFunction::Borrowed);
+        }
+
+        if let (Some(mut dominant), Some(index)) = (dominant, target) {
+            if let Some(triad) = key
+                .triad(index as u8 + 1)
+                .ok()
+                .and_then(|c| RomanNumeral::from_chord(key, &c))
+                .filter(|t| t.triad != TriadQuality::Diminished)
+            {
+                dominant.degree = 5;
+                dominant.accidental = 0;
+                dominant.secondary = Some(Box::new(RomanNumeral::new(triad.degree, triad.triad)));
+
+                return Self::with(chord, Some(dominant), HarmonicFunction::SecondaryDominant);
+            }
+        }
+
+        Self::with(chord, numeral, HarmonicFunction::Chromatic)
+    }
+
+    /// Analyzes all of the given [`Chord`]s in the given [`Key`].
+    pub fn all(key: &Key, chords: &[Chord]) -> Vec<Self> {
+        chords.iter().map(|c| Self::new(key, c)).collect()
+    }
+
+    fn with(chord: &Chord, numeral: Option<RomanNumeral>, function: HarmonicFunction) -> Self {
+        Self { chord: chord.clone(), numeral, function }
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let numeral = self.numeral.as_ref().map(|n| n.name()).unwrap_or_else(|| "?".to_owned());
+
+        write!(f, "{}: {} ({})", self.chord.name(), numeral, self.function.static_name())
+    }
+}
+
+// Helpers.
+
+/// Returns the (one-based) degree of the given [`Chord`] in the given [`Key`] if the chord is diatonic.
+fn diatonic_degree(key: &Key, chord: &Chord) -> Option<u8> {
+    let numeral = RomanNumeral::from_chord(key, chord)?;
+
+    if numeral.accidental != 0 {
+        return None;
+    }
+
+    let diatonic = match numeral.seventh {
+        Some(_) => key.seventh(numeral.degree),
+        None => key.triad(numeral.degree),
+    }
+    .ok()?;
+
+    if RomanNumeral::from_chord(key, &diatonic)?.same_harmony(&numeral) {
+        return Some(numeral.degree);
+    }
+
+    // Minor keys also borrow their dominant harmony from harmonic minor.
+    if key.mode() == Scale::Aeolian && matches!(numeral.degree, 5 | 7) {
+        return diatonic_degree(&Key::new(key.tonic(), Scale::HarmonicMinor), chord);
+    }
+
+    None
+}
+
+/// Returns the accidental implied by the given degree and triad quality in the given [`Key`].
+///
+/// In minor keys, `vii°` is built on the leading tone, rather than the subtonic.
+fn leading_tone(key: &Key, degree: u8, triad: TriadQuality) -> i8 {
+    (key.mode() == Scale::Aeolian && degree == 7 && triad == TriadQuality::Diminished) as i8
+}
+
+/// Parses a scale degree [`Pair`] into its degree, accidental, and whether or not it is uppercase.
+fn degree_from_pair(pair: Pair<'_, Rule>) -> (u8, i8, bool) {
+    let mut accidental = 0;
+    let mut degree = 1;
+    let mut is_upper = true;
+
+    for component in pair.into_inner() {
+        match component.as_rule() {
+            Rule::accidental => {
+                accidental = match component.as_str() {
+                    "#" | "♯" => 1,
+                    _ => -1,
+                }
+            }
+            Rule::upper_numeral | Rule::lower_numeral => {
+                is_upper = component.as_rule() == Rule::upper_numeral;
+                degree = match component.as_str().to_uppercase().as_str() {
+                    "I" => 1,
+                    "II" => 2,
+                    "III" => 3,
+                    "IV" => 4,
+                    "V" => 5,
+                    "VI" => 6,
+                    "VII" => 7,
+                    _ => unreachable!(),
+                };
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    (degree, accidental, is_upper)
+}
+
+/// Parses a numeral [`Pair`] into a [`RomanNumeral`].
+fn numeral_from_pair(pair: Pair<'_, Rule>) -> Res<RomanNumeral> {
+    assert_eq!(Rule::numeral, pair.as_rule());
+
+    let mut components = pair.into_inner();
+
+    let (degree, accidental, is_upper) = degree_from_pair(components.next().unwrap());
+
+    let mut result = RomanNumeral::new(degree, if is_upper { TriadQuality::Major } else { TriadQuality::Minor });
+    result.accidental = accidental;
+
+    while let Some(component) = components.next() {
+        match component.as_rule() {
+            Rule::diminished => {
+                result.triad = TriadQuality::Diminished;
+            }
+            Rule::half_diminished => {
+                result.triad = TriadQuality::Diminished;
+                result.seventh = Some(SeventhQuality::Minor);
+            }
+            Rule::augmented => {
+                result.triad = TriadQuality::Augmented;
+            }
+            Rule::major_seventh => {
+                result.seventh = Some(SeventhQuality::Major);
+            }
+            Rule::seventh => {
+                result.seventh = match (result.triad, result.seventh) {
+                    (TriadQuality::Diminished, None) => Some(SeventhQuality::Diminished),
+                    _ => Some(SeventhQuality::Minor),
+                };
+            }
+            Rule::sixth => {
+                result.sixth = true;
+            }
+            Rule::slash => {
+                let (degree, accidental, is_upper) = degree_from_pair(components.next().unwrap());
+
+                let mut target = RomanNumeral::new(degree, if is_upper { TriadQuality::Major } else { TriadQuality::Minor });
+                target.accidental = accidental;
+
+                result.secondary = Some(Box::new(target));
+            }
+            Rule::hat => {
+                result.inversion = components.next().unwrap().as_str().parse::<u8>()?;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(result)
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::*;
+    use pretty_assertions::assert_eq;
+
+    fn names(chords: &[Chord]) -> Vec<String> {
+        chords.iter().map(|c| c.name()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let numeral = RomanNumeral::parse("V7/V").unwrap();
+
+        assert_eq!(numeral.degree, 5);
+        assert_eq!(numeral.seventh, Some(SeventhQuality::Minor));
+        assert_eq!(numeral.secondary, Some(Box::new(RomanNumeral::new(5, TriadQuality::Major))));
+
+        let numeral = RomanNumeral::parse("bVImaj7").unwrap();
+
+        assert_eq!(numeral.degree, 6);
+        assert_eq!(numeral.accidental, -1);
+        assert_eq!(numeral.seventh, Some(SeventhQuality::Major));
+
+        assert_eq!(RomanNumeral::parse("vii°7").unwrap().seventh, Some(SeventhQuality::Diminished));
+        assert_eq!(RomanNumeral::parse("viiø7").unwrap().seventh, Some(SeventhQuality::Minor));
+        assert_eq!(RomanNumeral::parse("I^1").unwrap().inversion, 1);
+
+        assert!(RomanNumeral::parse("VIII").is_err());
+        assert!(RomanNumeral::parse("X7").is_err());
+    }
+
+    #[test]
+    fn test_to_chords() {
+        let key = Key::major(C);
+        let numerals = RomanNumeral::parse_progression("ii7 V7/V bVImaj7 I6").unwrap();
+
+        assert_eq!(names(&numerals.iter().map(|n| n.to_chord(&key).unwrap()).collect::<Vec<_>>()), vec!["Dm7", "D7", "A♭maj7", "C(add6)"]);
+
+        let key = Key::minor(A);
+        let numerals = RomanNumeral::parse_progression("i - iv | V7, viiø7/iv i^2").unwrap();
+
+        assert_eq!(names(&numerals.iter().map(|n| n.to_chord(&key).unwrap()).collect::<Vec<_>>()), vec!["Am", "Dm", "E7", "C♯m7(♭5)", "Am"]);
+        assert_eq!(numerals[4].to_chord(&key).unwrap().inversion(), 2);
+
+        let key = Key::new(C, Scale::MajorPentatonic);
+
+        assert!(RomanNumeral::parse("vi").unwrap().to_chord(&key).is_err());
+        assert!(RomanNumeral::parse("I").unwrap().to_chord(&key).is_err());
+
+        // Chromatic numerals at the ends of the circle of fifths cannot be spelled.
+        assert!(RomanNumeral::parse("bIV").unwrap().to_chord(&Key::parse("Fbb").unwrap()).is_err());
+        assert!(RomanNumeral::parse("#VII").unwrap().to_chord(&Key::parse("B##").unwrap()).is_err());
+        assert!(RomanNumeral::parse("bIV").unwrap().to_chord(&Key::parse("Fb").unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_analysis() {
+        let key = Key::major(C);
+        let chords = ["Dm7", "D7", "G7", "Abmaj7", "Cmaj7", "F#7"].map(|c| Chord::parse(c).unwrap());
+        let analysis = Analysis::all(&key, &chords);
+
+        assert_eq!(
+            analysis.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+            vec![
+                "Dm7: ii7 (subdominant)",
+                "D7: V7/V (secondary dominant)",
+                "G7: V7 (dominant)",
+                "A♭maj7: ♭VImaj7 (borrowed)",
+                "Cmaj7: Imaj7 (tonic)",
+                "F♯7: ♯IV7 (chromatic)",
+            ]
+        );
+
+        let key = Key::minor(A);
+        let chords = ["Am", "E7", "Bm7b5", "D", "G#dim"].map(|c| Chord::parse(c).unwrap());
+        let analysis = Analysis::all(&key, &chords);
+
+        assert_eq!(
+            analysis.iter().map(|a| a.to_string()).collect::<Vec<_>>(),
+            vec!["Am: i (tonic)", "E7: V7 (dominant)", "Bm7(♭5): iiø7 (subdominant)", "D: IV (borrowed)", "G♯dim: vii°7 (dominant)"]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::major(EFlat);
+
+        for symbol in ["I", "ii7", "iii", "IVmaj7", "V7", "vi", "viiø7", "♭VII", "V7/ii", "vii°7/V", "III+", "I^1"] {
+            let numeral = RomanNumeral::parse(symbol).unwrap();
+
+            assert_eq!(numeral.name(), symbol);
+
+            if numeral.secondary.is_none() {
+                assert_eq!(RomanNumeral::from_chord(&key, &numeral.to_chord(&key).unwrap()), Some(numeral));
+            }
+        }
+    }
+}
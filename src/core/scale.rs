@@ -0,0 +1,780 @@
+//! A module for working with scales and modes.
+
+use crate::core::{
+    base::{HasDescription, HasStaticName, Parsable, Res},
+    interval::Interval,
+    known_chord::HasRelativeScale,
+    note::Note,
+};
+
+// Traits.
+
+/// A trait for types that have a known scale.
+pub trait HasKnownScale {
+    /// Returns the known scale of the type (usually a [`KnownChord`][crate::core::known_chord::KnownChord]).
+    fn known_scale(&self) -> Scale;
+}
+
+// Enum.
+
+/// An enum representing a scale (or mode).
+///
+/// A [`Scale`] has no root, so it is only a set of intervals.  Use [`Scale::notes`]
+/// to build the scale from a given root [`Note`].
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Scale {
+    /// The ionian mode (first mode of the major scale).
+    Ionian,
+    /// The dorian mode (second mode of the major scale).
+    Dorian,
+    /// The phrygian mode (third mode of the major scale).
+    Phrygian,
+    /// The lydian mode (fourth mode of the major scale).
+    Lydian,
+    /// The mixolydian mode (fifth mode of the major scale).
+    Mixolydian,
+    /// The aeolian mode (sixth mode of the major scale).
+    Aeolian,
+    /// The locrian mode (seventh mode of the major scale).
+    Locrian,
+
+    /// The melodic minor scale (first mode of the melodic minor scale).
+    MelodicMinor,
+    /// The dorian ♭2 mode (second mode of the melodic minor scale).
+    DorianFlat2,
+    /// The lydian augmented mode (third mode of the melodic minor scale).
+    LydianAugmented,
+    /// The lydian dominant mode (fourth mode of the melodic minor scale).
+    LydianDominant,
+    /// The mixolydian ♭6 mode (fifth mode of the melodic minor scale).
+    MixolydianFlat6,
+    /// The locrian ♮2 mode (sixth mode of the melodic minor scale).
+    LocrianNatural2,
+    /// The altered scale (seventh mode of the melodic minor scale).
+    Altered,
+
+    /// The harmonic minor scale (first mode of the harmonic minor scale).
+    HarmonicMinor,
+    /// The locrian ♮6 mode (second mode of the harmonic minor scale).
+    LocrianNatural6,
+    /// The ionian augmented mode (third mode of the harmonic minor scale).
+    IonianAugmented,
+    /// The dorian ♯4 mode (fourth mode of the harmonic minor scale).
+    DorianSharp4,
+    /// The phrygian dominant mode (fifth mode of the harmonic minor scale).
+    PhrygianDominant,
+    /// The lydian ♯2 mode (sixth mode of the harmonic minor scale).
+    LydianSharp2,
+    /// The ultralocrian mode (seventh mode of the harmonic minor scale).
+    Ultralocrian,
+
+    /// The harmonic major scale (first mode of the harmonic major scale).
+    HarmonicMajor,
+    /// The dorian ♭5 mode (second mode of the harmonic major scale).
+    DorianFlat5,
+    /// The phrygian ♭4 mode (third mode of the harmonic major scale).
+    PhrygianFlat4,
+    /// The lydian ♭3 mode (fourth mode of the harmonic major scale).
+    LydianFlat3,
+    /// The mixolydian ♭2 mode (fifth mode of the harmonic major scale).
+    MixolydianFlat2,
+    /// The lydian augmented ♯2 mode (sixth mode of the harmonic major scale).
+    LydianAugmentedSharp2,
+    /// The locrian 𝄫7 mode (seventh mode of the harmonic major scale).
+    LocrianDoubleFlat7,
+
+    /// The whole/half diminished scale.
+    WholeHalfDiminished,
+    /// The half/whole diminished scale.
+    HalfWholeDiminished,
+
+    /// The whole tone scale.
+    WholeTone,
+
+    /// The major pentatonic scale.
+    MajorPentatonic,
+    /// The minor pentatonic scale.
+    MinorPentatonic,
+
+    /// The (minor) blues scale.
+    Blues,
+    /// The major blues scale.
+    MajorBlues,
+
+    /// The bebop dominant scale.
+    BebopDominant,
+    /// The bebop major scale.
+    BebopMajor,
+    /// The bebop dorian scale.
+    BebopDorian,
+}
+
+// Impls.
+
+impl Scale {
+    /// Returns the notes of the scale, starting from the given root.
+    pub fn notes(&self, root: Note) -> Vec<Note> {
+        self.relative_scale().into_iter().map(|i| root + i).collect()
+    }
+
+    /// Returns the parent scale, and the (zero-based) degree of the parent on which this mode starts.
+    ///
+    /// Only the modes of the seven note parent scales (major, melodic minor, harmonic minor,
+    /// and harmonic major) have a parent.
+    pub fn parent(&self) -> Option<(Scale, usize)> {
+        MODE_FAMILIES.iter().find_map(|family| family.iter().position(|s| s == self).map(|k| (family[0], k)))
+    }
+}
+
+impl HasStaticName for Scale {
+    fn static_name(&self) -> &'static str {
+        match self {
+            Scale::Ionian => "ionian",
+            Scale::Dorian => "dorian",
+            Scale::Phrygian => "phrygian",
+            Scale::Lydian => "lydian",
+            Scale::Mixolydian => "mixolydian",
+            Scale::Aeolian => "aeolian",
+            Scale::Locrian => "locrian",
+
+            Scale::MelodicMinor => "melodic minor",
+            Scale::DorianFlat2 => "dorian ♭2",
+            Scale::LydianAugmented => "lydian augmented",
+            Scale::LydianDominant => "lydian dominant",
+            Scale::MixolydianFlat6 => "mixolydian ♭6",
+            Scale::LocrianNatural2 => "locrian ♮2",
+            Scale::Altered => "altered",
+
+            Scale::HarmonicMinor => "harmonic minor",
+            Scale::LocrianNatural6 => "locrian ♮6",
+            Scale::IonianAugmented => "ionian augmented",
+            Scale::DorianSharp4 => "dorian ♯4",
+            Scale::PhrygianDominant => "phrygian dominant",
+            Scale::LydianSharp2 => "lydian ♯2",
+            Scale::Ultralocrian => "ultralocrian",
+
+            Scale::HarmonicMajor => "harmonic major",
+            Scale::DorianFlat5 => "dorian ♭5",
+            Scale::PhrygianFlat4 => "phrygian ♭4",
+            Scale::LydianFlat3 => "lydian ♭3",
+            Scale::MixolydianFlat2 => "mixolydian ♭2",
+            Scale::LydianAugmentedSharp2 => "lydian augmented ♯2",
+            Scale::LocrianDoubleFlat7 => "locrian 𝄫7",
+
+            Scale::WholeHalfDiminished => "whole/half diminished",
+            Scale::HalfWholeDiminished => "half/whole diminished",
+
+            Scale::WholeTone => "whole tone",
+
+            Scale::MajorPentatonic => "major pentatonic",
+            Scale::MinorPentatonic => "minor pentatonic",
+
+            Scale::Blues => "blues",
+            Scale::MajorBlues => "major blues",
+
+            Scale::BebopDominant => "bebop dominant",
+            Scale::BebopMajor => "bebop major",
+            Scale::BebopDorian => "bebop dorian",
+        }
+    }
+}
+
+impl HasDescription for Scale {
+    fn description(&self) -> &'static str {
+        match self {
+            Scale::Ionian => "ionian, major, first mode of major scale",
+            Scale::Dorian => "dorian, second mode of major scale, minor with natural six",
+            Scale::Phrygian => "phrygian, third mode of major scale, minor with flat two",
+            Scale::Lydian => "lydian, fourth mode of major scale, major with sharp four",
+            Scale::Mixolydian => "mixolydian, fifth mode of major scale, major with flat seven",
+            Scale::Aeolian => "aeolian, natural minor, sixth mode of major scale",
+            Scale::Locrian => "locrian, seventh mode of major scale, minor with flat two and flat five",
+
+            Scale::MelodicMinor => "melodic minor, jazz minor, major with flat third",
+            Scale::DorianFlat2 => "dorian flat 2, phrygian natural six, second mode of melodic minor",
+            Scale::LydianAugmented => "lydian augmented, major with sharp four and five, third mode of melodic minor",
+            Scale::LydianDominant => "lydian dominant, lyxian, major with sharp four and flat seven, fourth mode of melodic minor",
+            Scale::MixolydianFlat6 => "mixolydian flat 6, aeolian dominant, fifth mode of melodic minor",
+            Scale::LocrianNatural2 => "locrian natural 2, half diminished, sixth mode of melodic minor",
+            Scale::Altered => "altered, super locrian, diminished whole tone, seventh mode of melodic minor, melodic minor up a half step",
+
+            Scale::HarmonicMinor => "harmonic minor, natural minor with major seven",
+            Scale::LocrianNatural6 => "locrian natural 6, second mode of harmonic minor",
+            Scale::IonianAugmented => "ionian augmented, major with sharp five, third mode of harmonic minor",
+            Scale::DorianSharp4 => "dorian sharp 4, ukrainian dorian, fourth mode of harmonic minor",
+            Scale::PhrygianDominant => "phrygian dominant, spanish phrygian, fifth mode of harmonic minor",
+            Scale::LydianSharp2 => "lydian sharp 2, sixth mode of harmonic minor",
+            Scale::Ultralocrian => "ultralocrian, altered diminished, seventh mode of harmonic minor",
+
+            Scale::HarmonicMajor => "harmonic major, major with flat six",
+            Scale::DorianFlat5 => "dorian flat 5, second mode of harmonic major",
+            Scale::PhrygianFlat4 => "phrygian flat 4, third mode of harmonic major",
+            Scale::LydianFlat3 => "lydian flat 3, lydian minor, fourth mode of harmonic major",
+            Scale::MixolydianFlat2 => "mixolydian flat 2, fifth mode of harmonic major",
+            Scale::LydianAugmentedSharp2 => "lydian augmented sharp 2, sixth mode of harmonic major",
+            Scale::LocrianDoubleFlat7 => "locrian double flat 7, seventh mode of harmonic major",
+
+            Scale::WholeHalfDiminished => "fully diminished (whole first), whole/half/whole diminished",
+            Scale::HalfWholeDiminished => "dominant diminished (half first), half/whole/half diminished",
+
+            Scale::WholeTone => "whole tone, augmented dominant",
+
+            Scale::MajorPentatonic => "major pentatonic, major without four and seven",
+            Scale::MinorPentatonic => "minor pentatonic, natural minor without two and six",
+
+            Scale::Blues => "blues, minor pentatonic with flat five",
+            Scale::MajorBlues => "major blues, major pentatonic with flat three",
+
+            Scale::BebopDominant => "bebop dominant, mixolydian with passing major seven",
+            Scale::BebopMajor => "bebop major, major with passing sharp five",
+            Scale::BebopDorian => "bebop dorian, dorian with passing major three",
+        }
+    }
+}
+
+impl HasRelativeScale for Scale {
+    fn relative_scale(&self) -> Vec<Interval> {
+        match self {
+            Scale::Ionian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::Dorian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::Phrygian => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::Lydian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::Mixolydian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::Aeolian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::Locrian => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+
+            Scale::MelodicMinor => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::DorianFlat2 => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::LydianAugmented => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::AugmentedFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::LydianDominant => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::MixolydianFlat6 => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::LocrianNatural2 => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::Altered => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::DiminishedFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+
+            Scale::HarmonicMinor => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::LocrianNatural6 => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::IonianAugmented => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::AugmentedFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::DorianSharp4 => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::PhrygianDominant => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::LydianSharp2 => vec![
+                Interval::PerfectUnison,
+                Interval::AugmentedSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::Ultralocrian => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::DiminishedFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::DiminishedSeventh,
+            ],
+
+            Scale::HarmonicMajor => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::DorianFlat5 => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::PhrygianFlat4 => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::DiminishedFourth,
+                Interval::PerfectFifth,
+                Interval::MinorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::LydianFlat3 => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::MixolydianFlat2 => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+            Scale::LydianAugmentedSharp2 => vec![
+                Interval::PerfectUnison,
+                Interval::AugmentedSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::AugmentedFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::LocrianDoubleFlat7 => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::DiminishedSeventh,
+            ],
+
+            Scale::WholeHalfDiminished => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::MinorSixth,
+                Interval::DiminishedSeventh,
+                Interval::MajorSeventh,
+            ],
+            Scale::HalfWholeDiminished => vec![
+                Interval::PerfectUnison,
+                Interval::MinorSecond,
+                Interval::MinorThird,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+
+            Scale::WholeTone => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::AugmentedFourth,
+                Interval::AugmentedFifth,
+                Interval::AugmentedSixth,
+            ],
+
+            Scale::MajorPentatonic => vec![Interval::PerfectUnison, Interval::MajorSecond, Interval::MajorThird, Interval::PerfectFifth, Interval::MajorSixth],
+            Scale::MinorPentatonic => vec![Interval::PerfectUnison, Interval::MinorThird, Interval::PerfectFourth, Interval::PerfectFifth, Interval::MinorSeventh],
+
+            Scale::Blues => vec![
+                Interval::PerfectUnison,
+                Interval::MinorThird,
+                Interval::PerfectFourth,
+                Interval::DiminishedFifth,
+                Interval::PerfectFifth,
+                Interval::MinorSeventh,
+            ],
+            Scale::MajorBlues => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::MajorThird,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+            ],
+
+            Scale::BebopDominant => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+                Interval::MajorSeventh,
+            ],
+            Scale::BebopMajor => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::AugmentedFifth,
+                Interval::MajorSixth,
+                Interval::MajorSeventh,
+            ],
+            Scale::BebopDorian => vec![
+                Interval::PerfectUnison,
+                Interval::MajorSecond,
+                Interval::MinorThird,
+                Interval::MajorThird,
+                Interval::PerfectFourth,
+                Interval::PerfectFifth,
+                Interval::MajorSixth,
+                Interval::MinorSeventh,
+            ],
+        }
+    }
+}
+
+impl Parsable for Scale {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        // Normalize the input, so that `Harmonic Minor`, `harmonic-minor`, and `harmonicminor` are all the same.
+        let normalized = normalize_scale_name(symbol);
+
+        // Check the static names first, and then some common aliases.
+        if let Some(scale) = ALL_SCALES.iter().find(|s| normalize_scale_name(s.static_name()) == normalized) {
+            return Ok(*scale);
+        }
+
+        let scale = match normalized.as_str() {
+            "major" => Scale::Ionian,
+            "minor" | "naturalminor" => Scale::Aeolian,
+            "jazzminor" => Scale::MelodicMinor,
+            "phrygian6" | "phrygiannatural6" => Scale::DorianFlat2,
+            "lydian#5" => Scale::LydianAugmented,
+            "lydianb7" | "lydian7" | "overtone" => Scale::LydianDominant,
+            "aeoliandominant" | "mixolydianb13" => Scale::MixolydianFlat6,
+            "halfdiminished" | "locrian2" => Scale::LocrianNatural2,
+            "superlocrian" | "alt" | "diminishedwholetone" => Scale::Altered,
+            "locrian6" => Scale::LocrianNatural6,
+            "ionian#5" => Scale::IonianAugmented,
+            "spanishphrygian" | "phrygianmajor" => Scale::PhrygianDominant,
+            "alteredb7" | "superlocrianbb7" => Scale::Ultralocrian,
+            "lydianminor" => Scale::LydianFlat3,
+            "wholehalf" | "diminished" => Scale::WholeHalfDiminished,
+            "halfwhole" | "dominantdiminished" => Scale::HalfWholeDiminished,
+            "minorblues" => Scale::Blues,
+            "bebop" => Scale::BebopDominant,
+            "bebopminor" => Scale::BebopDorian,
+            _ => return Err(crate::core::base::Err::msg(format!("Unknown scale `{}`.", symbol))),
+        };
+
+        Ok(scale)
+    }
+}
+
+// Helpers.
+
+/// Normalizes a scale name for comparison (lowercase, no whitespace or separators, and ASCII accidentals).
+fn normalize_scale_name(name: &str) -> String {
+    name.to_lowercase()
+        .replace('♭', "b")
+        .replace('♯', "#")
+        .replace('♮', "")
+        .replace('𝄫', "bb")
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_' && *c != '/')
+        .collect()
+}
+
+// Statics.
+
+/// All known [`Scale`]s.
+pub static ALL_SCALES: [Scale; 38] = [
+    Scale::Ionian,
+    Scale::Dorian,
+    Scale::Phrygian,
+    Scale::Lydian,
+    Scale::Mixolydian,
+    Scale::Aeolian,
+    Scale::Locrian,
+    Scale::MelodicMinor,
+    Scale::DorianFlat2,
+    Scale::LydianAugmented,
+    Scale::LydianDominant,
+    Scale::MixolydianFlat6,
+    Scale::LocrianNatural2,
+    Scale::Altered,
+    Scale::HarmonicMinor,
+    Scale::LocrianNatural6,
+    Scale::IonianAugmented,
+    Scale::DorianSharp4,
+    Scale::PhrygianDominant,
+    Scale::LydianSharp2,
+    Scale::Ultralocrian,
+    Scale::HarmonicMajor,
+    Scale::DorianFlat5,
+    Scale::PhrygianFlat4,
+    Scale::LydianFlat3,
+    Scale::MixolydianFlat2,
+    Scale::LydianAugmentedSharp2,
+    Scale::LocrianDoubleFlat7,
+    Scale::WholeHalfDiminished,
+    Scale::HalfWholeDiminished,
+    Scale::WholeTone,
+    Scale::MajorPentatonic,
+    Scale::MinorPentatonic,
+    Scale::Blues,
+    Scale::MajorBlues,
+    Scale::BebopDominant,
+    Scale::BebopMajor,
+    Scale::BebopDorian,
+];
+
+/// The modes of the seven note parent scales, in order (the parent scale is always first).
+static MODE_FAMILIES: [[Scale; 7]; 4] = [
+    [Scale::Ionian, Scale::Dorian, Scale::Phrygian, Scale::Lydian, Scale::Mixolydian, Scale::Aeolian, Scale::Locrian],
+    [
+        Scale::MelodicMinor,
+        Scale::DorianFlat2,
+        Scale::LydianAugmented,
+        Scale::LydianDominant,
+        Scale::MixolydianFlat6,
+        Scale::LocrianNatural2,
+        Scale::Altered,
+    ],
+    [
+        Scale::HarmonicMinor,
+        Scale::LocrianNatural6,
+        Scale::IonianAugmented,
+        Scale::DorianSharp4,
+        Scale::PhrygianDominant,
+        Scale::LydianSharp2,
+        Scale::Ultralocrian,
+    ],
+    [
+        Scale::HarmonicMajor,
+        Scale::DorianFlat5,
+        Scale::PhrygianFlat4,
+        Scale::LydianFlat3,
+        Scale::MixolydianFlat2,
+        Scale::LydianAugmentedSharp2,
+        Scale::LocrianDoubleFlat7,
+    ],
+];
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::note::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_notes() {
+        assert_eq!(Scale::Dorian.notes(D), vec![D, E, F, G, A, B, CFive]);
+        assert_eq!(Scale::HarmonicMinor.notes(F), vec![F, G, AFlat, BFlat, CFive, DFlatFive, EFive]);
+        assert_eq!(Scale::Altered.notes(G), vec![G, AFlat, BFlat, CFlatFive, DFlatFive, EFlatFive, FFive]);
+        assert_eq!(Scale::PhrygianDominant.notes(E), vec![E, F, GSharp, A, B, CFive, DFive]);
+        assert_eq!(Scale::WholeTone.notes(C), vec![C, D, E, FSharp, GSharp, ASharp]);
+        assert_eq!(Scale::MinorPentatonic.notes(A), vec![A, CFive, DFive, EFive, GFive]);
+        assert_eq!(Scale::Blues.notes(C), vec![C, EFlat, F, GFlat, G, BFlat]);
+        assert_eq!(Scale::BebopDominant.notes(G), vec![G, A, B, CFive, DFive, EFive, FFive, FSharpFive]);
+        assert_eq!(Scale::HalfWholeDiminished.notes(C), vec![C, DFlat, EFlat, E, FSharp, G, A, BFlat]);
+    }
+
+    #[test]
+    fn test_modes() {
+        use crate::core::pitch::HasPitch;
+
+        // Every mode of a parent scale should contain the same pitches as the parent.
+        for family in MODE_FAMILIES.iter() {
+            let parent = family[0];
+            let parent_notes = parent.notes(C);
+
+            for (k, mode) in family.iter().enumerate() {
+                let mut expected = parent_notes.iter().map(|n| n.pitch()).collect::<Vec<_>>();
+                let mut actual = mode.notes(parent_notes[k]).iter().map(|n| n.pitch()).collect::<Vec<_>>();
+
+                expected.sort();
+                actual.sort();
+
+                assert_eq!(actual, expected, "{} is not mode {} of {}", mode.static_name(), k + 1, parent.static_name());
+                assert_eq!(mode.parent(), Some((parent, k)));
+            }
+        }
+
+        assert_eq!(Scale::WholeTone.parent(), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Scale::parse("dorian").unwrap(), Scale::Dorian);
+        assert_eq!(Scale::parse("Harmonic Minor").unwrap(), Scale::HarmonicMinor);
+        assert_eq!(Scale::parse("harmonic-major").unwrap(), Scale::HarmonicMajor);
+        assert_eq!(Scale::parse("lydian b7").unwrap(), Scale::LydianDominant);
+        assert_eq!(Scale::parse("dorian ♭2").unwrap(), Scale::DorianFlat2);
+        assert_eq!(Scale::parse("dorian b2").unwrap(), Scale::DorianFlat2);
+        assert_eq!(Scale::parse("super locrian").unwrap(), Scale::Altered);
+        assert_eq!(Scale::parse("whole/half diminished").unwrap(), Scale::WholeHalfDiminished);
+        assert_eq!(Scale::parse("major").unwrap(), Scale::Ionian);
+        assert!(Scale::parse("nonsense").is_err());
+
+        for scale in ALL_SCALES.iter() {
+            assert_eq!(Scale::parse(scale.static_name()).unwrap(), *scale);
+        }
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(Scale::LocrianNatural2.static_name(), "locrian ♮2");
+        assert_eq!(
+            Scale::Altered.description(),
+            "altered, super locrian, diminished whole tone, seventh mode of melodic minor, melodic minor up a half step"
+        );
+    }
+}
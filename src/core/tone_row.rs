@@ -0,0 +1,313 @@
+//! A module for twelve-tone rows, and their matrices.
+//!
+//! The row forms are labeled relative to the row itself (i.e., `P0` is the row as given), so `P5` is the row transposed
+//! up five semitones, `I0` is its inversion around the first pitch, `R0` is its retrograde, and `RI0` is the retrograde of `I0`.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::core::{
+    base::{HasStaticName, Parsable, Res},
+    named_pitch::NamedPitch,
+    note::Note,
+    pitch::{HasPitch, Pitch},
+};
+
+// Enum.
+
+/// The kind of a row form.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+pub enum RowKind {
+    /// The prime form (`P`).
+    Prime,
+    /// The inversion (`I`).
+    Inversion,
+    /// The retrograde (`R`).
+    Retrograde,
+    /// The retrograde inversion (`RI`).
+    RetrogradeInversion,
+}
+
+// Structs.
+
+/// The label of a row form (e.g., `P0`, `I7`, `R11`, or `RI3`).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, Ord, PartialOrd)]
+pub struct RowLabel {
+    /// The kind of the row form.
+    pub kind: RowKind,
+    /// The transposition of the row form (in semitones, relative to the first pitch of the row).
+    pub transposition: u8,
+}
+
+/// A twelve-tone row (i.e., an ordering of all twelve [`Pitch`]es).
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct ToneRow {
+    /// The pitches of the row.
+    pitches: [Pitch; 12],
+}
+
+// Impls.
+
+impl HasStaticName for RowKind {
+    fn static_name(&self) -> &'static str {
+        match self {
+            RowKind::Prime => "P",
+            RowKind::Inversion => "I",
+            RowKind::Retrograde => "R",
+            RowKind::RetrogradeInversion => "RI",
+        }
+    }
+}
+
+impl RowLabel {
+    /// Creates a new [`RowLabel`].
+    pub fn new(kind: RowKind, transposition: u8) -> Self {
+        Self { kind, transposition: transposition % 12 }
+    }
+}
+
+impl Display for RowLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.kind.static_name(), self.transposition)
+    }
+}
+
+impl Parsable for RowLabel {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let symbol = symbol.trim().to_uppercase();
+
+        let (kind, transposition) = if let Some(t) = symbol.strip_prefix("RI") {
+            (RowKind::RetrogradeInversion, t)
+        } else if let Some(t) = symbol.strip_prefix('P') {
+            (RowKind::Prime, t)
+        } else if let Some(t) = symbol.strip_prefix('I') {
+            (RowKind::Inversion, t)
+        } else if let Some(t) = symbol.strip_prefix('R') {
+            (RowKind::Retrograde, t)
+        } else {
+            return Err(anyhow::Error::msg(format!("Invalid row form `{}` (expected `P`, `I`, `R`, or `RI`, followed by 0 to 11).", symbol)));
+        };
+
+        match transposition.parse::<u8>() {
+            Ok(t) if t < 12 => Ok(RowLabel::new(kind, t)),
+            _ => Err(anyhow::Error::msg(format!("Invalid row form transposition `{}` (expected 0 to 11).", transposition))),
+        }
+    }
+}
+
+impl ToneRow {
+    /// Creates a new [`ToneRow`], if the pitches are a permutation of all twelve pitches.
+    pub fn new(pitches: &[Pitch]) -> Res<Self> {
+        let pitches: [Pitch; 12] = pitches
+            .try_into()
+            .map_err(|_| anyhow::Error::msg(format!("A tone row must have exactly 12 pitches (got {}).", pitches.len())))?;
+
+        for (k, pitch) in pitches.iter().enumerate() {
+            if pitches[..k].contains(pitch) {
+                return Err(anyhow::Error::msg(format!("A tone row must contain each pitch once (`{}` is repeated).", name(*pitch))));
+            }
+        }
+
+        Ok(Self { pitches })
+    }
+
+    /// Returns the pitches of the row.
+    pub fn pitches(&self) -> &[Pitch; 12] {
+        &self.pitches
+    }
+
+    /// Returns the pitches of the row form with the given label.
+    pub fn form(&self, label: RowLabel) -> [Pitch; 12] {
+        let first = self.pitches[0] as u8;
+        let t = label.transposition;
+
+        let mut result = self.pitches.map(|p| {
+            let interval = (p as u8 + 12 - first) % 12;
+
+            let pitch_class = match label.kind {
+                RowKind::Prime | RowKind::Retrograde => first + t + interval,
+                RowKind::Inversion | RowKind::RetrogradeInversion => first + t + 12 - interval,
+            };
+
+            Pitch::try_from(pitch_class % 12).unwrap()
+        });
+
+        if matches!(label.kind, RowKind::Retrograde | RowKind::RetrogradeInversion) {
+            result.reverse();
+        }
+
+        result
+    }
+
+    /// Returns the label of the row form that the given pitches are, if any.
+    pub fn label_of(&self, pitches: &[Pitch]) -> Option<RowLabel> {
+        [RowKind::Prime, RowKind::Inversion, RowKind::Retrograde, RowKind::RetrogradeInversion]
+            .into_iter()
+            .flat_map(|kind| (0..12).map(move |t| RowLabel::new(kind, t)))
+            .find(|label| self.form(*label) == pitches)
+    }
+
+    /// Returns the matrix of the row (the prime forms are the rows, and the inversions are the columns).
+    ///
+    /// The first row is `P0`, and the first column is `I0`.
+    pub fn matrix(&self) -> [[Pitch; 12]; 12] {
+        self.form(RowLabel::new(RowKind::Inversion, 0))
+            .map(|p| self.form(RowLabel::new(RowKind::Prime, self.transposition_of(p))))
+    }
+
+    /// Returns the matrix as text, with the labels of the row forms around the edges.
+    pub fn table(&self) -> String {
+        self.render(" ", true)
+    }
+
+    /// Returns the matrix as CSV, with the labels of the row forms around the edges.
+    pub fn csv(&self) -> String {
+        self.render(",", false)
+    }
+
+    /// Returns the transposition (relative to the first pitch of the row) of the form starting on the given pitch.
+    fn transposition_of(&self, pitch: Pitch) -> u8 {
+        (pitch as u8 + 12 - self.pitches[0] as u8) % 12
+    }
+
+    /// Renders the matrix, with the labels of the row forms around the edges.
+    fn render(&self, separator: &str, pad: bool) -> String {
+        let matrix = self.matrix();
+        let labels = |kind: RowKind| matrix.iter().map(move |row| RowLabel::new(kind, self.transposition_of(row[0])).to_string());
+        let columns = |kind: RowKind| matrix[0].iter().map(move |p| RowLabel::new(kind, self.transposition_of(*p)).to_string());
+
+        let mut lines = vec![];
+
+        lines.push([String::new()].into_iter().chain(columns(RowKind::Inversion)).chain([String::new()]).collect::<Vec<_>>());
+
+        for (row, (left, right)) in matrix.iter().zip(labels(RowKind::Prime).zip(labels(RowKind::Retrograde))) {
+            lines.push([left].into_iter().chain(row.iter().map(|p| name(*p).to_owned())).chain([right]).collect());
+        }
+
+        lines.push([String::new()].into_iter().chain(columns(RowKind::RetrogradeInversion)).chain([String::new()]).collect());
+
+        let width = lines.iter().flatten().map(|cell| cell.chars().count()).max().unwrap_or(0);
+
+        lines
+            .into_iter()
+            .map(|cells| {
+                let line = cells
+                    .into_iter()
+                    .map(|cell| if pad { format!("{:width$}", cell, width = width) } else { cell })
+                    .collect::<Vec<_>>()
+                    .join(separator);
+
+                if pad {
+                    line.trim_end().to_owned()
+                } else {
+                    line
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Display for ToneRow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pitches.iter().map(|p| name(*p)).collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl Parsable for ToneRow {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let pitches = symbol
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|s| !s.is_empty())
+            .map(|s| Note::parse(s).map(|n| n.pitch()))
+            .collect::<Res<Vec<_>>>()?;
+
+        Self::new(&pitches)
+    }
+}
+
+// Helpers.
+
+/// Returns the name of the pitch (e.g., `E♭`).
+fn name(pitch: Pitch) -> &'static str {
+    NamedPitch::from(pitch).static_name()
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    // The row of Berg's Violin Concerto.
+    const BERG: &str = "G Bb D F# A C E G# B C# Eb F";
+
+    fn names(pitches: &[Pitch]) -> String {
+        pitches.iter().map(|p| name(*p)).collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn test_forms() {
+        let row = ToneRow::parse(BERG).unwrap();
+
+        assert_eq!(row.to_string(), "G B♭ D G♭ A C E A♭ B D♭ E♭ F");
+        assert_eq!(names(&row.form(RowLabel::parse("P0").unwrap())), "G B♭ D G♭ A C E A♭ B D♭ E♭ F");
+        assert_eq!(names(&row.form(RowLabel::parse("P5").unwrap())), "C E♭ G B D F A D♭ E G♭ A♭ B♭");
+        assert_eq!(names(&row.form(RowLabel::parse("I0").unwrap())), "G E C A♭ F D B♭ G♭ E♭ D♭ B A");
+        assert_eq!(names(&row.form(RowLabel::parse("I3").unwrap())), "B♭ G E♭ B A♭ F D♭ A G♭ E D C");
+        assert_eq!(names(&row.form(RowLabel::parse("R0").unwrap())), "F E♭ D♭ B A♭ E C A G♭ D B♭ G");
+        assert_eq!(names(&row.form(RowLabel::parse("RI0").unwrap())), "A B D♭ E♭ G♭ B♭ D F A♭ C E G");
+
+        assert_eq!(row.label_of(&row.form(RowLabel::parse("RI7").unwrap())), Some(RowLabel::new(RowKind::RetrogradeInversion, 7)));
+        assert_eq!(row.label_of(&row.form(RowLabel::parse("R7").unwrap())).unwrap().to_string(), "R7");
+        assert_eq!(row.label_of(&[Pitch::C; 12]), None);
+    }
+
+    #[test]
+    fn test_matrix() {
+        let row = ToneRow::parse(BERG).unwrap();
+        let matrix = row.matrix();
+
+        assert_eq!(matrix[0], row.form(RowLabel::new(RowKind::Prime, 0)));
+        assert_eq!(matrix.map(|r| r[0]), row.form(RowLabel::new(RowKind::Inversion, 0)));
+
+        // The diagonal is the first pitch.
+        assert!((0..12).all(|k| matrix[k][k] == Pitch::G));
+
+        let table = row.table();
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 14);
+        assert_eq!(lines[0].split_whitespace().take(3).collect::<Vec<_>>(), vec!["I0", "I3", "I7"]);
+        assert_eq!(lines[1].split_whitespace().collect::<Vec<_>>().join(" "), "P0 G B♭ D G♭ A C E A♭ B D♭ E♭ F R0");
+        assert_eq!(lines[2].split_whitespace().collect::<Vec<_>>().join(" "), "P9 E G B E♭ G♭ A D♭ F A♭ B♭ C D R9");
+        assert_eq!(lines[13].split_whitespace().take(3).collect::<Vec<_>>(), vec!["RI0", "RI3", "RI7"]);
+
+        let csv = row.csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], ",I0,I3,I7,I11,I2,I5,I9,I1,I4,I6,I8,I10,");
+        assert_eq!(lines[1], "P0,G,B♭,D,G♭,A,C,E,A♭,B,D♭,E♭,F,R0");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(ToneRow::parse("C C# D D# E F F# G G# A A# B").is_ok());
+        assert!(ToneRow::parse("C, Db, D, Eb, E, F, Gb, G, Ab, A, Bb, B").is_ok());
+        assert!(ToneRow::parse("C C# D D# E F F# G G# A A#").is_err());
+        assert!(ToneRow::parse("C C# D D# E F F# G G# A A# Cb5").is_ok());
+        assert!(ToneRow::parse("C C# D D# E F F# G G# A A# B#").is_err());
+        assert!(ToneRow::parse("C C# D D# E F F# G G# A A# X").is_err());
+
+        assert_eq!(RowLabel::parse("ri11").unwrap(), RowLabel::new(RowKind::RetrogradeInversion, 11));
+        assert_eq!(RowLabel::parse("P0").unwrap().to_string(), "P0");
+        assert!(RowLabel::parse("P12").is_err());
+        assert!(RowLabel::parse("X1").is_err());
+    }
+}
@@ -0,0 +1,600 @@
+//! A module for tuning systems (temperaments) and the reference pitch.
+//!
+//! A [`Tuning`] is passed to [`HasFrequency::frequency_in`](crate::core::pitch::HasFrequency::frequency_in) (and to playback and audio
+//! analysis), and defaults to twelve-tone equal temperament at A4 = 440 Hz.
+
+use std::fmt::{Display, Error, Formatter};
+
+use crate::core::{
+    base::{HasName, HasStaticName, Parsable, Res},
+    named_pitch::{HasAccidentals, HasLetter, HasNamedPitch, NamedPitch, ALL_PITCHES as ALL_NAMED_PITCHES},
+    note::{CZero, HasQuarterTone, Note, NoteRecreator, QuarterTone, A, ALL_PITCH_NOTES},
+    octave::{HasOctave, Octave, ALL_OCTAVES},
+    pitch::{HasPitch, Pitch},
+};
+
+// Enum.
+
+/// An enum representing a temperament (i.e., how the twelve pitch classes are tuned within an octave).
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Temperament {
+    /// Twelve-tone equal temperament.
+    Equal,
+    /// Five-limit just intonation, relative to the given tonic.
+    Just(Pitch),
+    /// Pythagorean tuning (pure fifths from the minor third to the augmented fifth above the tonic), relative to the given tonic.
+    Pythagorean(Pitch),
+    /// Quarter-comma meantone (from the minor third to the augmented fifth above the tonic), relative to the given tonic.
+    Meantone(Pitch),
+    /// Werckmeister III (a well temperament).
+    Werckmeister,
+    /// A custom table of the cents above C of each pitch class (from C to B).
+    Custom([f32; 12]),
+    /// An equal division of the octave into the given number of steps (e.g., 19, 24, or 31), where the notes are
+    /// spelled along the chain of fifths (so, `C♯` and `D♭` differ in 19-EDO and 31-EDO).
+    Edo(u16),
+}
+
+// Struct.
+
+/// A tuning, which is a [`Temperament`] and the frequency of A4 (the reference pitch).
+///
+/// The temperament is anchored so that A4 always sounds at the reference pitch.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Tuning {
+    /// The frequency of A4 (in Hz).
+    reference: f32,
+    /// The temperament.
+    temperament: Temperament,
+    /// The cents above C of each pitch class (from C to B), computed from the temperament.
+    table: [f64; 12],
+}
+
+// Statics.
+
+/// The ratios above the tonic of five-limit just intonation.
+static JUST_RATIOS: [(f64, f64); 12] = [
+    (1.0, 1.0),
+    (16.0, 15.0),
+    (9.0, 8.0),
+    (6.0, 5.0),
+    (5.0, 4.0),
+    (4.0, 3.0),
+    (45.0, 32.0),
+    (3.0, 2.0),
+    (8.0, 5.0),
+    (5.0, 3.0),
+    (16.0, 9.0),
+    (15.0, 8.0),
+];
+
+/// The cents above C of Werckmeister III.
+static WERCKMEISTER_CENTS: [f64; 12] = [0.0, 90.225, 192.18, 294.135, 390.225, 498.045, 588.27, 696.09, 792.18, 888.27, 996.09, 1092.18];
+
+// Impls.
+
+impl Tuning {
+    /// Creates a new [`Tuning`] from a reference pitch (the frequency of A4) and a [`Temperament`].
+    pub fn new(reference: f32, temperament: Temperament) -> Self {
+        Self {
+            reference,
+            temperament,
+            table: cents_above_c(temperament),
+        }
+    }
+
+    /// Returns twelve-tone equal temperament at A4 = 440 Hz.
+    pub fn equal() -> Self {
+        Self::default()
+    }
+
+    /// Returns five-limit just intonation, relative to the given tonic, at A4 = 440 Hz.
+    pub fn just(tonic: Pitch) -> Self {
+        Self::default().with_temperament(Temperament::Just(tonic))
+    }
+
+    /// Returns Pythagorean tuning, relative to the given tonic, at A4 = 440 Hz.
+    pub fn pythagorean(tonic: Pitch) -> Self {
+        Self::default().with_temperament(Temperament::Pythagorean(tonic))
+    }
+
+    /// Returns quarter-comma meantone, relative to the given tonic, at A4 = 440 Hz.
+    pub fn meantone(tonic: Pitch) -> Self {
+        Self::default().with_temperament(Temperament::Meantone(tonic))
+    }
+
+    /// Returns Werckmeister III at A4 = 440 Hz.
+    pub fn werckmeister() -> Self {
+        Self::default().with_temperament(Temperament::Werckmeister)
+    }
+
+    /// Returns a tuning from a table of the cents above C of each pitch class (from C to B), at A4 = 440 Hz.
+    pub fn custom(cents: [f32; 12]) -> Self {
+        Self::default().with_temperament(Temperament::Custom(cents))
+    }
+
+    /// Returns an equal division of the octave into the given number of steps (e.g., 19, 24, or 31), at A4 = 440 Hz.
+    pub fn edo(divisions: u16) -> Res<Self> {
+        if divisions < 5 {
+            return Err(anyhow::Error::msg("An equal division of the octave needs at least five steps."));
+        }
+
+        Ok(Self::default().with_temperament(Temperament::Edo(divisions)))
+    }
+
+    /// Returns the tuning with the given reference pitch (the frequency of A4, e.g., `442.0` or `415.0`).
+    pub fn with_reference(self, reference: f32) -> Self {
+        Self { reference, ..self }
+    }
+
+    /// Returns the tuning with the given [`Temperament`].
+    pub fn with_temperament(self, temperament: Temperament) -> Self {
+        Self::new(self.reference, temperament)
+    }
+
+    /// Returns the reference pitch (the frequency of A4).
+    pub fn reference(&self) -> f32 {
+        self.reference
+    }
+
+    /// Returns the [`Temperament`].
+    pub fn temperament(&self) -> Temperament {
+        self.temperament
+    }
+
+    /// Returns the cents above C of the given pitch.
+    pub fn cents(&self, pitch: Pitch) -> f32 {
+        self.cents_above_c_zero(&Note::new(NamedPitch::from(pitch), Octave::Zero)) as f32
+    }
+
+    /// Returns how far (in cents) the given pitch is from its equal-tempered frequency at the same reference pitch.
+    pub fn deviation(&self, pitch: Pitch) -> f32 {
+        let a = self.cents(Pitch::A) as f64;
+
+        ((self.cents(pitch) as f64 - a) - (pitch as u8 as f64 - Pitch::A as u8 as f64) * 100.0) as f32
+    }
+
+    /// Returns the frequency of the given [`Note`] in this tuning.
+    pub fn frequency(&self, note: &Note) -> f32 {
+        let cents_above_a4 = self.cents_above_c_zero(note) - self.cents_above_c_zero(&A);
+
+        (self.reference as f64 * 2f64.powf(cents_above_a4 / 1200.0)) as f32
+    }
+
+    /// Returns the nearest [`Note`] to the given frequency in this tuning, and how far the frequency is from that note
+    /// (in cents, where positive means sharp).
+    ///
+    /// In an equal division of the octave, every step is a candidate (e.g., the quarter tones of 24-EDO, or both `C♯`
+    /// and `D♭` in 19-EDO), spelled with the fewest accidentals.
+    ///
+    /// Frequencies outside of the range of notes (more than a quarter tone below `C0`, or above `B15`) are an error.
+    pub fn nearest_note(&self, frequency: f32) -> Res<(Note, f32)> {
+        if !frequency.is_finite() || frequency <= 0.0 {
+            return Err(anyhow::Error::msg("The frequency must be a positive number."));
+        }
+
+        let cents = |note: &Note| 1200.0 * (frequency as f64 / self.frequency(note) as f64).log2();
+
+        let last = *ALL_PITCH_NOTES.last().unwrap();
+
+        if cents(&CZero) < -50.0 || cents(&last) > 50.0 {
+            return Err(anyhow::Error::msg(format!("The frequency is outside of the range of notes ({} to {}).", CZero, last)));
+        }
+
+        // Estimate the note, and then check its neighbors (since temperaments may stray from equal temperament).
+        let estimate = ((cents(&CZero) / 100.0).round() as i32).clamp(0, ALL_PITCH_NOTES.len() as i32 - 1);
+
+        let note = match self.temperament {
+            Temperament::Edo(divisions) => {
+                let on_grid = |note: &Note| {
+                    let steps = self.cents_above_c_zero(note) * divisions as f64 / 1200.0;
+
+                    (steps - steps.round()).abs() < 1e-6
+                };
+
+                // Prefer the closest step, and then the simplest spelling (and the universal one among equals, e.g., `B♭` over `A♯` in 24-EDO).
+                let rank = |note: &Note| {
+                    let quarter_tone = (note.quarter_tone() != QuarterTone::Natural) as u8;
+                    let universal = NamedPitch::from(note.pitch()) == note.named_pitch();
+
+                    (
+                        (cents(note).abs() * 1000.0).round() as i64,
+                        2 * note.named_pitch().accidentals().unsigned_abs() + quarter_tone,
+                        !universal,
+                    )
+                };
+
+                let octave = estimate as usize / 12;
+
+                ALL_OCTAVES[octave.saturating_sub(1)..=(octave + 1).min(ALL_OCTAVES.len() - 1)]
+                    .iter()
+                    .flat_map(|octave| ALL_NAMED_PITCHES.iter().map(|named_pitch| Note::new(*named_pitch, *octave)))
+                    .flat_map(|note| [QuarterTone::Natural, QuarterTone::HalfFlat, QuarterTone::HalfSharp].map(|q| note.with_quarter_tone(q)))
+                    .filter(on_grid)
+                    .min_by_key(rank)
+                    .unwrap_or(CZero)
+            }
+            _ => (estimate - 1..=estimate + 1)
+                .filter_map(Note::from_semitones)
+                .min_by(|a, b| cents(a).abs().total_cmp(&cents(b).abs()))
+                .unwrap_or(CZero),
+        };
+
+        Ok((note, cents(&note) as f32))
+    }
+
+    /// Returns the cents above C0 of the given [`Note`] (before the reference pitch is applied).
+    fn cents_above_c_zero(&self, note: &Note) -> f64 {
+        let quarter_tone = match note.quarter_tone() {
+            QuarterTone::HalfFlat => -0.5,
+            QuarterTone::Natural => 0.0,
+            QuarterTone::HalfSharp => 0.5,
+        };
+
+        match self.temperament {
+            Temperament::Edo(divisions) => {
+                let divisions = divisions.max(1) as f64;
+                let fifth = (divisions * 1.5f64.log2()).round();
+                let sharp = 7.0 * fifth - 4.0 * divisions;
+
+                // Find the natural on the chain of fifths (e.g., D is two fifths above C), and then apply the accidentals.
+                let fifths = "FCGDAEB".find(note.named_pitch().letter()).unwrap() as f64 - 1.0;
+                let natural = (fifths * fifth).rem_euclid(divisions);
+                let steps = note.octave() as u8 as f64 * divisions + natural + (note.named_pitch().accidentals() as f64 + quarter_tone) * sharp;
+
+                steps * 1200.0 / divisions
+            }
+            _ => {
+                let semitones = note.semitones();
+
+                1200.0 * semitones.div_euclid(12) as f64 + self.table[semitones.rem_euclid(12) as usize] + quarter_tone * 100.0
+            }
+        }
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::new(440.0, Temperament::Equal)
+    }
+}
+
+impl HasName for Temperament {
+    fn name(&self) -> String {
+        let tonic = |pitch: &Pitch| NamedPitch::from(*pitch).static_name();
+
+        match self {
+            Temperament::Equal => "equal temperament".to_owned(),
+            Temperament::Just(pitch) => format!("just intonation in {}", tonic(pitch)),
+            Temperament::Pythagorean(pitch) => format!("Pythagorean tuning in {}", tonic(pitch)),
+            Temperament::Meantone(pitch) => format!("quarter-comma meantone in {}", tonic(pitch)),
+            Temperament::Werckmeister => "Werckmeister III".to_owned(),
+            Temperament::Custom(_) => "custom temperament".to_owned(),
+            Temperament::Edo(divisions) => format!("{}-EDO", divisions),
+        }
+    }
+}
+
+impl HasName for Tuning {
+    fn name(&self) -> String {
+        format!("{} (A4 = {} Hz)", self.temperament.name(), self.reference)
+    }
+}
+
+impl Display for Tuning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Parsable for Tuning {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        let mut tuning = Tuning::default();
+        let mut tokens = Vec::new();
+
+        // Pull out the reference pitch (e.g., `A=442` or `A4=415`).
+        for token in symbol.split(|c: char| c.is_whitespace() || c == ',' || c == ':').filter(|t| !t.is_empty()) {
+            let lowercase = token.to_lowercase();
+
+            match lowercase.strip_prefix("a4=").or_else(|| lowercase.strip_prefix("a=")) {
+                Some(value) => {
+                    let reference = value.trim_end_matches("hz").parse::<f32>().ok().filter(|r| r.is_finite() && *r > 0.0);
+                    let reference = reference.ok_or_else(|| anyhow::Error::msg(format!("Invalid reference pitch `{}`.", token)))?;
+
+                    tuning = tuning.with_reference(reference);
+                }
+                None => tokens.push(token),
+            }
+        }
+
+        let tonic = |tokens: &[&str]| -> Res<Pitch> {
+            match tokens.get(1) {
+                Some(tonic) => Ok(Note::parse(tonic)?.pitch()),
+                None => Ok(Pitch::C),
+            }
+        };
+
+        let temperament = match tokens.first().map(|t| t.to_lowercase()).as_deref() {
+            None | Some("equal" | "et" | "12tet" | "12-tet" | "12edo" | "12-edo") => Temperament::Equal,
+            Some("just" | "ji") => Temperament::Just(tonic(&tokens)?),
+            Some("pythagorean") => Temperament::Pythagorean(tonic(&tokens)?),
+            Some("meantone") => Temperament::Meantone(tonic(&tokens)?),
+            Some("werckmeister" | "werckmeister3" | "werckmeisteriii") => Temperament::Werckmeister,
+            Some(edo) if parse_edo(edo).is_some() => Tuning::edo(parse_edo(edo).unwrap_or_default())?.temperament,
+            Some(_) => {
+                let cents = tokens
+                    .iter()
+                    .map(|t| t.parse::<f32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .ok()
+                    .and_then(|c| <[f32; 12]>::try_from(c).ok())
+                    .ok_or_else(|| {
+                        anyhow::Error::msg(format!(
+                            "Unknown tuning `{}` (expected `equal`, `just`, `pythagorean`, `meantone`, or `werckmeister`, with an optional tonic, or twelve cents values from C to B).",
+                            symbol
+                        ))
+                    })?;
+
+                Temperament::Custom(cents)
+            }
+        };
+
+        if tokens.len() > 2 && !matches!(temperament, Temperament::Custom(_)) {
+            return Err(anyhow::Error::msg(format!("Unexpected `{}` in tuning `{}`.", tokens[2], symbol)));
+        }
+
+        Ok(tuning.with_temperament(temperament))
+    }
+}
+
+// Helpers.
+
+/// Returns the cents above C of each pitch class (from C to B) in the given [`Temperament`].
+///
+/// Equal divisions of the octave spell their notes along the chain of fifths, so they do not use this table.
+fn cents_above_c(temperament: Temperament) -> [f64; 12] {
+    match temperament {
+        Temperament::Equal | Temperament::Edo(_) => std::array::from_fn(|k| k as f64 * 100.0),
+        Temperament::Just(tonic) => relative_to_tonic(tonic, JUST_RATIOS.map(|(n, d)| 1200.0 * (n / d).log2())),
+        Temperament::Pythagorean(tonic) => relative_to_tonic(tonic, stacked_fifths(1200.0 * 1.5f64.log2(), -3)),
+        Temperament::Meantone(tonic) => relative_to_tonic(tonic, stacked_fifths(1200.0 * 5f64.log2() / 4.0, -3)),
+        Temperament::Werckmeister => WERCKMEISTER_CENTS,
+        Temperament::Custom(cents) => cents.map(|c| c as f64),
+    }
+}
+
+/// Parses the number of steps of an equal division of the octave (e.g., `19edo`, `24-EDO`, or `31tet`).
+fn parse_edo(symbol: &str) -> Option<u16> {
+    let symbol = symbol.to_lowercase();
+    let divisions = symbol.strip_suffix("edo").or_else(|| symbol.strip_suffix("tet"))?;
+
+    divisions.trim_end_matches('-').parse().ok()
+}
+
+/// Returns the cents above the tonic of the twelve pitches generated by stacking fifths of the given size, starting
+/// the given number of fifths from the tonic.
+fn stacked_fifths(fifth: f64, lowest: i32) -> [f64; 12] {
+    let mut result = [0.0; 12];
+
+    for k in lowest..lowest + 12 {
+        result[(7 * k).rem_euclid(12) as usize] = (k as f64 * fifth).rem_euclid(1200.0);
+    }
+
+    result
+}
+
+/// Converts a table of the cents above the tonic (by semitones above the tonic) into a table of the cents above C.
+fn relative_to_tonic(tonic: Pitch, table: [f64; 12]) -> [f64; 12] {
+    let tonic = tonic as usize;
+
+    std::array::from_fn(|k| {
+        let degree = (k + 12 - tonic) % 12;
+        let wrapped = if tonic + degree >= 12 { 1200.0 } else { 0.0 };
+
+        100.0 * tonic as f64 + table[degree] - wrapped
+    })
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        note::*,
+        pitch::{HasBaseFrequency, HasFrequency},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 0.01, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn test_equal() {
+        let tuning = Tuning::default();
+
+        assert_eq!(tuning.frequency(&A), 440.0);
+        assert_eq!(tuning.frequency(&AFive), 880.0);
+        assert_close(tuning.frequency(&C), 261.63);
+        assert_close(tuning.frequency(&CZero), 16.35);
+        assert_eq!(tuning.frequency(&BSharpThree), tuning.frequency(&C));
+
+        let tuning = Tuning::equal().with_reference(442.0);
+
+        assert_eq!(tuning.frequency(&A), 442.0);
+        assert_close(tuning.frequency(&C), 262.81);
+        assert_close(Tuning::equal().with_reference(415.0).frequency(&A), 415.0);
+    }
+
+    #[test]
+    fn test_temperaments() {
+        // Just intonation has pure thirds and fifths above the tonic.
+        let just = Tuning::just(Pitch::A);
+
+        assert_close(just.frequency(&CSharpFive) / just.frequency(&A), 1.25);
+        assert_close(just.frequency(&EFive) / just.frequency(&A), 1.5);
+        assert_close(just.deviation(Pitch::DFlat), -13.69);
+
+        let just = Tuning::just(Pitch::C);
+
+        assert_close(just.frequency(&E) / just.frequency(&C), 1.25);
+        assert_close(just.frequency(&G) / just.frequency(&C), 1.5);
+        assert_close(just.cents(Pitch::E), 386.31);
+
+        // Pythagorean tuning has pure fifths (except for the wolf fifth).
+        let pythagorean = Tuning::pythagorean(Pitch::C);
+
+        assert_close(pythagorean.frequency(&G) / pythagorean.frequency(&C), 1.5);
+        assert_close(pythagorean.frequency(&D) / pythagorean.frequency(&GThree), 1.5);
+        assert_close(pythagorean.cents(Pitch::E), 407.82);
+
+        // Meantone has pure major thirds.
+        let meantone = Tuning::meantone(Pitch::C);
+
+        assert_close(meantone.frequency(&E) / meantone.frequency(&C), 1.25);
+        assert_close(meantone.frequency(&GSharp) / meantone.frequency(&E), 1.25);
+        assert_close(meantone.cents(Pitch::D), 193.16);
+
+        assert_close(Tuning::werckmeister().cents(Pitch::G), 696.09);
+        assert_eq!(Tuning::werckmeister().frequency(&A), 440.0);
+
+        // The reference pitch is always A4.
+        for tuning in [
+            just,
+            pythagorean,
+            meantone,
+            Tuning::custom([0.0, 100.0, 200.0, 300.0, 400.0, 500.0, 600.0, 700.0, 800.0, 905.0, 1000.0, 1100.0]),
+        ] {
+            assert_close(tuning.frequency(&A), 440.0);
+            assert_eq!(tuning.frequency(&BSharpThree), tuning.frequency(&C));
+        }
+    }
+
+    #[test]
+    fn test_edo() {
+        let cents = |tuning: &Tuning, low: &Note, high: &Note| 1200.0 * (tuning.frequency(high) / tuning.frequency(low)).log2();
+
+        // 12-EDO is equal temperament.
+        let twelve = Tuning::edo(12).unwrap();
+
+        for note in [C, CSharp, DFlat, EFlat, FSharpFive, BFlatThree] {
+            assert_close(twelve.frequency(&note), Tuning::equal().frequency(&note));
+        }
+
+        // In 19-EDO (and 31-EDO), enharmonics differ.
+        let nineteen = Tuning::edo(19).unwrap();
+
+        assert_eq!(nineteen.frequency(&A), 440.0);
+        assert_close(cents(&nineteen, &C, &CSharp), 1200.0 / 19.0);
+        assert_close(cents(&nineteen, &C, &DFlat), 2400.0 / 19.0);
+        assert_close(cents(&nineteen, &C, &CFive), 1200.0);
+
+        let thirty_one = Tuning::edo(31).unwrap();
+
+        assert_close(cents(&thirty_one, &C, &E), 10.0 * 1200.0 / 31.0);
+        assert_close(cents(&thirty_one, &C, &C.with_quarter_tone(QuarterTone::HalfSharp)), 1200.0 / 31.0);
+
+        // In 24-EDO, the quarter tones are single steps.
+        let twenty_four = Tuning::edo(24).unwrap();
+
+        assert_close(cents(&twenty_four, &E, &E.with_quarter_tone(QuarterTone::HalfFlat)), -50.0);
+        assert_close(cents(&twenty_four, &C, &CSharp.with_quarter_tone(QuarterTone::HalfSharp)), 150.0);
+
+        assert!(Tuning::edo(0).is_err());
+        assert!(Tuning::edo(1).is_err());
+    }
+
+    #[test]
+    fn test_nearest_note() {
+        let tuning = Tuning::default();
+
+        assert_eq!(tuning.nearest_note(440.0).unwrap(), (A, 0.0));
+        assert_eq!(tuning.nearest_note(261.63).unwrap().0, C);
+        assert_eq!(tuning.nearest_note(445.0).unwrap().0, A);
+        assert_close(tuning.nearest_note(445.0).unwrap().1, 19.56);
+        assert_close(tuning.nearest_note(442.5).unwrap().1, 9.81);
+        assert_eq!(tuning.nearest_note(455.0).unwrap().0, BFlat);
+        assert_close(tuning.nearest_note(455.0).unwrap().1, -41.96);
+        assert_eq!(tuning.nearest_note(16.0).unwrap().0, CZero);
+
+        // Frequencies outside of the range of notes are an error.
+        assert!(tuning.nearest_note(1.0).is_err());
+        assert!(tuning.nearest_note(10_000_000.0).is_err());
+        assert!(tuning.nearest_note(0.0).is_err());
+
+        // The reference pitch (and temperament) are honored.
+        assert_eq!(tuning.with_reference(442.0).nearest_note(442.0).unwrap(), (A, 0.0));
+        assert_eq!(tuning.with_reference(415.0).nearest_note(440.0).unwrap().0, BFlat);
+        assert_close(tuning.with_reference(415.0).nearest_note(440.0).unwrap().1, 1.27);
+
+        let just = Tuning::just(Pitch::C);
+
+        assert_eq!(just.nearest_note(just.frequency(&E)).unwrap(), (E, 0.0));
+        assert_close(just.nearest_note(tuning.frequency(&E)).unwrap().1, -1.95);
+        assert_eq!(just.with_reference(432.0).nearest_note(261.0).unwrap().0, C);
+        assert_close(just.with_reference(432.0).nearest_note(261.0).unwrap().1, 11.98);
+
+        // In 24-EDO, the quarter tones are on the grid.
+        let twenty_four = Tuning::edo(24).unwrap();
+
+        assert_eq!(twenty_four.nearest_note(452.0).unwrap().0, A.with_quarter_tone(QuarterTone::HalfSharp));
+        assert_close(twenty_four.nearest_note(452.0).unwrap().1, -3.42);
+        assert_eq!(twenty_four.nearest_note(440.0).unwrap(), (A, 0.0));
+        assert_eq!(twenty_four.nearest_note(twenty_four.frequency(&BFlat)).unwrap(), (BFlat, 0.0));
+        assert_eq!(twenty_four.nearest_note(twenty_four.frequency(&ESharp)).unwrap(), (F, 0.0));
+        assert_eq!(
+            twenty_four.nearest_note(twenty_four.frequency(&E.with_quarter_tone(QuarterTone::HalfFlat))).unwrap().0,
+            E.with_quarter_tone(QuarterTone::HalfFlat)
+        );
+
+        // In 19-EDO, `C♯` and `D♭` are different steps (and neither is in 12-TET).
+        let nineteen = Tuning::edo(19).unwrap();
+
+        assert_eq!(nineteen.nearest_note(nineteen.frequency(&CSharp)).unwrap(), (CSharp, 0.0));
+        assert_eq!(nineteen.nearest_note(nineteen.frequency(&DFlat)).unwrap(), (DFlat, 0.0));
+        assert_eq!(nineteen.nearest_note(16.5).unwrap().0.octave(), Octave::Zero);
+    }
+
+    #[test]
+    fn test_frequency_in() {
+        let just = Tuning::just(Pitch::C).with_reference(415.0);
+
+        assert_close(A.frequency_in(&just), 415.0);
+        assert_close(E.frequency_in(&just) / C.frequency_in(&just), 1.25);
+
+        // Without a tuning, the frequency is in equal temperament at A4 = 440 Hz.
+        assert_eq!(A.frequency(), 440.0);
+        assert_eq!(A.frequency(), A.frequency_in(&Tuning::default()));
+        assert_eq!(Pitch::A.base_frequency(), A.frequency() / 16.0);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Tuning::parse("equal").unwrap(), Tuning::default());
+        assert_eq!(Tuning::parse("A=442").unwrap(), Tuning::equal().with_reference(442.0));
+        assert_eq!(Tuning::parse("just D").unwrap(), Tuning::just(Pitch::D));
+        assert_eq!(Tuning::parse("meantone Eb A4=415").unwrap(), Tuning::meantone(Pitch::EFlat).with_reference(415.0));
+        assert_eq!(Tuning::parse("pythagorean").unwrap(), Tuning::pythagorean(Pitch::C));
+        assert_eq!(Tuning::parse("werckmeister").unwrap(), Tuning::werckmeister());
+        assert_eq!(
+            Tuning::parse("0 100 200 300 400 500 600 700 800 900 1000 1100").unwrap(),
+            Tuning::custom(std::array::from_fn(|k| k as f32 * 100.0))
+        );
+        assert_eq!(Tuning::parse("just D A=442").unwrap().name(), "just intonation in D (A4 = 442 Hz)");
+
+        assert_eq!(Tuning::parse("19edo").unwrap(), Tuning::edo(19).unwrap());
+        assert_eq!(Tuning::parse("24-EDO A=442").unwrap().name(), "24-EDO (A4 = 442 Hz)");
+        assert_eq!(Tuning::parse("31tet").unwrap(), Tuning::edo(31).unwrap());
+
+        assert!(Tuning::parse("0edo").is_err());
+        assert!(Tuning::parse("1edo").is_err());
+        assert!(Tuning::parse("A=-3").is_err());
+        assert!(Tuning::parse("just H").is_err());
+        assert!(Tuning::parse("0 100 200").is_err());
+        assert!(Tuning::parse("just D E").is_err());
+    }
+}
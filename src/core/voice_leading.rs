@@ -0,0 +1,439 @@
+//! A module for voice leading through a sequence of chords.
+//!
+//! The [`VoiceLeader`] chooses a voicing for each chord, such that the total motion of the voices is minimized.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::core::{
+    base::{HasName, Res},
+    chord::{Chord, HasRoot, HasSlash},
+    interval::Interval,
+    name_style::NamingOptions,
+    note::{Note, NoteRecreator, Respellable},
+    octave::Octave,
+    voicing::{Register, Tones},
+};
+
+// Statics.
+
+/// The default number of voices used by a [`VoiceLeader`].
+pub const DEFAULT_VOICES: usize = 4;
+
+/// The cost of moving a voice off of a tone that is common to both chords.
+const COMMON_TONE_PENALTY: f32 = 2.0;
+
+/// The cost of a pair of voices moving in parallel fifths or octaves.
+const PARALLEL_PENALTY: f32 = 12.0;
+
+// Structs.
+
+/// A voice leader, which voices a sequence of [`Chord`]s so that the voices move as little as possible.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct VoiceLeader {
+    /// The number of voices.
+    voices: usize,
+    /// The register into which every voice must fit.
+    register: Register,
+    /// Whether or not to prefer holding tones that are common to consecutive chords.
+    keep_common_tones: bool,
+    /// Whether or not to avoid parallel fifths and octaves.
+    avoid_parallels: bool,
+}
+
+/// The result of voice leading through a sequence of [`Chord`]s.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct VoiceLeading {
+    /// The chords that were voiced.
+    chords: Vec<Chord>,
+    /// The voicing of each chord (with the voices ordered from low to high).
+    voicings: Vec<Vec<Note>>,
+}
+
+// Impls.
+
+impl VoiceLeader {
+    /// Creates a new [`VoiceLeader`] with the default options.
+    pub fn new() -> Self {
+        Self {
+            voices: DEFAULT_VOICES,
+            register: Register::default(),
+            keep_common_tones: true,
+            avoid_parallels: true,
+        }
+    }
+
+    /// Sets the number of voices.
+    pub fn with_voices(mut self, voices: usize) -> Self {
+        self.voices = voices;
+
+        self
+    }
+
+    /// Sets the register into which every voice must fit.
+    pub fn with_register(mut self, register: Register) -> Self {
+        self.register = register;
+
+        self
+    }
+
+    /// Sets whether or not to prefer holding tones that are common to consecutive chords.
+    pub fn with_keep_common_tones(mut self, keep_common_tones: bool) -> Self {
+        self.keep_common_tones = keep_common_tones;
+
+        self
+    }
+
+    /// Sets whether or not to avoid parallel fifths and octaves.
+    pub fn with_avoid_parallels(mut self, avoid_parallels: bool) -> Self {
+        self.avoid_parallels = avoid_parallels;
+
+        self
+    }
+
+    /// Voices the given [`Chord`]s, minimizing the total motion of the voices.
+    pub fn lead(&self, chords: &[Chord]) -> Res<VoiceLeading> {
+        if !(2..=6).contains(&self.voices) {
+            return Err(anyhow::Error::msg("The number of voices must be between 2 and 6."));
+        }
+
+        let candidates = chords
+            .iter()
+            .map(|c| {
+                let candidates = self.candidates(c);
+
+                if candidates.is_empty() {
+                    return Err(anyhow::Error::msg(format!(
+                        "Could not voice {} with {} voices in the register {}.",
+                        c.name(),
+                        self.voices,
+                        self.register
+                    )));
+                }
+
+                Ok(candidates)
+            })
+            .collect::<Res<Vec<_>>>()?;
+
+        let Some(first) = candidates.first() else {
+            return Ok(VoiceLeading { chords: vec![], voicings: vec![] });
+        };
+
+        // Prefer starting in the middle of the register.
+        let center = (self.register.low.semitones() + self.register.high.semitones()) as f32 / 2.0;
+
+        let mut costs = first
+            .iter()
+            .map(|v| v.iter().map(|n| (n.semitones() as f32 - center).abs()).sum::<f32>() / self.voices as f32)
+            .collect::<Vec<_>>();
+        let mut back_pointers = vec![];
+
+        // Find the cheapest path through the candidates (i.e., the Viterbi algorithm).
+        for pair in candidates.windows(2) {
+            let (previous, current) = (&pair[0], &pair[1]);
+
+            let (next_costs, pointers): (Vec<_>, Vec<_>) = current
+                .iter()
+                .map(|c| {
+                    previous
+                        .iter()
+                        .enumerate()
+                        .map(|(k, p)| (costs[k] + self.transition_cost(p, c), k))
+                        .min_by(|a, b| a.0.total_cmp(&b.0))
+                        .unwrap_or((f32::INFINITY, 0))
+                })
+                .unzip();
+
+            costs = next_costs;
+            back_pointers.push(pointers);
+        }
+
+        let mut index = costs.iter().enumerate().min_by(|a, b| a.1.total_cmp(b.1)).map(|(k, _)| k).unwrap_or(0);
+        let mut voicings = vec![candidates[candidates.len() - 1][index].clone()];
+
+        for (k, pointers) in back_pointers.iter().enumerate().rev() {
+            index = pointers[index];
+            voicings.push(candidates[k][index].clone());
+        }
+
+        voicings.reverse();
+
+        Ok(VoiceLeading { chords: chords.to_vec(), voicings })
+    }
+
+    /// Returns every voicing of the chord that uses the right number of voices, and fits into the register.
+    fn candidates(&self, chord: &Chord) -> Vec<Vec<Note>> {
+        let (tones, counts) = self.tones(chord);
+
+        // The slash note (if any) must be in the bass.
+        let slash = chord.slash();
+        let bass = if slash != chord.root() {
+            tones.iter().position(|t| pitch_class(*t) == pitch_class(slash))
+        } else {
+            None
+        };
+
+        let mut pool = vec![];
+
+        for (k, tone) in tones.iter().enumerate() {
+            for octave in 0..=9 {
+                if let Ok(octave) = Octave::try_from(octave) {
+                    let note = tone.with_octave(octave);
+
+                    if self.register.contains(note) {
+                        pool.push((note, k));
+                    }
+                }
+            }
+        }
+
+        pool.sort_by_key(|(n, _)| n.semitones());
+
+        let mut result = vec![];
+
+        choose(&pool, 0, &mut counts.clone(), &mut vec![], bass, self.voices, &mut result);
+
+        result.into_iter().map(|v| v.into_iter().map(|k| pool[k].0).collect()).collect()
+    }
+
+    /// Returns the tones of the chord in order of importance, and how many voices each one gets.
+    fn tones(&self, chord: &Chord) -> (Vec<Note>, Vec<usize>) {
+        let root = chord.root();
+        let chord_tones = Tones::new(chord);
+
+        // The guide tones are the most important, then the highest tensions (which name the chord), and the fifth is the least important.
+        let mut intervals = vec![chord_tones.third, chord_tones.seventh, Some(Interval::PerfectUnison)];
+        intervals.extend(chord_tones.tensions.iter().rev().map(|t| Some(*t)));
+        intervals.push(chord_tones.fifth);
+
+        let mut tones: Vec<Note> = vec![];
+
+        let slash = chord.slash();
+
+        if slash != root {
+            tones.push(slash);
+        }
+
+        for note in intervals.into_iter().flatten().map(|i| root + i) {
+            if !tones.iter().any(|t| pitch_class(*t) == pitch_class(note)) {
+                tones.push(note);
+            }
+        }
+
+        tones.truncate(self.voices);
+
+        let mut counts = vec![1; tones.len()];
+
+        // Double the root (and then the fifth) when there are more voices than tones.
+        let doubled = [root, root + chord_tones.fifth.unwrap_or(Interval::PerfectUnison)]
+            .iter()
+            .filter_map(|n| tones.iter().position(|t| pitch_class(*t) == pitch_class(*n)))
+            .collect::<Vec<_>>();
+
+        for k in 0..self.voices.saturating_sub(tones.len()) {
+            if let Some(index) = doubled.get(k % doubled.len().max(1)) {
+                counts[*index] += 1;
+            }
+        }
+
+        (tones, counts)
+    }
+
+    /// Returns the cost of moving from one voicing to the next.
+    fn transition_cost(&self, previous: &[Note], current: &[Note]) -> f32 {
+        let from = previous.iter().map(|n| n.semitones()).collect::<Vec<_>>();
+        let to = current.iter().map(|n| n.semitones()).collect::<Vec<_>>();
+
+        let mut cost = from.iter().zip(&to).map(|(a, b)| (b - a).abs() as f32).sum::<f32>();
+
+        if self.keep_common_tones {
+            let common = from.iter().zip(&to).filter(|(a, b)| a != b && to.iter().any(|t| (t - *a).rem_euclid(12) == 0)).count();
+
+            cost += common as f32 * COMMON_TONE_PENALTY;
+        }
+
+        if self.avoid_parallels {
+            for i in 0..from.len() {
+                for j in (i + 1)..from.len() {
+                    let before = (from[j] - from[i]).rem_euclid(12);
+                    let after = (to[j] - to[i]).rem_euclid(12);
+                    let (motion_i, motion_j) = (to[i] - from[i], to[j] - from[j]);
+
+                    if before == after && (before == 0 || before == 7) && motion_i != 0 && motion_i.signum() == motion_j.signum() {
+                        cost += PARALLEL_PENALTY;
+                    }
+                }
+            }
+        }
+
+        cost
+    }
+}
+
+impl Default for VoiceLeader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VoiceLeading {
+    /// Returns the chords that were voiced.
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+
+    /// Returns the voicing of each chord (with the voices ordered from low to high).
+    pub fn voicings(&self) -> &[Vec<Note>] {
+        &self.voicings
+    }
+
+    /// Returns the motion of each voice (in semitones, where positive is up) between each pair of consecutive chords.
+    pub fn motions(&self) -> Vec<Vec<i8>> {
+        self.voicings
+            .windows(2)
+            .map(|pair| pair[0].iter().zip(&pair[1]).map(|(a, b)| (b.semitones() - a.semitones()) as i8).collect())
+            .collect()
+    }
+
+    /// Returns the total motion of all of the voices (in semitones).
+    pub fn total_motion(&self) -> u32 {
+        self.motions().iter().flatten().map(|m| m.unsigned_abs() as u32).sum()
+    }
+
+    /// Returns the voice leading (each chord, with its voicing and the motion of its voices), written with the given [`NamingOptions`].
+    pub fn to_string_with(&self, options: &NamingOptions) -> String {
+        let mut result = String::new();
+
+        self.write_with(&mut result, options).expect("Writing to a string cannot fail.");
+
+        result
+    }
+
+    /// Writes the voice leading (see [`VoiceLeading::to_string_with`]).
+    fn write_with(&self, f: &mut impl Write, options: &NamingOptions) -> fmt::Result {
+        for (k, (chord, voicing)) in self.chords.iter().zip(&self.voicings).enumerate() {
+            let notes = voicing.iter().map(|n| n.respelled(options.spelling()).name()).collect::<Vec<_>>().join(" ");
+
+            write!(f, "{}: {}", chord.name_with(options), notes)?;
+
+            if k > 0 {
+                let motions = self.motions()[k - 1]
+                    .iter()
+                    .map(|m| if *m == 0 { "0".to_owned() } else { format!("{:+}", m) })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                write!(f, " ({})", motions)?;
+            }
+
+            if k + 1 < self.chords.len() {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for VoiceLeading {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_with(f, &NamingOptions::default())
+    }
+}
+
+// Helpers.
+
+/// Returns the pitch class of the note (i.e., the number of semitones above C).
+fn pitch_class(note: Note) -> i32 {
+    note.semitones().rem_euclid(12)
+}
+
+/// Chooses ascending notes from the pool (given as indexes), such that each tone is used the given number of times.
+///
+/// Adjacent voices are at most an octave apart (or two octaves, between the bottom two voices).
+fn choose(pool: &[(Note, usize)], start: usize, counts: &mut [usize], chosen: &mut Vec<usize>, bass: Option<usize>, voices: usize, result: &mut Vec<Vec<usize>>) {
+    if chosen.len() == voices {
+        result.push(chosen.clone());
+        return;
+    }
+
+    for k in start..pool.len() {
+        let (note, tone) = pool[k];
+
+        if let Some(last) = chosen.last() {
+            let gap = note.semitones() - pool[*last].0.semitones();
+            let limit = if chosen.len() == 1 { 24 } else { 12 };
+
+            if gap > limit {
+                break;
+            }
+
+            if gap == 0 {
+                continue;
+            }
+        } else if bass.is_some() && bass != Some(tone) {
+            continue;
+        }
+
+        if counts[tone] == 0 {
+            continue;
+        }
+
+        counts[tone] -= 1;
+        chosen.push(k);
+
+        choose(pool, k + 1, counts, chosen, bass, voices, result);
+
+        chosen.pop();
+        counts[tone] += 1;
+    }
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{base::Parsable, name_style::NameStyle, note::*};
+    use pretty_assertions::assert_eq;
+
+    fn chords(symbols: &[&str]) -> Vec<Chord> {
+        symbols.iter().map(|s| Chord::parse(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_lead() {
+        let leading = VoiceLeader::new().lead(&chords(&["Dm7", "G7", "Cmaj7"])).unwrap();
+
+        assert_eq!(leading.to_string(), "Dm7: A3 C4 D4 F4\nG7: G3 B3 D4 F4 (-2, -1, 0, 0)\nCmaj7: G3 B3 C4 E4 (0, 0, -2, -1)");
+        assert_eq!(leading.voicings()[1], vec![GThree, BThree, DFour, FFour]);
+        assert_eq!(leading.motions(), vec![vec![-2, -1, 0, 0], vec![0, 0, -2, -1]]);
+        assert_eq!(leading.total_motion(), 6);
+
+        // Three voices, with the slash notes in the bass.
+        let leading = VoiceLeader::new().with_voices(3).lead(&chords(&["C", "F/C", "G7/B", "C"])).unwrap();
+
+        assert_eq!(leading.to_string(), "C: C4 E4 G4\nF/C: C4 F4 A4 (0, +1, +2)\nG7/B: B3 F4 G4 (-1, 0, -2)\nC: C4 E4 G4 (+1, -1, 0)");
+
+        // The chords, and their voicings, are written with the naming options.
+        let leading = VoiceLeader::new().with_voices(3).lead(&chords(&["A#7", "D#maj7"])).unwrap();
+        let options = NamingOptions::default().with_spelling(Spelling::Flats).with_style(NameStyle::Jazz);
+
+        assert_eq!(leading.to_string_with(&options), "B♭7: A♭3 B♭3 D4\nE♭Δ7: E♭3 G3 D4 (-5, -3, 0)");
+    }
+
+    #[test]
+    fn test_options() {
+        let progression = chords(&["C", "Dm", "Em", "F"]);
+
+        // Doubled roots that move by step make parallel octaves, unless the voices move apart.
+        let strict = VoiceLeader::new().lead(&progression).unwrap();
+        let free = VoiceLeader::new().with_avoid_parallels(false).lead(&progression).unwrap();
+
+        assert!(free.total_motion() < strict.total_motion());
+        assert_eq!(free.motions()[0], vec![2, 2, 2, 1]);
+
+        assert!(VoiceLeader::new().with_voices(1).lead(&progression).is_err());
+        assert!(VoiceLeader::new().with_register(Register::new(CFour, DFour)).lead(&progression).is_err());
+        assert_eq!(VoiceLeader::new().lead(&[]).unwrap().voicings().len(), 0);
+    }
+}
@@ -0,0 +1,560 @@
+//! A module for working with chord voicings.
+//!
+//! A voicing is a concrete arrangement of the tones of a [`Chord`] (e.g., a drop 2, or a rootless voicing).
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::core::{
+    base::{HasName, HasStaticName, Parsable, Res},
+    chord::{Chord, HasChord, HasRoot, HasScale, HasSlash},
+    interval::Interval,
+    known_chord::HasRelativeChord,
+    note::{CSix, CTwo, Note, NoteRecreator},
+    octave::{HasOctave, Octave},
+    pitch::HasPitch,
+};
+
+// Traits.
+
+/// A trait for types that can be voiced (most likely a [`Chord`]).
+pub trait HasVoicings {
+    /// Returns the voicing of the given kind, constrained to the given [`Register`].
+    fn voicing(&self, kind: VoicingKind, register: Register) -> Res<Voicing>;
+
+    /// Returns all of the voicings that fit into the given [`Register`].
+    fn voicings(&self, register: Register) -> Vec<Voicing>;
+}
+
+// Enum.
+
+/// The kinds of [`Voicing`]s.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug, PartialOrd, Ord)]
+pub enum VoicingKind {
+    /// All of the chord tones, stacked as closely as possible above the root.
+    Close,
+    /// A four-note close voicing, with the second voice from the top dropped an octave.
+    Drop2,
+    /// A four-note close voicing, with the third voice from the top dropped an octave.
+    Drop3,
+    /// A four-note close voicing, with the second and fourth voices from the top dropped an octave.
+    Drop24,
+    /// A rootless voicing built up from the third (i.e., 3-5-7-9, or 3-13-7-9 for dominant chords).
+    RootlessA,
+    /// A rootless voicing built up from the seventh (i.e., 7-9-3-5, or 7-9-3-13 for dominant chords).
+    RootlessB,
+    /// The root, third, and seventh (i.e., 1-3-7).
+    Shell,
+    /// The root and fifth on the bottom, with the third, seventh, and tensions spread above.
+    Spread,
+    /// Four notes of the chord's scale, stacked in (diatonic) fourths up from the third.
+    Quartal,
+    /// The third and seventh, with a triad of tensions (i.e., 9, 11, and 13) stacked above.
+    UpperStructure,
+}
+
+// Structs.
+
+/// A register (i.e., a range of notes) into which a [`Voicing`] must fit.
+#[derive(PartialEq, Eq, Copy, Clone, Hash, Debug)]
+pub struct Register {
+    /// The lowest note of the register.
+    pub low: Note,
+    /// The highest note of the register.
+    pub high: Note,
+}
+
+/// A voicing of a [`Chord`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Voicing {
+    /// The voiced chord.
+    chord: Chord,
+    /// The kind of voicing.
+    kind: VoicingKind,
+    /// The notes of the voicing (from low to high).
+    notes: Vec<Note>,
+}
+
+/// The tones of a chord, sorted by their role (relative to the root).
+pub(crate) struct Tones {
+    pub(crate) third: Option<Interval>,
+    pub(crate) fifth: Option<Interval>,
+    pub(crate) seventh: Option<Interval>,
+    pub(crate) ninth: Option<Interval>,
+    pub(crate) eleventh: Option<Interval>,
+    pub(crate) thirteenth: Option<Interval>,
+    pub(crate) tensions: Vec<Interval>,
+}
+
+// Impls.
+
+impl Register {
+    /// Creates a new [`Register`] from the given lowest and highest notes.
+    pub fn new(low: Note, high: Note) -> Self {
+        Self { low, high }
+    }
+
+    /// Returns whether or not the given note is within the register.
+    pub fn contains(&self, note: Note) -> bool {
+        self.low <= note && note <= self.high
+    }
+}
+
+impl Default for Register {
+    fn default() -> Self {
+        Register::new(CTwo, CSix)
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.low.name(), self.high.name())
+    }
+}
+
+impl HasStaticName for VoicingKind {
+    fn static_name(&self) -> &'static str {
+        match self {
+            VoicingKind::Close => "close",
+            VoicingKind::Drop2 => "drop 2",
+            VoicingKind::Drop3 => "drop 3",
+            VoicingKind::Drop24 => "drop 2+4",
+            VoicingKind::RootlessA => "rootless A",
+            VoicingKind::RootlessB => "rootless B",
+            VoicingKind::Shell => "shell",
+            VoicingKind::Spread => "spread",
+            VoicingKind::Quartal => "quartal",
+            VoicingKind::UpperStructure => "upper structure",
+        }
+    }
+}
+
+impl Display for VoicingKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.static_name())
+    }
+}
+
+impl Parsable for VoicingKind {
+    fn parse(symbol: &str) -> Res<Self>
+    where
+        Self: Sized,
+    {
+        // Normalize the input, so that `drop 2`, `drop-2`, and `Drop2` are all the same.
+        let normalized = normalize_kind_name(symbol);
+
+        ALL_VOICING_KINDS
+            .iter()
+            .find(|k| normalize_kind_name(k.static_name()) == normalized)
+            .copied()
+            .ok_or_else(|| anyhow::Error::msg(format!("Unknown voicing `{}`.", symbol)))
+    }
+}
+
+impl Voicing {
+    /// Voices the given [`Chord`] with the given kind of voicing, and fits it into the given [`Register`].
+    ///
+    /// The voicing is placed as close as possible to the octave of the chord's root.
+    pub fn new(chord: &Chord, kind: VoicingKind, register: Register) -> Res<Self> {
+        let tones = Tones::new(chord);
+        let root = chord.root();
+
+        let third = tones.third;
+        let seventh = tones.seventh;
+        let is_dominant = third == Some(Interval::MajorThird) && seventh == Some(Interval::MinorSeventh) && tones.fifth == Some(Interval::PerfectFifth);
+
+        // Dominant rootless voicings use the thirteenth in place of the (unaltered) fifth.
+        let color = if is_dominant { Some(tones.thirteenth.unwrap_or(Interval::MajorThirteenth)) } else { tones.fifth };
+        let ninth = Some(tones.ninth.unwrap_or(Interval::MajorNinth));
+
+        let mut notes = match kind {
+            VoicingKind::Close => close(root, &[&[third, tones.fifth, seventh], &tones.tensions.iter().map(|t| Some(*t)).collect::<Vec<_>>()[..]].concat()),
+            VoicingKind::Drop2 | VoicingKind::Drop3 | VoicingKind::Drop24 => {
+                let mut notes = close(root, &[third, tones.fifth, seventh.or(Some(Interval::PerfectOctave))]);
+
+                if notes.len() < 4 {
+                    return Err(anyhow::Error::msg("Drop voicings require four voices."));
+                }
+
+                // Voices are counted from the top.
+                let dropped: &[usize] = match kind {
+                    VoicingKind::Drop2 => &[2],
+                    VoicingKind::Drop3 => &[3],
+                    _ => &[2, 4],
+                };
+
+                for k in dropped {
+                    let index = notes.len() - k;
+                    notes[index] = shift(notes[index], -1)?;
+                }
+
+                notes
+            }
+            VoicingKind::RootlessA | VoicingKind::RootlessB => {
+                if seventh.is_none() {
+                    return Err(anyhow::Error::msg("Rootless voicings require a seventh (or sixth) chord."));
+                }
+
+                match kind {
+                    VoicingKind::RootlessA => stack(root, &[third, color, seventh, ninth]),
+                    _ => stack(shift(root, -1)?, &[seventh, ninth, third, color]),
+                }
+            }
+            VoicingKind::Shell => stack(root, &[Some(Interval::PerfectUnison), third, seventh.or(tones.fifth)]),
+            VoicingKind::Spread => {
+                let upper = [&[third, seventh], &tones.tensions.iter().map(|t| Some(*t)).collect::<Vec<_>>()[..]].concat();
+                let bottom = stack(root, &[Some(Interval::PerfectUnison), tones.fifth]);
+                let top = *bottom.last().unwrap_or(&root);
+
+                [bottom, stack_above(top, root, &upper)].concat()
+            }
+            VoicingKind::Quartal => {
+                let scale = chord.scale();
+
+                // Stack diatonic fourths for seven-note scales, and perfect fourths otherwise (e.g., for whole tone scales).
+                if scale.len() == 7 {
+                    // Start from the third (or the second note of the scale, for chords without one).
+                    let start = third.map(|t| root + t).and_then(|t| scale.iter().position(|n| n.pitch() == t.pitch())).unwrap_or(1);
+
+                    stack(root, &(0..4).map(|k| Some(scale[(start + 3 * k) % 7] - root)).collect::<Vec<_>>())
+                } else {
+                    let bottom = root + third.unwrap_or(Interval::MajorSecond);
+
+                    (0..4).map(|k| (0..k).fold(bottom, |n, _| n + Interval::PerfectFourth)).collect()
+                }
+            }
+            VoicingKind::UpperStructure => {
+                let guide_tones = if seventh.is_some() { [third, seventh] } else { [Some(Interval::PerfectUnison), third] };
+
+                // Tensions that the chord lacks default to the natural ones (with a ♯11 over a major third).
+                let eleventh = tones.eleventh.unwrap_or(if third == Some(Interval::MajorThird) {
+                    Interval::AugmentedEleventh
+                } else {
+                    Interval::PerfectEleventh
+                });
+                let thirteenth = tones.thirteenth.unwrap_or(Interval::MajorThirteenth);
+
+                let bottom = stack(root, &guide_tones);
+                let top = *bottom.last().unwrap_or(&root);
+
+                [bottom, stack_above(top, root, &[ninth, Some(eleventh), Some(thirteenth)])].concat()
+            }
+        };
+
+        notes.sort();
+
+        // Add the slash note (if any) below the voicing.
+        let slash = chord.slash();
+
+        let notes = if slash != root {
+            let lowest = *notes.first().unwrap_or(&root);
+            let mut bass = slash.with_octave(lowest.octave());
+
+            while bass >= lowest {
+                bass = shift(bass, -1)?;
+            }
+
+            [vec![bass], notes].concat()
+        } else {
+            notes
+        };
+
+        Ok(Self {
+            chord: chord.clone(),
+            kind,
+            notes: fit(notes, register)?,
+        })
+    }
+
+    /// Returns the voiced [`Chord`].
+    pub fn chord(&self) -> &Chord {
+        &self.chord
+    }
+
+    /// Returns the kind of voicing.
+    pub fn kind(&self) -> VoicingKind {
+        self.kind
+    }
+
+    /// Returns the notes of the voicing (from low to high).
+    pub fn notes(&self) -> Vec<Note> {
+        self.notes.clone()
+    }
+}
+
+impl HasName for Voicing {
+    fn name(&self) -> String {
+        format!("{} ({})", self.chord.name(), self.kind.static_name())
+    }
+}
+
+impl Display for Voicing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let notes = self.notes.iter().map(|n| n.name()).collect::<Vec<_>>().join(" ");
+
+        write!(f, "{}: {}", self.name(), notes)
+    }
+}
+
+impl HasChord for Voicing {
+    fn chord(&self) -> Vec<Note> {
+        self.notes()
+    }
+}
+
+impl From<Voicing> for Vec<Note> {
+    fn from(voicing: Voicing) -> Self {
+        voicing.notes
+    }
+}
+
+#[cfg(feature = "audio")]
+use super::{
+    base::{Playable, PlaybackHandle},
+    tuning::Tuning,
+};
+
+#[cfg(feature = "audio")]
+impl Playable for Voicing {
+    #[no_coverage]
+    fn play_in(&self, tuning: &Tuning, delay: f32, length: f32, fade_in: f32) -> Res<PlaybackHandle> {
+        super::chord::play_notes(&self.notes, tuning, delay, length, fade_in)
+    }
+}
+
+impl HasVoicings for Chord {
+    fn voicing(&self, kind: VoicingKind, register: Register) -> Res<Voicing> {
+        Voicing::new(self, kind, register)
+    }
+
+    fn voicings(&self, register: Register) -> Vec<Voicing> {
+        ALL_VOICING_KINDS.iter().filter_map(|k| Voicing::new(self, *k, register).ok()).collect()
+    }
+}
+
+impl Tones {
+    /// Sorts the tones of the given [`Chord`] by their role.
+    pub(crate) fn new(chord: &Chord) -> Self {
+        let intervals = chord.relative_chord();
+        let has = |i: Interval| intervals.contains(&i);
+
+        let third = [Interval::MajorThird, Interval::MinorThird].into_iter().find(|i| has(*i));
+        let seventh = [Interval::MajorSeventh, Interval::MinorSeventh, Interval::DiminishedSeventh].into_iter().find(|i| has(*i));
+
+        let mut tones = Tones {
+            third,
+            fifth: None,
+            seventh,
+            ninth: None,
+            eleventh: None,
+            thirteenth: None,
+            tensions: vec![],
+        };
+
+        for interval in intervals {
+            match interval {
+                Interval::PerfectUnison => {}
+                Interval::MajorThird | Interval::MinorThird if Some(interval) == third => {}
+                Interval::MajorSeventh | Interval::MinorSeventh | Interval::DiminishedSeventh if Some(interval) == seventh => {}
+                // Suspended chords use the second or fourth in place of the third.
+                Interval::MajorSecond | Interval::PerfectFourth if tones.third.is_none() => tones.third = Some(interval),
+                Interval::DiminishedFifth | Interval::PerfectFifth | Interval::AugmentedFifth => tones.fifth = Some(interval),
+                // Sixth chords use the sixth in place of the seventh.
+                Interval::MajorSixth if tones.seventh.is_none() => tones.seventh = Some(interval),
+                _ => {
+                    let tension = compound(interval);
+
+                    match tension {
+                        Interval::MinorNinth | Interval::MajorNinth | Interval::AugmentedNinth => tones.ninth = tones.ninth.or(Some(tension)),
+                        Interval::DiminishedEleventh | Interval::PerfectEleventh | Interval::AugmentedEleventh => tones.eleventh = tones.eleventh.or(Some(tension)),
+                        _ => tones.thirteenth = tones.thirteenth.or(Some(tension)),
+                    }
+
+                    tones.tensions.push(tension);
+                }
+            }
+        }
+
+        tones
+    }
+}
+
+// Statics.
+
+/// All of the [`VoicingKind`]s.
+pub static ALL_VOICING_KINDS: [VoicingKind; 10] = [
+    VoicingKind::Close,
+    VoicingKind::Drop2,
+    VoicingKind::Drop3,
+    VoicingKind::Drop24,
+    VoicingKind::RootlessA,
+    VoicingKind::RootlessB,
+    VoicingKind::Shell,
+    VoicingKind::Spread,
+    VoicingKind::Quartal,
+    VoicingKind::UpperStructure,
+];
+
+// Helpers.
+
+/// Normalizes a voicing kind name (i.e., lowercase, without spaces, dashes, or pluses).
+fn normalize_kind_name(name: &str) -> String {
+    name.chars().filter(|c| !matches!(c, ' ' | '-' | '_' | '+')).collect::<String>().to_lowercase()
+}
+
+/// Returns the compound form of a tension (e.g., a major second becomes a major ninth).
+fn compound(interval: Interval) -> Interval {
+    match interval {
+        Interval::MinorSecond => Interval::MinorNinth,
+        Interval::MajorSecond => Interval::MajorNinth,
+        Interval::AugmentedSecond => Interval::AugmentedNinth,
+        Interval::DiminishedFourth => Interval::DiminishedEleventh,
+        Interval::PerfectFourth => Interval::PerfectEleventh,
+        Interval::AugmentedFourth => Interval::AugmentedEleventh,
+        Interval::MinorSixth => Interval::MinorThirteenth,
+        Interval::MajorSixth => Interval::MajorThirteenth,
+        Interval::AugmentedSixth => Interval::AugmentedThirteenth,
+        _ => interval,
+    }
+}
+
+/// Moves a note by the given number of octaves.
+fn shift(note: Note, octaves: i8) -> Res<Note> {
+    let octave = u8::try_from(note.octave() as i8 + octaves).ok().and_then(|o| Octave::try_from(o).ok());
+
+    octave.map(|o| note.with_octave(o)).ok_or_else(|| anyhow::Error::msg("The voicing is out of range."))
+}
+
+/// Returns the given note, moved by octaves so that it is the lowest such note above `floor`.
+fn above(floor: Note, note: Note) -> Note {
+    let mut result = note.with_octave(floor.octave());
+
+    while result <= floor {
+        result = match shift(result, 1) {
+            Ok(n) => n,
+            Err(_) => break,
+        };
+    }
+
+    while let Ok(lower) = shift(result, -1) {
+        if lower <= floor {
+            break;
+        }
+
+        result = lower;
+    }
+
+    result
+}
+
+/// Stacks the given intervals (relative to `root`) upwards, with each note above the last, starting from the first interval.
+fn stack(root: Note, intervals: &[Option<Interval>]) -> Vec<Note> {
+    let intervals = intervals.iter().flatten().map(|i| Some(*i)).collect::<Vec<_>>();
+
+    match intervals.split_first() {
+        Some((Some(first), rest)) => {
+            let first = (root + *first).with_octave(root.octave());
+
+            [vec![first], stack_above(first, root, rest)].concat()
+        }
+        _ => vec![],
+    }
+}
+
+/// Stacks the given intervals (relative to `root`) upwards, with each note above the last, starting above `floor`.
+fn stack_above(floor: Note, root: Note, intervals: &[Option<Interval>]) -> Vec<Note> {
+    let mut notes = vec![];
+    let mut last = floor;
+
+    for interval in intervals.iter().flatten() {
+        last = above(last, root + *interval);
+        notes.push(last);
+    }
+
+    notes
+}
+
+/// Returns the root and the given intervals (relative to `root`) in close position (i.e., all within the octave above the root).
+fn close(root: Note, intervals: &[Option<Interval>]) -> Vec<Note> {
+    let mut notes = vec![root];
+
+    for interval in intervals.iter().flatten() {
+        let note = above(root, root + *interval);
+
+        if !notes.contains(&note) {
+            notes.push(note);
+        }
+    }
+
+    notes.sort();
+    notes
+}
+
+/// Moves the notes by octaves so that they fit into the register (as close to where they started as possible).
+fn fit(notes: Vec<Note>, register: Register) -> Res<Vec<Note>> {
+    for octaves in [0, 1, -1, 2, -2, 3, -3, 4, -4] {
+        let Ok(shifted) = notes.iter().map(|n| shift(*n, octaves)).collect::<Res<Vec<_>>>() else {
+            continue;
+        };
+
+        if shifted.iter().all(|n| register.contains(*n)) {
+            return Ok(shifted);
+        }
+    }
+
+    Err(anyhow::Error::msg(format!("The voicing does not fit into the register {}.", register)))
+}
+
+// Tests.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{chord::Chordable, note::*};
+    use pretty_assertions::assert_eq;
+
+    fn voiced(symbol: &str, kind: VoicingKind) -> String {
+        Chord::parse(symbol).unwrap().voicing(kind, Register::default()).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_voicings() {
+        assert_eq!(voiced("Cmaj7", VoicingKind::Close), "Cmaj7 (close): C4 E4 G4 B4");
+        assert_eq!(voiced("Cmaj7", VoicingKind::Drop2), "Cmaj7 (drop 2): G3 C4 E4 B4");
+        assert_eq!(voiced("Cmaj7", VoicingKind::Drop3), "Cmaj7 (drop 3): E3 C4 G4 B4");
+        assert_eq!(voiced("Cmaj7", VoicingKind::Drop24), "Cmaj7 (drop 2+4): C3 G3 E4 B4");
+        assert_eq!(voiced("Dm7", VoicingKind::RootlessA), "Dm7 (rootless A): F4 A4 C5 E5");
+        assert_eq!(voiced("G7", VoicingKind::RootlessA), "G7 (rootless A): B4 E5 F5 A5");
+        assert_eq!(voiced("G7", VoicingKind::RootlessB), "G7 (rootless B): F3 A3 B3 E4");
+        assert_eq!(voiced("G7", VoicingKind::Shell), "G7 (shell): G4 B4 F5");
+        assert_eq!(voiced("C9", VoicingKind::Spread), "C9 (spread): C3 G3 E4 B♭4 D5");
+        assert_eq!(voiced("Dm7", VoicingKind::Quartal), "Dm7 (quartal): F4 B4 E5 A5");
+        assert_eq!(voiced("C7", VoicingKind::UpperStructure), "C7 (upper structure): E4 B♭4 D5 F♯5 A5");
+        assert_eq!(voiced("C/E", VoicingKind::Close), "C/E (close): E3 C4 E4 G4");
+    }
+
+    #[test]
+    fn test_register() {
+        let chord = Chord::new(C).seven();
+
+        // The voicing is moved by octaves to fit into the register.
+        let voicing = chord.voicing(VoicingKind::Close, Register::new(CFive, CSeven)).unwrap();
+
+        assert_eq!(voicing.notes(), vec![CFive, EFive, GFive, BFlatFive]);
+        assert_eq!(Vec::<Note>::from(voicing), vec![CFive, EFive, GFive, BFlatFive]);
+
+        assert!(chord.voicing(VoicingKind::Spread, Register::new(CFour, CFive)).is_err());
+        assert!(Chord::new(C).voicing(VoicingKind::RootlessA, Register::default()).is_err());
+
+        assert_eq!(chord.voicings(Register::default()).len(), ALL_VOICING_KINDS.len());
+        assert_eq!(Chord::new(C).voicings(Register::default()).len(), ALL_VOICING_KINDS.len() - 2);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(VoicingKind::parse("drop 2").unwrap(), VoicingKind::Drop2);
+        assert_eq!(VoicingKind::parse("Drop-2+4").unwrap(), VoicingKind::Drop24);
+        assert_eq!(VoicingKind::parse("rootless-a").unwrap(), VoicingKind::RootlessA);
+        assert_eq!(VoicingKind::parse("upper_structure").unwrap(), VoicingKind::UpperStructure);
+        assert!(VoicingKind::parse("drop 5").is_err());
+    }
+}
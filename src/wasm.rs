@@ -10,13 +10,17 @@ use js_sys::{Array, Object, Reflect};
 use wasm_bindgen::{convert::RefFromWasmAbi, prelude::*};
 
 use crate::core::{
-    base::{HasDescription, HasName, HasPreciseName, HasStaticName, Parsable, Res, PlaybackHandle},
-    chord::{Chord, Chordable, HasChord, HasExtensions, HasInversion, HasIsCrunchy, HasModifiers, HasRoot, HasScale, HasSlash},
+    base::{HasDescription, HasName, HasPreciseName, HasStaticName, Parsable, PlaybackHandle, Res},
+    chord::{Chord, Chordable, HasChord, HasExtensions, HasInversion, HasIsCrunchy, HasModifiers, HasRoot, HasScale, HasSlash, HasUpper},
     interval::Interval,
+    name_style::{NameStyle, NamingOptions},
     named_pitch::HasNamedPitch,
-    note::{HasPrimaryHarmonicSeries, Note},
+    note::{HasPrimaryHarmonicSeries, Note, Respellable, Spelling},
     octave::{HasOctave, Octave},
     pitch::HasFrequency,
+    progression::Progression,
+    tuning::Tuning,
+    voicing::{HasVoicings, Register, VoicingKind},
 };
 
 // Use `wee_alloc` as the global allocator.
@@ -66,6 +70,24 @@ impl KordNote {
         Ok(Self { inner: Note::parse(&name).to_js_error()? })
     }
 
+    /// Returns the nearest [`Note`] to the given frequency, and how far the frequency is from it (in cents), as
+    /// `[note, cents]`, honoring the given reference pitch (the frequency of A4).
+    #[wasm_bindgen(js_name = fromFrequency)]
+    pub fn from_frequency(frequency: f32, reference: Option<f32>) -> JsRes<Array> {
+        let tuning = match reference {
+            Some(reference) => Tuning::default().with_reference(reference),
+            None => Tuning::default(),
+        };
+
+        let (note, cents) = tuning.nearest_note(frequency).to_js_error()?;
+
+        let result = Array::new();
+        result.push(&KordNote::from(note).into());
+        result.push(&JsValue::from_f64(cents as f64));
+
+        Ok(result)
+    }
+
     /// Returns [`Note`]s from audio data.
     #[cfg(feature = "analyze_base")]
     #[wasm_bindgen(js_name = fromAudio)]
@@ -115,6 +137,12 @@ impl KordNote {
         self.inner.frequency()
     }
 
+    /// Returns the [`Note`]'s frequency in the given [`Tuning`] (e.g., `just D`, `19edo`, or `werckmeister A=415`).
+    #[wasm_bindgen(js_name = frequencyIn)]
+    pub fn frequency_in(&self, tuning: String) -> JsRes<f32> {
+        Ok(self.inner.frequency_in(&Tuning::parse(&tuning).to_js_error()?))
+    }
+
     /// Adds the given interval to the [`Note`], producing a new [`Note`] instance.
     #[wasm_bindgen(js_name = addInterval)]
     pub fn add_interval(&self, interval: Interval) -> KordNote {
@@ -145,6 +173,14 @@ impl KordNote {
         series.into_iter().map(KordNote::from).into_js_array()
     }
 
+    /// Returns a new [`Note`] respelled according to the given spelling policy (e.g., `sharps`, `flats`, `no-doubles`, or a key, such as `Eb`).
+    #[wasm_bindgen]
+    pub fn respelled(&self, spelling: String) -> JsRes<KordNote> {
+        Ok(KordNote {
+            inner: self.inner.respelled(Spelling::parse(&spelling).to_js_error()?),
+        })
+    }
+
     /// Returns the clone of the [`Note`].
     #[wasm_bindgen]
     pub fn copy(&self) -> KordNote {
@@ -207,16 +243,18 @@ impl KordChord {
         Ok(candidates.into_js_array())
     }
 
-    /// Returns the [`Chord`]'s friendly name.
+    /// Returns the [`Chord`]'s friendly name, optionally in the given name style (e.g., `jazz`, `berklee`, `ascii`, `ireal`, or `latex`)
+    /// and spelling policy (e.g., `sharps`, `flats`, `no-doubles`, or a key, such as `Eb`).
     #[wasm_bindgen]
-    pub fn name(&self) -> String {
-        self.inner.name()
+    pub fn name(&self, style: Option<String>, spelling: Option<String>) -> JsRes<String> {
+        Ok(self.inner.name_with(&naming_options(style, spelling)?))
     }
 
-    /// Returns the [`Chord`]'s precise name.
+    /// Returns the [`Chord`]'s precise name, optionally in the given name style (e.g., `jazz`, `berklee`, `ascii`, `ireal`, or `latex`)
+    /// and spelling policy (e.g., `sharps`, `flats`, `no-doubles`, or a key, such as `Eb`).
     #[wasm_bindgen(js_name = preciseName)]
-    pub fn precise_name(&self) -> String {
-        self.inner.precise_name()
+    pub fn precise_name(&self, style: Option<String>, spelling: Option<String>) -> JsRes<String> {
+        Ok(self.inner.precise_name_with(&naming_options(style, spelling)?))
     }
 
     /// Returns the [`Chord`] as a string (same as `precise_name`).
@@ -250,6 +288,12 @@ impl KordChord {
         self.inner.slash().name()
     }
 
+    /// Returns the [`Chord`]'s upper chord, if it is a polychord (e.g., the `D` in `D/C7`).
+    #[wasm_bindgen]
+    pub fn upper(&self) -> Option<KordChord> {
+        self.inner.upper().cloned().map(KordChord::from)
+    }
+
     /// Returns the [`Chord`]'s inversion.
     #[wasm_bindgen]
     pub fn inversion(&self) -> u8 {
@@ -268,6 +312,14 @@ impl KordChord {
         self.inner.chord().into_iter().map(KordNote::from).into_js_array()
     }
 
+    /// Returns the notes of the given voicing of the [`Chord`] (e.g., `drop2`, `rootless-a`, `shell`, `quartal`, etc.).
+    #[wasm_bindgen]
+    pub fn voicing(&self, kind: String) -> JsRes<Array> {
+        let voicing = self.inner.voicing(VoicingKind::parse(&kind).to_js_error()?, Register::default()).to_js_error()?;
+
+        Ok(voicing.notes().into_iter().map(KordNote::from).into_js_array())
+    }
+
     /// Returns the [`Chord`]'s chord tones as a string.
     #[wasm_bindgen(js_name = chordString)]
     pub fn chord_string(&self) -> String {
@@ -314,6 +366,14 @@ impl KordChord {
         }
     }
 
+    /// Returns a new polychord with the provided [`Chord`] stacked above this one.
+    #[wasm_bindgen(js_name = withUpper)]
+    pub fn with_upper(&self, upper: &KordChord) -> Self {
+        KordChord {
+            inner: self.inner.clone().with_upper(upper.inner.clone()),
+        }
+    }
+
     /// Returns a new [`Chord`] with the octave of the root set to the provided value.
     #[wasm_bindgen(js_name = withOctave)]
     pub fn with_octave(&self, octave: u8) -> JsRes<KordChord> {
@@ -330,14 +390,52 @@ impl KordChord {
         }
     }
 
+    /// Adds the given interval to the [`Chord`], producing a new [`Chord`] instance.
+    #[wasm_bindgen(js_name = addInterval)]
+    pub fn add_interval(&self, interval: Interval) -> JsRes<KordChord> {
+        Ok(KordChord {
+            inner: self.inner.clone().checked_transpose(interval, false).to_js_error()?,
+        })
+    }
+
+    /// Subtracts the given interval from the [`Chord`], producing a new [`Chord`] instance.
+    #[wasm_bindgen(js_name = subInterval)]
+    pub fn subtract_interval(&self, interval: Interval) -> JsRes<KordChord> {
+        Ok(KordChord {
+            inner: self.inner.clone().checked_transpose(interval, true).to_js_error()?,
+        })
+    }
+
+    /// Returns a new [`Chord`] respelled with the simplest enharmonic root.
+    #[wasm_bindgen]
+    pub fn simplified(&self) -> KordChord {
+        KordChord { inner: self.inner.clone().simplified() }
+    }
+
+    /// Returns a new [`Chord`] whose root (and slash note) are respelled according to the given spelling policy (e.g., `sharps`, `flats`, `no-doubles`, or a key, such as `Eb`).
+    #[wasm_bindgen]
+    pub fn respelled(&self, spelling: String) -> JsRes<KordChord> {
+        Ok(KordChord {
+            inner: self.inner.clone().respelled(Spelling::parse(&spelling).to_js_error()?),
+        })
+    }
+
     /// Plays the [`Chord`].
     #[wasm_bindgen]
     #[cfg(feature = "audio")]
     pub async fn play(&self, delay: f32, length: f32, fade_in: f32) -> JsRes<()> {
-        use gloo_timers::future::TimeoutFuture;
+        self.play_in("equal".to_owned(), delay, length, fade_in).await
+    }
+
+    /// Plays the [`Chord`] in the given [`Tuning`] (e.g., `just D`, `19edo`, or `werckmeister A=415`).
+    #[wasm_bindgen(js_name = playIn)]
+    #[cfg(feature = "audio")]
+    pub async fn play_in(&self, tuning: String, delay: f32, length: f32, fade_in: f32) -> JsRes<()> {
         use crate::core::base::Playable;
+        use gloo_timers::future::TimeoutFuture;
 
-        let _handle = self.inner.play(delay, length, fade_in).context("Could not start the playback.").to_js_error()?;
+        let tuning = Tuning::parse(&tuning).to_js_error()?;
+        let _handle = self.inner.play_in(&tuning, delay, length, fade_in).context("Could not start the playback.").to_js_error()?;
 
         TimeoutFuture::new((length * 1_000.0) as u32).await;
 
@@ -351,10 +449,158 @@ impl KordChord {
     }
 }
 
+// [`Progression`] ABI.
+
+/// The [`Progression`] wrapper.
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct KordProgression {
+    inner: Progression,
+}
+
+impl From<Progression> for KordProgression {
+    fn from(progression: Progression) -> Self {
+        KordProgression { inner: progression }
+    }
+}
+
+impl From<KordProgression> for Progression {
+    fn from(kord_progression: KordProgression) -> Self {
+        kord_progression.inner
+    }
+}
+
+/// The [`Progression`] impl.
+#[wasm_bindgen]
+impl KordProgression {
+    /// Parses a [`Progression`] (e.g., `| Dm7 G7 | Cmaj7 |`).
+    #[wasm_bindgen]
+    pub fn parse(progression: String) -> JsRes<KordProgression> {
+        Ok(Self {
+            inner: Progression::parse(&progression).to_js_error()?,
+        })
+    }
+
+    /// Creates a new [`Progression`] from a set of [`Chord`]s (one chord per bar).
+    #[wasm_bindgen(js_name = fromChords)]
+    pub fn from_chords(chords: Array) -> JsRes<KordProgression> {
+        let chords: Vec<Chord> = chords.cloned_into_vec_inner::<KordChord, Chord>()?;
+
+        Ok(Self { inner: Progression::from_chords(&chords) })
+    }
+
+    /// Returns the [`Progression`] as a string.
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+
+    /// Returns the [`Progression`]'s chords (one per entry, with `null` for rests).
+    #[wasm_bindgen]
+    pub fn chords(&self) -> Array {
+        self.inner
+            .entries()
+            .iter()
+            .map(|e| e.chord.clone().map(|c| JsValue::from(KordChord::from(c))).unwrap_or(JsValue::NULL))
+            .into_js_array()
+    }
+
+    /// Returns the [`Progression`]'s durations (one per entry, in beats).
+    #[wasm_bindgen]
+    pub fn durations(&self) -> Array {
+        self.inner.entries().iter().map(|e| e.beats).into_js_array()
+    }
+
+    /// Returns the [`Progression`]'s time signature (e.g., `4/4`).
+    #[wasm_bindgen(js_name = timeSignature)]
+    pub fn time_signature(&self) -> String {
+        self.inner.time_signature().to_string()
+    }
+
+    /// Returns the [`Progression`]'s tempo (in beats per minute).
+    #[wasm_bindgen]
+    pub fn tempo(&self) -> f32 {
+        self.inner.tempo()
+    }
+
+    /// Returns the [`Progression`]'s length (in seconds).
+    #[wasm_bindgen(js_name = lengthInSeconds)]
+    pub fn length_in_seconds(&self) -> f32 {
+        self.inner.length_in_seconds()
+    }
+
+    /// Returns a new [`Progression`] with the tempo set to the provided value.
+    #[wasm_bindgen(js_name = withTempo)]
+    pub fn with_tempo(&self, tempo: f32) -> JsRes<KordProgression> {
+        Ok(KordProgression {
+            inner: self.inner.clone().with_tempo(tempo).to_js_error()?,
+        })
+    }
+
+    /// Adds the given interval to every chord in the [`Progression`], producing a new [`Progression`] instance.
+    #[wasm_bindgen(js_name = addInterval)]
+    pub fn add_interval(&self, interval: Interval) -> JsRes<KordProgression> {
+        Ok(KordProgression {
+            inner: self.inner.checked_transpose(interval, false).to_js_error()?,
+        })
+    }
+
+    /// Subtracts the given interval from every chord in the [`Progression`], producing a new [`Progression`] instance.
+    #[wasm_bindgen(js_name = subInterval)]
+    pub fn subtract_interval(&self, interval: Interval) -> JsRes<KordProgression> {
+        Ok(KordProgression {
+            inner: self.inner.checked_transpose(interval, true).to_js_error()?,
+        })
+    }
+
+    /// Returns a new [`Progression`] with every chord respelled with the simplest enharmonic root.
+    #[wasm_bindgen]
+    pub fn simplified(&self) -> KordProgression {
+        KordProgression { inner: self.inner.clone().simplified() }
+    }
+
+    /// Plays the [`Progression`] (once).
+    #[wasm_bindgen]
+    #[cfg(feature = "audio")]
+    pub async fn play(&self, fade_in: f32) -> JsRes<()> {
+        self.play_in("equal".to_owned(), fade_in).await
+    }
+
+    /// Plays the [`Progression`] (once) in the given [`Tuning`] (e.g., `just D`, `19edo`, or `werckmeister A=415`).
+    #[wasm_bindgen(js_name = playIn)]
+    #[cfg(feature = "audio")]
+    pub async fn play_in(&self, tuning: String, fade_in: f32) -> JsRes<()> {
+        use crate::core::base::Playable;
+        use gloo_timers::future::TimeoutFuture;
+
+        let tuning = Tuning::parse(&tuning).to_js_error()?;
+
+        for entry in self.inner.entries() {
+            let length = entry.beats * self.inner.seconds_per_beat();
+
+            let _handle = match &entry.chord {
+                Some(chord) => Some(chord.play_in(&tuning, 0.0, length, fade_in).context("Could not start the playback.").to_js_error()?),
+                None => None,
+            };
+
+            TimeoutFuture::new((length * 1_000.0) as u32).await;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the clone of the [`Progression`].
+    #[wasm_bindgen]
+    pub fn copy(&self) -> KordProgression {
+        self.clone()
+    }
+}
+
 // Playback handle.
 
 /// A handle to a [`Chord`] playback.
-/// 
+///
 /// Should be dropped to stop the playback, or after playback is finished.
 #[wasm_bindgen]
 pub struct KordPlaybackHandle {
@@ -412,6 +658,21 @@ impl<T> ToJsError<T> for Res<T> {
     }
 }
 
+/// Helper function for parsing the optional name style and spelling policy of a name into [`NamingOptions`].
+fn naming_options(style: Option<String>, spelling: Option<String>) -> JsRes<NamingOptions> {
+    let mut options = NamingOptions::default();
+
+    if let Some(style) = style {
+        options = options.with_style(NameStyle::parse(&style).to_js_error()?);
+    }
+
+    if let Some(spelling) = spelling {
+        options = options.with_spelling(Spelling::parse(&spelling).to_js_error()?);
+    }
+
+    Ok(options)
+}
+
 /// Helper trait for converting a [`IntoIterator<Item = T>`] (where `T: Into<JsValue>`) to an [`Array`].
 trait IntoJsArray {
     /// Converts the [`Vec`] to an [`Array`].